@@ -1,3 +1,8 @@
+use crate::{
+    geometry::{Rect, Transform},
+    size::Size,
+};
+
 #[derive(Debug, Clone, Copy, Default)]
 pub struct Camera {
     x: f64,
@@ -13,6 +18,21 @@ impl Camera {
         self.y
     }
 
+    /// Maps document coordinates to coordinates on the canvas.
+    pub fn transform(&self) -> Transform {
+        Transform::translation(-self.x, -self.y)
+    }
+
+    /// The part of the document shown on a canvas of the given size.
+    pub fn viewport(&self, size: Size<f64>) -> Rect {
+        let canvas = Rect::new(0.0, 0.0, size.width(), size.height());
+
+        self.transform()
+            .inverse()
+            .unwrap_or_default()
+            .apply_rect(canvas)
+    }
+
     pub fn translate(&mut self, x: f64, y: f64) {
         self.x += x;
         self.y += y;
@@ -1,15 +1,106 @@
+use std::{cell::RefCell, collections::HashMap};
+
 use crate::{
     camera::Camera,
-    elements::{Label, Rectangle, TextProperties},
+    elements::{Label, Path, Rectangle, TextProperties},
+    grid::Grid,
     size::Size,
 };
 
 pub trait Canvas {
     fn draw_rectangle(&self, rect: Rectangle, camera: &Camera);
     fn draw_text(&self, label: &Label, camera: &Camera);
+    fn draw_path(&self, path: &Path, camera: &Camera);
     fn measure_text(
         &self,
         text: &str,
         props: &TextProperties,
     ) -> Option<Size<f32>>;
+
+    /// The size of the drawing area, in document units.
+    fn size(&self) -> Size<f64>;
+
+    /// Fills the drawing area with the background and the dots of `grid`.
+    /// By default every visible dot is drawn as a rectangle; canvases that
+    /// can should draw the dots from a cached pattern instead.
+    fn draw_grid(&self, grid: &Grid, camera: &Camera) {
+        let viewport = camera.viewport(self.size());
+        let (l, t, r, b) = viewport.edges();
+
+        self.draw_rectangle(
+            Rectangle::new(l, t, r - l, b - t, grid.background(), None, None),
+            camera,
+        );
+
+        for (x, y) in grid.dots(viewport) {
+            let size = grid.dot_size();
+            self.draw_rectangle(
+                Rectangle::new(x, y, size, size, grid.dot_color(), None, None),
+                camera,
+            );
+        }
+    }
+}
+
+/// How many measurements a [`TextCache`] holds before it starts over.
+const TEXT_CACHE_CAPACITY: usize = 10_000;
+
+/// Measurements of text, keyed by font and then by the text itself, so that
+/// canvases only measure each string once per font.
+#[derive(Debug, Default)]
+pub struct TextCache {
+    sizes: RefCell<HashMap<String, HashMap<String, Size<f32>>>>,
+}
+
+impl TextCache {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns the cached size of `text` in the font of `props`, calling
+    /// `measure` only when the pair has not been measured before. Failed
+    /// measurements are not cached.
+    pub fn get_or_measure(
+        &self,
+        text: &str,
+        props: &TextProperties,
+        measure: impl FnOnce() -> Option<Size<f32>>,
+    ) -> Option<Size<f32>> {
+        let font = props.get_font_string();
+
+        if let Some(size) = self
+            .sizes
+            .borrow()
+            .get(&font)
+            .and_then(|sizes| sizes.get(text))
+        {
+            return Some(*size);
+        }
+
+        let size = measure()?;
+
+        if self.len() >= TEXT_CACHE_CAPACITY {
+            self.clear();
+        }
+        self.sizes
+            .borrow_mut()
+            .entry(font)
+            .or_default()
+            .insert(text.to_string(), size);
+
+        Some(size)
+    }
+
+    /// The number of measurements held.
+    pub fn len(&self) -> usize {
+        self.sizes.borrow().values().map(HashMap::len).sum()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    pub fn clear(&self) {
+        self.sizes.borrow_mut().clear();
+    }
 }
@@ -1,22 +1,56 @@
-use serde::{Deserialize, Serialize};
+use std::{
+    cmp::Reverse,
+    collections::{HashMap, HashSet},
+};
+
+use serde::{Deserialize, Deserializer, Serialize, de::Error as _};
 
 use crate::{
     camera::Camera,
     canvas::Canvas,
     color::Color,
     drawable::Drawable,
-    elements::{Element, Info, Rectangle},
+    elements::{Element, Info, MessageKind},
+    geometry::Rect,
+    grid::Grid,
+    id::Id,
     interaction::Interactive,
+    member::Member,
+    prompt::Prompt,
+    spatial::SpatialIndex,
 };
 
+/// How far around the viewport elements are still drawn, so that labels
+/// and decorations sticking out of their bounds are not cut off.
+const CULL_MARGIN: f64 = 100.0;
+
+/// How far outside of its bounds an element can still be hit, such as a
+/// relationship a few pixels off its line.
+const HIT_MARGIN: f64 = 6.0;
+
 #[derive(Clone, Debug, PartialEq, Default)]
 struct LocalData {
     show_info: bool,
     info_element: Info,
+    /// Where the elements are, keyed by their position in the document.
+    index: SpatialIndex<usize>,
+    /// The position of every element in the document, by id.
+    positions: HashMap<Id, usize>,
+    /// Whether the elements were attached to each other since the document
+    /// last changed.
+    settled: bool,
+    /// The container of every element placed in one, as of when the
+    /// document was last settled.
+    parents: HashMap<Id, Id>,
+    /// The elements hovered by the cursor.
+    hovered: HashSet<Id>,
+    /// The elements picked by the last selection.
+    selected: HashSet<Id>,
 }
 
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct Document {
+    #[serde(deserialize_with = "unique_elements")]
     elements: Vec<Element>,
     color: Color,
     #[serde(skip)]
@@ -28,63 +62,809 @@ impl Document {
         &self.elements
     }
 
-    pub fn elements_mut(&mut self) -> &mut Vec<Element> {
+    /// The elements, for passes that change many of them at once. Elements
+    /// added, removed or reordered through it are only found by id and in
+    /// the spatial index once the document notices them.
+    pub(crate) fn elements_mut(&mut self) -> &mut Vec<Element> {
+        self.local.settled = false;
         &mut self.elements
     }
 
-    pub fn add_element(&mut self, el: impl Into<Element>) {
-        self.elements.push(el.into());
+    pub fn add_element(&mut self, el: impl Into<Element>) -> Id {
+        let el = el.into();
+        let id = el.id();
+        self.local.settled = false;
+        self.local
+            .index
+            .insert(self.elements.len(), index_bounds(&el));
+        self.local.positions.insert(id, self.elements.len());
+        self.elements.push(el);
+        id
+    }
+
+    pub fn get(&self, id: Id) -> Option<&Element> {
+        self.position(id).map(|position| &self.elements[position])
+    }
+
+    pub fn get_mut(&mut self, id: Id) -> Option<&mut Element> {
+        self.local.settled = false;
+        self.locate(id).map(|position| &mut self.elements[position])
+    }
+
+    pub fn contains(&self, id: Id) -> bool {
+        self.position(id).is_some()
+    }
+
+    /// Removes an element from the document, and from the container it is
+    /// placed in, if any.
+    pub fn remove(&mut self, id: Id) -> Option<Element> {
+        let position = self.locate(id)?;
+
+        if let Some(parent) = self.parent(id)
+            && let Some(container) = self.get_mut(parent)
+        {
+            container.remove_content(id);
+        }
+
+        let el = self.elements.remove(position);
+        self.local.settled = false;
+        self.local.positions.remove(&id);
+        self.local.hovered.remove(&id);
+        self.local.selected.remove(&id);
+        self.local.index.remove(position);
+
+        // Everything after the element moved up.
+        let shift = |other: usize| match other > position {
+            true => other - 1,
+            false => other,
+        };
+        for other in self.local.positions.values_mut() {
+            *other = shift(*other);
+        }
+        self.local.index.rename(shift);
+
+        Some(el)
+    }
+
+    /// Where `id` is in the document. Elements added or reordered without
+    /// going through the document are searched for one by one.
+    fn position(&self, id: Id) -> Option<usize> {
+        match self.local.positions.get(&id) {
+            Some(position)
+                if self.elements.get(*position).map(|el| el.id())
+                    == Some(id) =>
+            {
+                Some(*position)
+            }
+            _ => self.elements.iter().position(|el| el.id() == id),
+        }
+    }
+
+    /// Like [`Document::position`], but rebuilds the positions of all
+    /// elements if `id` was not where it was expected.
+    fn locate(&mut self, id: Id) -> Option<usize> {
+        if let Some(position) = self.local.positions.get(&id)
+            && self.elements.get(*position).map(|el| el.id()) == Some(id)
+        {
+            return Some(*position);
+        }
+
+        self.rebuild_positions();
+        self.local.positions.get(&id).copied()
+    }
+
+    fn rebuild_positions(&mut self) {
+        self.local.positions = self
+            .elements
+            .iter()
+            .enumerate()
+            .map(|(position, el)| (el.id(), position))
+            .collect();
+    }
+
+    /// Moves an element, together with everything it contains (e.g. the
+    /// substates of a composite state or the nodes of a swimlane) and
+    /// everything attached to it (e.g. the ports of a component).
+    pub fn move_element(&mut self, id: Id, delta_x: f64, delta_y: f64) {
+        let mut pending = vec![id];
+        let mut moved = HashSet::new();
+        self.local.settled = false;
+
+        while let Some(id) = pending.pop() {
+            if !moved.insert(id) {
+                continue;
+            }
+
+            let Some(position) = self.locate(id) else {
+                continue;
+            };
+
+            let el = &mut self.elements[position];
+            el.adjust_position(delta_x, delta_y);
+            self.local.index.insert(position, index_bounds(el));
+            pending.extend(el.contents());
+            pending.extend(
+                self.elements
+                    .iter()
+                    .filter(|el| el.owner() == Some(id))
+                    .map(|el| el.id()),
+            );
+        }
+    }
+
+    /// The container `id` is placed in, if any.
+    pub fn parent(&self, id: Id) -> Option<Id> {
+        self.elements
+            .iter()
+            .find(|el| el.contents().contains(&id))
+            .map(|el| el.id())
+    }
+
+    /// The container of every element placed in one.
+    fn parents(&self) -> HashMap<Id, Id> {
+        self.elements
+            .iter()
+            .flat_map(|el| el.contents().iter().map(|id| (*id, el.id())))
+            .collect()
+    }
+
+    /// `id` and everything nested in it, at any depth.
+    pub fn descendants(&self, id: Id) -> HashSet<Id> {
+        let mut pending = vec![id];
+        let mut found = HashSet::new();
+
+        while let Some(id) = pending.pop() {
+            if !found.insert(id) {
+                continue;
+            }
+
+            if let Some(el) = self.get(id) {
+                pending.extend(el.contents());
+            }
+        }
+
+        found
     }
 
-    pub fn update_cursor(&mut self, x: i32, y: i32, visible: bool) {
+    /// Moves `id` out of its current container and into `parent`. Returns
+    /// `false`, leaving the hierarchy as is, if `parent` cannot contain the
+    /// element or is nested in it.
+    pub fn set_parent(&mut self, id: Id, parent: Option<Id>) -> bool {
+        if let Some(parent) = parent {
+            let (Some(el), Some(container)) = (self.get(id), self.get(parent))
+            else {
+                return false;
+            };
+
+            if !container.can_contain(el)
+                || self.descendants(id).contains(&parent)
+            {
+                return false;
+            }
+        }
+
+        if let Some(old) = self.parent(id)
+            && let Some(el) = self.get_mut(old)
+        {
+            el.remove_content(id);
+        }
+
+        if let Some(parent) = parent
+            && let Some(el) = self.get_mut(parent)
+        {
+            el.add_content(id);
+        }
+
+        true
+    }
+
+    /// Re-parents an element after it has been dragged: it is placed in the
+    /// innermost container under its center that can contain it (the most
+    /// deeply nested one, then the smallest), or taken out of any container.
+    /// Returns the new parent.
+    pub fn drop_element(&mut self, id: Id) -> Option<Id> {
+        // The current container must not grow around the element anymore,
+        // or it could never be dragged out.
+        self.set_parent(id, None);
+        self.attach_containers();
+
+        let el = self.get(id)?;
+        let center = el.bounds().center();
+        let nested = self.descendants(id);
+        let parents = self.parents();
+
+        let parent = self
+            .elements
+            .iter()
+            .filter(|container| {
+                !nested.contains(&container.id())
+                    && container.can_contain(el)
+                    && container.bounds().contains(center)
+            })
+            .max_by_key(|container| {
+                let bounds = container.bounds();
+                let area = bounds.width() as i64 * bounds.height() as i64;
+                (depth(&parents, container.id()), Reverse(area))
+            })
+            .map(|container| container.id())?;
+
+        self.set_parent(id, Some(parent));
+        self.attach_containers();
+        Some(parent)
+    }
+
+    /// Grows every container (composite states and swimlanes) to enclose
+    /// the elements in it, starting with the innermost ones.
+    pub fn attach_containers(&mut self) {
+        // Each pass settles one more level of nesting.
+        for _ in 0..self.elements.len() {
+            let bounds = self
+                .elements
+                .iter()
+                .map(|el| (el.id(), el.bounds()))
+                .collect::<HashMap<_, _>>();
+            let mut changed = false;
+
+            for el in &mut self.elements {
+                let contents = el
+                    .contents()
+                    .iter()
+                    .filter_map(|id| bounds.get(id))
+                    .copied()
+                    .reduce(Rect::union);
+
+                let before = el.bounds();
+                el.attach_contents(contents);
+                changed |= el.bounds() != before;
+            }
+
+            if !changed {
+                break;
+            }
+        }
+    }
+
+    /// Snaps ports onto the border of their owners, then starts the lines
+    /// of interfaces on the outline of theirs (which may be a port).
+    pub fn attach_ports(&mut self) {
+        let bounds = self
+            .elements
+            .iter()
+            .map(|el| (el.id(), el.bounds()))
+            .collect::<HashMap<_, _>>();
+
+        for el in &mut self.elements {
+            let Some(port) = el.as_port_mut() else {
+                continue;
+            };
+
+            if let Some(owner) = bounds.get(&port.owner()) {
+                port.attach(*owner);
+            }
+        }
+
+        let outlines = self
+            .elements
+            .iter()
+            .map(|el| (el.id(), el.outline()))
+            .collect::<HashMap<_, _>>();
+
         for el in &mut self.elements {
-            match (visible, el.is_hovered(), el.cursor_intersects(x, y)) {
-                (true, true, false) => el.hover_leave(),
-                (true, false, true) => el.hover_enter(),
-                (false, true, _) => el.hover_leave(),
-                _ => (),
+            let Some(interface) = el.as_interface_mut() else {
+                continue;
+            };
+
+            if let Some(owner) = outlines.get(&interface.owner()) {
+                interface.attach(*owner);
             }
         }
     }
 
-    pub fn draw(&mut self, canvas: &impl Canvas, camera: &Camera) {
-        let clear_rect: Element = Rectangle::new(
-            i32::MIN,
-            i32::MIN,
-            u32::MAX,
-            u32::MAX,
-            self.color,
-            None,
-            None,
-        )
-        .into();
-
-        clear_rect.draw(canvas, camera);
-
-        const SIZE: u32 = 2;
-        const SPACE: f64 = 75.0;
-        let offx = SPACE - (camera.x() % SPACE);
-        let offy = SPACE - (camera.y() % SPACE);
-        let dot_color = Color::Rgb {
-            red: 170,
-            green: 170,
-            blue: 170,
+    /// Draws the anchor lines of every note to the current outlines of the
+    /// elements it annotates. Anchors to elements that no longer exist are
+    /// left out.
+    pub fn attach_notes(&mut self) {
+        let outlines = self
+            .elements
+            .iter()
+            .map(|el| (el.id(), el.outline()))
+            .collect::<HashMap<_, _>>();
+
+        for el in &mut self.elements {
+            let Some(note) = el.as_note_mut() else {
+                continue;
+            };
+
+            let targets = note
+                .anchors()
+                .iter()
+                .filter_map(|id| outlines.get(id))
+                .copied()
+                .collect::<Vec<_>>();
+
+            note.attach(&targets);
+        }
+    }
+
+    /// Links every instance specification to its class, and checks every link
+    /// typed by an association against the classes of the instances it
+    /// connects. Links whose association no longer exists, or connects other
+    /// classes, are marked as mistyped.
+    pub fn attach_instances(&mut self) {
+        let classes = self
+            .elements
+            .iter()
+            .filter_map(|el| Some((el.id(), el.as_class()?)))
+            .map(|(id, class)| {
+                let attributes = class
+                    .attributes()
+                    .map(|a| Member::parse(a).name().to_string())
+                    .collect::<Vec<_>>();
+                (id, (class.name().to_string(), attributes))
+            })
+            .collect::<HashMap<_, _>>();
+
+        let instances = self
+            .elements
+            .iter()
+            .filter_map(|el| Some((el.id(), el.as_instance_specification()?)))
+            .map(|(id, instance)| (id, instance.class()))
+            .collect::<HashMap<_, _>>();
+
+        let associations = self
+            .elements
+            .iter()
+            .filter_map(|el| Some((el.id(), el.as_relationship()?)))
+            .map(|(id, r)| {
+                let name = r.label().map(String::from);
+                (id, (r.source(), r.target(), name))
+            })
+            .collect::<HashMap<_, _>>();
+
+        for el in &mut self.elements {
+            if let Some(instance) = el.as_instance_specification_mut() {
+                let title = instance.title().to_string();
+                let class = instance.class().and_then(|id| classes.get(&id));
+                instance.attach(class.cloned());
+
+                // Only a new title changes the size of an instance.
+                if instance.title() != title {
+                    el.invalidate();
+                }
+            } else if let Some(link) = el.as_relationship_mut() {
+                let (name, mistyped) = match link
+                    .association()
+                    .map(|id| associations.get(&id))
+                {
+                    None => (None, false),
+                    Some(None) => (None, true),
+                    Some(Some((source, target, name))) => {
+                        let ends = (
+                            instances.get(&link.source()).copied().flatten(),
+                            instances.get(&link.target()).copied().flatten(),
+                        );
+                        let typed = ends == (Some(*source), Some(*target))
+                            || ends == (Some(*target), Some(*source));
+
+                        (name.clone(), !typed)
+                    }
+                };
+
+                link.attach_association(name, mistyped);
+            }
+        }
+    }
+
+    /// Routes every relationship between the current outlines of the elements
+    /// it connects. Relationships whose ends no longer exist are left as is.
+    pub fn attach_relationships(&mut self) {
+        let outlines = self
+            .elements
+            .iter()
+            .filter(|el| el.as_relationship().is_none())
+            .map(|el| (el.id(), el.outline()))
+            .collect::<HashMap<_, _>>();
+
+        for el in &mut self.elements {
+            let Some(relationship) = el.as_relationship_mut() else {
+                continue;
+            };
+
+            let (Some(source), Some(target)) = (
+                outlines.get(&relationship.source()),
+                outlines.get(&relationship.target()),
+            ) else {
+                continue;
+            };
+
+            relationship.attach_outlines(*source, *target);
+        }
+    }
+
+    /// Lays out the elements of sequence diagrams. Activations are placed on
+    /// their lifelines, messages are routed between the lifelines at their
+    /// own height, leaving from and arriving at activation bars where there
+    /// are any, fragments are stretched over the lifelines they cover and
+    /// lifelines are fitted to the messages and fragments on them.
+    pub fn attach_messages(&mut self) {
+        let mut created = HashMap::new();
+        let mut destroyed = HashMap::new();
+        let mut last_event: HashMap<Id, f64> = HashMap::new();
+
+        for el in &self.elements {
+            if let Some(message) = el.as_message() {
+                let y = message.y();
+
+                match message.kind() {
+                    MessageKind::Create => {
+                        created.entry(message.target()).or_insert(y);
+                    }
+                    MessageKind::Destroy => {
+                        destroyed.entry(message.target()).or_insert(y);
+                    }
+                    _ => (),
+                }
+
+                for id in [message.source(), message.target()] {
+                    let last = last_event.entry(id).or_insert(f64::MIN);
+                    *last = f64::max(*last, message.bottom());
+                }
+            }
+
+            if let Some(activation) = el.as_activation() {
+                let last =
+                    last_event.entry(activation.lifeline()).or_insert(f64::MIN);
+                *last = f64::max(*last, activation.end());
+            }
+
+            if let Some(fragment) = el.as_fragment() {
+                for id in fragment.lifelines() {
+                    let last = last_event.entry(*id).or_insert(f64::MIN);
+                    *last = f64::max(*last, fragment.bottom());
+                }
+            }
+        }
+
+        let mut lifelines = HashMap::new();
+
+        for el in &mut self.elements {
+            let id = el.id();
+            let Some(lifeline) = el.as_lifeline_mut() else {
+                continue;
+            };
+
+            lifeline.attach(
+                created.get(&id).copied(),
+                destroyed.get(&id).copied(),
+                last_event.get(&id).copied(),
+            );
+            lifelines.insert(id, (lifeline.center(), lifeline.header_bounds()));
+        }
+
+        let frames = self
+            .elements
+            .iter()
+            .filter_map(|el| el.as_fragment())
+            .map(|f| (f.lifelines().to_vec(), f.y(), f.bottom()))
+            .collect::<Vec<_>>();
+        let mut index = 0;
+
+        for el in &mut self.elements {
+            let Some(fragment) = el.as_fragment_mut() else {
+                continue;
+            };
+
+            let headers = fragment
+                .lifelines()
+                .iter()
+                .filter_map(|id| lifelines.get(id))
+                .map(|(_, header)| header);
+            let left = headers.clone().map(|h| h.left()).reduce(f64::min);
+            let right = headers.map(|h| h.right()).reduce(f64::max);
+
+            // A fragment is nested in every other one that encloses it and
+            // shares a lifeline with it; of two equal ones, the later one is
+            // nested in the earlier.
+            let (covered, top, bottom) = &frames[index];
+            let depth = frames
+                .iter()
+                .enumerate()
+                .filter(|(i, (other, t, b))| {
+                    other.iter().any(|id| covered.contains(id))
+                        && t <= top
+                        && b >= bottom
+                        && (*i < index || (t, b) != (top, bottom))
+                })
+                .count();
+
+            if let (Some(left), Some(right)) = (left, right) {
+                fragment.attach((left, right), depth as f64);
+            }
+
+            index += 1;
+        }
+
+        let ranges = self
+            .elements
+            .iter()
+            .filter_map(|el| el.as_activation())
+            .map(|a| (a.lifeline(), a.start(), a.end()))
+            .collect::<Vec<_>>();
+        let mut bars: HashMap<Id, Vec<Rect>> = HashMap::new();
+        index = 0;
+
+        for el in &mut self.elements {
+            let Some(activation) = el.as_activation_mut() else {
+                continue;
+            };
+
+            let Some((center, _)) = lifelines.get(&activation.lifeline())
+            else {
+                index += 1;
+                continue;
+            };
+
+            // An activation is nested in every other one on the same
+            // lifeline that encloses it; of two equal ones, the later one
+            // is nested in the earlier.
+            let (lifeline, start, end) = ranges[index];
+            let depth = ranges
+                .iter()
+                .enumerate()
+                .filter(|(i, (other, s, e))| {
+                    *other == lifeline
+                        && *s <= start
+                        && *e >= end
+                        && (*i < index || (*s, *e) != (start, end))
+                })
+                .count();
+
+            activation.attach(*center, depth as f64);
+            bars.entry(lifeline).or_default().push(activation.bounds());
+            index += 1;
+        }
+
+        for el in &mut self.elements {
+            let Some(message) = el.as_message_mut() else {
+                continue;
+            };
+
+            let (Some(source), Some(target)) = (
+                lifelines.get(&message.source()),
+                lifelines.get(&message.target()),
+            ) else {
+                continue;
+            };
+
+            let y = message.y();
+            let no_bars = vec![];
+            let source_bars = bars.get(&message.source()).unwrap_or(&no_bars);
+            let target_bars = bars.get(&message.target()).unwrap_or(&no_bars);
+
+            // Messages to self leave and return on the right.
+            let is_self = message.source() == message.target();
+            let leaves_right = is_self || source.0 <= target.0;
+            let arrives_right = is_self || source.0 > target.0;
+
+            let from = lifeline_edge(source.0, source_bars, y, leaves_right);
+            let to = match (message.kind(), arrives_right) {
+                (MessageKind::Create, true) => target.1.right(),
+                (MessageKind::Create, false) => target.1.left(),
+                _ => lifeline_edge(target.0, target_bars, y, arrives_right),
+            };
+
+            message.attach(from, to);
+        }
+    }
+
+    /// The topmost element at (`x`, `y`), if any, as stacked by
+    /// [`Document::draw`].
+    pub fn element_at(&mut self, x: f64, y: f64) -> Option<Id> {
+        self.ensure_index();
+        if !self.local.settled {
+            self.local.parents = self.parents();
+        }
+
+        let mut candidates = self.local.index.at(x, y);
+        candidates
+            .sort_unstable_by_key(|position| Reverse(self.stacking(*position)));
+
+        candidates
+            .into_iter()
+            .map(|position| &self.elements[position])
+            .find(|el| el.cursor_intersects(x, y))
+            .map(|el| el.id())
+    }
+
+    /// The elements lying entirely within `area`, in document order, such as
+    /// those picked by a marquee selection.
+    pub fn elements_within(&mut self, area: Rect) -> Vec<Id> {
+        self.ensure_index();
+
+        let mut positions = self.local.index.query(area);
+        positions.sort_unstable();
+
+        positions
+            .into_iter()
+            .map(|position| &self.elements[position])
+            .filter(|el| area.contains_rect(el.bounds()))
+            .map(|el| el.id())
+            .collect()
+    }
+
+    /// Selects the elements in `ids`, and deselects the ones selected
+    /// before.
+    pub fn select(&mut self, ids: impl IntoIterator<Item = Id>) {
+        let ids = ids.into_iter().collect::<HashSet<_>>();
+
+        for id in std::mem::take(&mut self.local.selected) {
+            if !ids.contains(&id)
+                && let Some(position) = self.locate(id)
+            {
+                self.elements[position]
+                    .get_interaction_mut()
+                    .set_selected(false);
+            }
+        }
+
+        for id in ids {
+            if let Some(position) = self.locate(id) {
+                self.elements[position]
+                    .get_interaction_mut()
+                    .set_selected(true);
+                self.local.selected.insert(id);
+            }
+        }
+    }
+
+    /// The elements picked by the last selection.
+    pub fn selection(&self) -> &HashSet<Id> {
+        &self.local.selected
+    }
+
+    /// Marks `id` as hovered or not. Only its interaction state changes, so
+    /// the document stays settled and the element keeps its layout.
+    pub fn hover(&mut self, id: Id, hovered: bool) {
+        let Some(position) = self.locate(id) else {
+            return;
         };
 
-        for row in -1..100 {
-            for col in -1..100 {
-                let x = (row as f64 * SPACE) + offx;
-                let y = (col as f64 * SPACE) + offy;
-                let rect = Rectangle::new(
-                    x as _, y as _, SIZE, SIZE, dot_color, None, None,
-                );
-                rect.draw_fixed(canvas);
+        self.elements[position]
+            .get_interaction_mut()
+            .set_hover(hovered);
+
+        match hovered {
+            true => self.local.hovered.insert(id),
+            false => self.local.hovered.remove(&id),
+        };
+    }
+
+    /// Clicks `id` at (`x`, `y`), returning what to ask the user for, if
+    /// anything. Clicking does not change the element, so the document stays
+    /// settled.
+    pub fn click(&mut self, id: Id, x: f64, y: f64) -> Option<Prompt> {
+        let position = self.locate(id)?;
+        let el = &mut self.elements[position];
+        el.click(x - el.x(), y - el.y())
+    }
+
+    pub fn update_cursor(&mut self, x: f64, y: f64, visible: bool) {
+        self.ensure_index();
+
+        // Only the elements hovered before can be left.
+        for id in std::mem::take(&mut self.local.hovered) {
+            let Some(position) = self.locate(id) else {
+                continue;
+            };
+            let el = &mut self.elements[position];
+
+            if visible && el.cursor_intersects(x, y) {
+                self.local.hovered.insert(id);
+            } else {
+                el.hover_leave();
+            }
+        }
+
+        if !visible {
+            return;
+        }
+
+        for position in self.local.index.at(x, y) {
+            let el = &mut self.elements[position];
+
+            if !el.is_hovered() && el.cursor_intersects(x, y) {
+                el.hover_enter();
+                self.local.hovered.insert(el.id());
             }
         }
+    }
+
+    /// Brings the spatial index up to date with the bounds of every element,
+    /// and the positions of the elements with the document.
+    fn reindex(&mut self) {
+        let count = self.elements.len();
+        self.local.index.retain(|position| position < count);
+
+        for (position, el) in self.elements.iter().enumerate() {
+            self.local.index.insert(position, index_bounds(el));
+        }
+
+        if self.local.positions.len() != count {
+            self.rebuild_positions();
+        }
+    }
+
+    /// Where the element at `position` is stacked: by [`draw_order`], nested
+    /// containers above the ones they are in, and otherwise in document
+    /// order.
+    fn stacking(&self, position: usize) -> (u8, usize, usize) {
+        let el = &self.elements[position];
+        (
+            draw_order(el),
+            depth(&self.local.parents, el.id()),
+            position,
+        )
+    }
+
+    /// Rebuilds the spatial index if elements were added or removed without
+    /// going through the document. Moves are picked up on the next draw.
+    fn ensure_index(&mut self) {
+        if self.local.index.len() != self.elements.len() {
+            self.reindex();
+        }
+    }
+
+    /// Draws the elements within the part of the document shown by the
+    /// camera. Elements outside of it are neither measured nor drawn, but
+    /// are still laid out, so relationships to them stay in place.
+    ///
+    /// Elements are only attached to each other again after the document
+    /// changed or newly visible elements were laid out.
+    pub fn draw(&mut self, canvas: &impl Canvas, camera: &Camera) {
+        let viewport = camera.viewport(canvas.size()).inflate(CULL_MARGIN);
+
+        canvas.draw_grid(&Grid::new(self.color), camera);
 
-        for element in &mut self.elements {
-            element.initalize(canvas);
-            element.draw(canvas, camera);
+        if !self.local.settled {
+            self.attach_instances();
+            // Notes are visible wherever their anchor lines are.
+            self.attach_notes();
+            self.reindex();
+        } else {
+            self.ensure_index();
+        }
+
+        // Only elements that changed since they were last drawn are laid
+        // out again.
+        let mut laid_out = false;
+        for position in self.local.index.query(viewport) {
+            let element = &mut self.elements[position];
+            if element.needs_layout()
+                && element.drawn_bounds().intersects(viewport)
+            {
+                element.initalize(canvas);
+                laid_out = true;
+            }
+        }
+
+        if laid_out || !self.local.settled {
+            self.attach_containers();
+            self.attach_ports();
+            self.attach_relationships();
+            self.attach_messages();
+            self.attach_notes();
+
+            // Keys whose bounds stayed the same are left in place.
+            self.reindex();
+            self.local.parents = self.parents();
+            self.local.settled = true;
+        }
+
+        let mut positions = self.local.index.query(viewport);
+        positions.sort_unstable_by_key(|position| self.stacking(*position));
+
+        for position in positions {
+            self.elements[position].draw(canvas, camera);
         }
 
         if self.local.show_info {
@@ -116,7 +896,95 @@ impl Default for Document {
             local: LocalData {
                 show_info: false,
                 info_element: Info::default(),
+                index: SpatialIndex::default(),
+                positions: HashMap::new(),
+                settled: false,
+                parents: HashMap::new(),
+                hovered: HashSet::new(),
+                selected: HashSet::new(),
             },
         }
     }
 }
+
+/// Deserializes the elements of a document, rejecting documents in which
+/// two elements share an id.
+fn unique_elements<'de, D>(deserializer: D) -> Result<Vec<Element>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    let elements = Vec::<Element>::deserialize(deserializer)?;
+    let mut ids = HashSet::new();
+
+    match elements.iter().find(|el| !ids.insert(el.id())) {
+        Some(el) => Err(D::Error::custom(format!(
+            "more than one element has the id {}",
+            el.id()
+        ))),
+        None => Ok(elements),
+    }
+}
+
+/// The bounds an element is kept under in the spatial index.
+fn index_bounds(el: &Element) -> Rect {
+    el.drawn_bounds().inflate(HIT_MARGIN)
+}
+
+/// How many containers `id` is nested in, given the container of each
+/// element.
+fn depth(parents: &HashMap<Id, Id>, id: Id) -> usize {
+    let mut depth = 0;
+    let mut current = id;
+
+    // Bounded, in case a hand-edited document nests containers in a cycle.
+    while let Some(parent) = parents.get(&current)
+        && depth <= parents.len()
+    {
+        depth += 1;
+        current = *parent;
+    }
+
+    depth
+}
+
+/// Lifelines, system boundaries, deployment nodes and containers are drawn
+/// below everything on them, and relationships below the elements they
+/// connect.
+fn draw_order(el: &Element) -> u8 {
+    if el.as_lifeline().is_some()
+        || el.as_system_boundary().is_some()
+        || el.as_swimlane().is_some()
+        || el.as_deployment_node().is_some()
+        || el.as_package().is_some()
+        || el.as_c4_boundary().is_some()
+        || el.as_state().is_some_and(|s| s.is_composite())
+    {
+        0
+    } else if el.as_fragment().is_some() {
+        1
+    } else if el.as_relationship().is_some() {
+        2
+    } else if el.as_activation().is_some() {
+        3
+    } else if el.as_message().is_some() {
+        4
+    } else {
+        5
+    }
+}
+
+/// Where a message at height `y` meets a lifeline whose line is at `center`:
+/// the left or right side of the innermost activation bar at that height, or
+/// the line itself.
+fn lifeline_edge(center: f64, bars: &[Rect], y: f64, right: bool) -> f64 {
+    let innermost = bars
+        .iter()
+        .filter(|bar| bar.top() <= y && y <= bar.bottom())
+        .max_by(|a, b| a.left().total_cmp(&b.left()));
+
+    match innermost {
+        Some(bar) if right => bar.right(),
+        Some(bar) => bar.left(),
+        None => center,
+    }
+}
@@ -1,4 +1,4 @@
-use crate::{camera::Camera, canvas::Canvas};
+use crate::{camera::Camera, canvas::Canvas, geometry::Rect};
 
 #[allow(unused_variables)]
 pub trait Drawable {
@@ -6,6 +6,9 @@ pub trait Drawable {
 
     fn draw(&self, canvas: &impl Canvas, camera: &Camera);
 
+    /// The area the drawable covers, in document coordinates.
+    fn bounds(&self) -> Rect;
+
     fn draw_fixed(&self, canvas: &impl Canvas) {
         self.draw(canvas, &Camera::default());
     }
@@ -0,0 +1,141 @@
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    camera::Camera,
+    canvas::Canvas,
+    color::{Color, WHITE},
+    drawable::Drawable,
+    geometry::Rect,
+    id::Id,
+    interaction::{InteractionState, Interactive},
+    stroke::Stroke,
+};
+
+use super::Rectangle;
+
+const DEFAULT_STROKE: Stroke = const {
+    Stroke::new(
+        2,
+        Color::Rgb {
+            red: 90,
+            green: 90,
+            blue: 90,
+        },
+    )
+};
+
+const HIGHLIGHT_STROKE: Stroke = const {
+    Stroke::new(
+        2,
+        Color::Rgb {
+            red: 142,
+            green: 202,
+            blue: 230,
+        },
+    )
+};
+
+const WIDTH: f64 = 12.0;
+
+/// A bar on a lifeline showing when its participant is active. Nested
+/// activations (e.g. a call to self) are shifted to the right of the ones
+/// they are nested in.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Activation {
+    lifeline: Id,
+    start: f64,
+    end: f64,
+
+    #[serde(skip)]
+    x: f64,
+    #[serde(skip)]
+    interaction_state: InteractionState,
+}
+
+impl Activation {
+    pub fn new(lifeline: Id, start: f64, end: f64) -> Self {
+        Self {
+            lifeline,
+            start: f64::min(start, end),
+            end: f64::max(start, end),
+            x: 0.0,
+            interaction_state: InteractionState::default(),
+        }
+    }
+
+    pub fn lifeline(&self) -> Id {
+        self.lifeline
+    }
+
+    pub fn start(&self) -> f64 {
+        self.start
+    }
+
+    pub fn end(&self) -> f64 {
+        self.end
+    }
+
+    pub fn x(&self) -> f64 {
+        self.x
+    }
+
+    pub fn y(&self) -> f64 {
+        self.start
+    }
+
+    pub fn width(&self) -> f64 {
+        WIDTH
+    }
+
+    pub fn height(&self) -> f64 {
+        self.end - self.start
+    }
+
+    /// Places the bar on the line of its lifeline at `center`, shifted by
+    /// half a bar for every activation it is nested in.
+    pub fn attach(&mut self, center: f64, depth: f64) {
+        self.x = center - WIDTH / 2.0 + (depth * WIDTH / 2.0);
+    }
+}
+
+impl Drawable for Activation {
+    fn draw(&self, canvas: &impl Canvas, camera: &Camera) {
+        let stroke = if self.is_highlighted() {
+            HIGHLIGHT_STROKE
+        } else {
+            DEFAULT_STROKE
+        };
+
+        Rectangle::new(
+            self.x,
+            self.start,
+            WIDTH,
+            self.height(),
+            WHITE,
+            None,
+            Some(stroke),
+        )
+        .draw(canvas, camera);
+    }
+
+    fn bounds(&self) -> Rect {
+        Rect::from_edges(self.x, self.start, self.x + WIDTH, self.end)
+    }
+}
+
+impl Interactive for Activation {
+    fn get_interaction(&self) -> InteractionState {
+        self.interaction_state
+    }
+
+    fn get_interaction_mut(&mut self) -> &mut InteractionState {
+        &mut self.interaction_state
+    }
+
+    /// Activations stay on their lifeline, so they can only be moved up and
+    /// down.
+    fn adjust_position(&mut self, _: f64, delta_y: f64) {
+        self.start += delta_y;
+        self.end += delta_y;
+    }
+}
@@ -0,0 +1,195 @@
+use std::rc::Rc;
+
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    camera::Camera,
+    canvas::Canvas,
+    color::{Color, WHITE},
+    drawable::Drawable,
+    geometry::Rect,
+    interaction::{InteractionState, Interactive},
+    prompt::{Prompt, PromptResponse},
+    stroke::Stroke,
+};
+
+use super::{Label, Path, Rectangle, TextProperties};
+
+const DEFAULT_STROKE: Stroke = const {
+    Stroke::new(
+        2,
+        Color::Rgb {
+            red: 90,
+            green: 90,
+            blue: 90,
+        },
+    )
+};
+
+const HIGHLIGHT_STROKE: Stroke = const {
+    Stroke::new(
+        2,
+        Color::Rgb {
+            red: 142,
+            green: 202,
+            blue: 230,
+        },
+    )
+};
+
+const TEXT_COLOR: Color = const {
+    Color::Rgb {
+        red: 31,
+        green: 31,
+        blue: 31,
+    }
+};
+
+const FIGURE_WIDTH: f64 = 40.0;
+const FIGURE_HEIGHT: f64 = 80.0;
+const HEAD_SIZE: f64 = 20.0;
+/// Height of the shoulders and hips, measured from the top of the figure.
+const SHOULDERS: f64 = 30.0;
+const HIPS: f64 = 52.0;
+const SPACING: f64 = 6.0;
+
+/// Someone or something interacting with the system of a use-case diagram,
+/// drawn as a stick figure with its name below.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Actor {
+    x: f64,
+    y: f64,
+    title: Label,
+
+    #[serde(skip)]
+    interaction_state: InteractionState,
+}
+
+impl Actor {
+    pub fn new(x: f64, y: f64, name: String) -> Self {
+        Self {
+            x,
+            y,
+            title: Label::new(
+                0.0,
+                0.0,
+                name,
+                TextProperties::default(),
+                TEXT_COLOR,
+            ),
+            interaction_state: InteractionState::default(),
+        }
+    }
+
+    pub fn name(&self) -> &str {
+        self.title.text()
+    }
+
+    pub fn set_name(&mut self, value: String) {
+        self.title.set_text(value);
+    }
+
+    pub fn x(&self) -> f64 {
+        self.x
+    }
+
+    pub fn y(&self) -> f64 {
+        self.y
+    }
+
+    pub fn width(&self) -> f64 {
+        let width = self.title.width().unwrap_or(0.0);
+        f64::max(width, FIGURE_WIDTH)
+    }
+
+    pub fn height(&self) -> f64 {
+        let name = self.title.height().unwrap_or(0.0);
+        FIGURE_HEIGHT + SPACING + name
+    }
+}
+
+impl Drawable for Actor {
+    fn initalize(&mut self, canvas: &impl Canvas) {
+        self.title.initalize(canvas);
+
+        let offset = (self.width() - self.title.width().unwrap_or(0.0)) / 2.0;
+        self.title
+            .set_position(self.x + offset, self.y + FIGURE_HEIGHT + SPACING);
+    }
+
+    fn draw(&self, canvas: &impl Canvas, camera: &Camera) {
+        let stroke = if self.is_highlighted() {
+            HIGHLIGHT_STROKE
+        } else {
+            DEFAULT_STROKE
+        };
+
+        let (x, y) = (self.x + self.width() / 2.0, self.y);
+        let half = FIGURE_WIDTH / 2.0;
+
+        Rectangle::new(
+            x - HEAD_SIZE / 2.0,
+            y,
+            HEAD_SIZE,
+            HEAD_SIZE,
+            WHITE,
+            Some(HEAD_SIZE / 2.0),
+            Some(stroke),
+        )
+        .draw(canvas, camera);
+
+        for (from, to) in [
+            ((x, y + HEAD_SIZE), (x, y + HIPS)),
+            ((x - half, y + SHOULDERS), (x + half, y + SHOULDERS)),
+            ((x, y + HIPS), (x - half, y + FIGURE_HEIGHT)),
+            ((x, y + HIPS), (x + half, y + FIGURE_HEIGHT)),
+        ] {
+            Path::line(from, to, stroke).draw(canvas, camera);
+        }
+
+        self.title.draw(canvas, camera);
+    }
+
+    fn bounds(&self) -> Rect {
+        Rect::new(self.x, self.y, self.width(), self.height())
+    }
+}
+
+impl Interactive for Actor {
+    fn get_interaction(&self) -> InteractionState {
+        self.interaction_state
+    }
+
+    fn get_interaction_mut(&mut self) -> &mut InteractionState {
+        &mut self.interaction_state
+    }
+
+    fn adjust_position(&mut self, delta_x: f64, delta_y: f64) {
+        self.x += delta_x;
+        self.y += delta_y;
+        self.title.adjust_position(delta_x, delta_y);
+    }
+
+    #[allow(unused_variables)]
+    fn click(&mut self, x: f64, y: f64) -> Option<Prompt> {
+        Some(Prompt::Text {
+            explanation: "Provide this actor with a new name".into(),
+            placeholder: "Actor name".into(),
+            value: self.title.text().to_string(),
+            metadata: Rc::new(()),
+        })
+    }
+
+    fn prompt(&mut self, response: PromptResponse) {
+        let PromptResponse::Text {
+            response,
+            metadata: _,
+        } = response;
+
+        if response.is_empty() {
+            return;
+        }
+
+        self.title.set_text(response);
+    }
+}
@@ -0,0 +1,238 @@
+use std::rc::Rc;
+
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    camera::Camera,
+    canvas::Canvas,
+    color::Color,
+    drawable::Drawable,
+    geometry::Rect,
+    interaction::{InteractionState, Interactive},
+    prompt::{Prompt, PromptResponse},
+    stroke::Stroke,
+};
+
+use super::{Label, Path, Rectangle, TextProperties};
+
+const DEFAULT_COLOR: Color = const {
+    Color::Rgb {
+        red: 252,
+        green: 252,
+        blue: 252,
+    }
+};
+
+const DEFAULT_STROKE: Stroke = const {
+    Stroke::new(
+        2,
+        Color::Rgb {
+            red: 90,
+            green: 90,
+            blue: 90,
+        },
+    )
+};
+
+const HIGHLIGHT_STROKE: Stroke = const {
+    Stroke::new(
+        2,
+        Color::Rgb {
+            red: 142,
+            green: 202,
+            blue: 230,
+        },
+    )
+};
+
+const TEXT_COLOR: Color = const {
+    Color::Rgb {
+        red: 31,
+        green: 31,
+        blue: 31,
+    }
+};
+
+const MARGIN: f64 = 10.0;
+const SPACING: f64 = 4.0;
+const MIN_WIDTH: f64 = 120.0;
+const MIN_HEIGHT: f64 = 50.0;
+/// Size of the document icon in the top right corner, and of its fold.
+const ICON_WIDTH: f64 = 14.0;
+const ICON_HEIGHT: f64 = 18.0;
+const FOLD: f64 = 5.0;
+
+/// A file or other physical piece of information deployed on a node, drawn
+/// as a rectangle with the `«artifact»` keyword above its name and a
+/// document icon in the top right corner.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Artifact {
+    x: f64,
+    y: f64,
+    title: Label,
+
+    #[serde(skip, default = "keyword")]
+    keyword: Label,
+    #[serde(skip)]
+    interaction_state: InteractionState,
+}
+
+impl Artifact {
+    pub fn new(x: f64, y: f64, name: String) -> Self {
+        Self {
+            x,
+            y,
+            title: Label::new(
+                0.0,
+                0.0,
+                name,
+                TextProperties::default(),
+                TEXT_COLOR,
+            ),
+            keyword: keyword(),
+            interaction_state: InteractionState::default(),
+        }
+    }
+
+    pub fn name(&self) -> &str {
+        self.title.text()
+    }
+
+    pub fn set_name(&mut self, value: String) {
+        self.title.set_text(value);
+    }
+
+    pub fn x(&self) -> f64 {
+        self.x
+    }
+
+    pub fn y(&self) -> f64 {
+        self.y
+    }
+
+    /// The text is kept clear of the icon on both sides.
+    pub fn width(&self) -> f64 {
+        let text = [&self.keyword, &self.title]
+            .map(|l| l.width().unwrap_or(0.0))
+            .into_iter()
+            .fold(0.0, f64::max);
+        f64::max(text + 2.0 * (2.0 * MARGIN + ICON_WIDTH), MIN_WIDTH)
+    }
+
+    pub fn height(&self) -> f64 {
+        f64::max(self.text_height() + 2.0 * MARGIN, MIN_HEIGHT)
+    }
+
+    fn text_height(&self) -> f64 {
+        self.keyword.height().unwrap_or(0.0)
+            + SPACING
+            + self.title.height().unwrap_or(0.0)
+    }
+}
+
+fn keyword() -> Label {
+    Label::new(
+        0.0,
+        0.0,
+        "«artifact»",
+        TextProperties::default(),
+        TEXT_COLOR,
+    )
+}
+
+impl Drawable for Artifact {
+    fn initalize(&mut self, canvas: &impl Canvas) {
+        self.keyword.initalize(canvas);
+        self.title.initalize(canvas);
+
+        let (width, height) = (self.width(), self.height());
+        let mut offset_y = self.y + ((height - self.text_height()) / 2.0);
+
+        for label in [&mut self.keyword, &mut self.title] {
+            let offset_x = (width - label.width().unwrap_or(0.0)) / 2.0;
+            label.set_position(self.x + offset_x, offset_y);
+            offset_y = label.y() + SPACING;
+        }
+    }
+
+    fn draw(&self, canvas: &impl Canvas, camera: &Camera) {
+        let stroke = if self.is_highlighted() {
+            HIGHLIGHT_STROKE
+        } else {
+            DEFAULT_STROKE
+        };
+
+        Rectangle::new(
+            self.x,
+            self.y,
+            self.width(),
+            self.height(),
+            DEFAULT_COLOR,
+            None,
+            Some(stroke),
+        )
+        .draw(canvas, camera);
+
+        let r = self.x + self.width() - MARGIN;
+        let (l, t) = (r - ICON_WIDTH, self.y + MARGIN);
+        let b = t + ICON_HEIGHT;
+        Path::new(
+            vec![(l, t), (r - FOLD, t), (r, t + FOLD), (r, b), (l, b)],
+            Some(stroke),
+        )
+        .closed(Some(DEFAULT_COLOR))
+        .draw(canvas, camera);
+        Path::new(
+            vec![(r - FOLD, t), (r - FOLD, t + FOLD), (r, t + FOLD)],
+            Some(stroke),
+        )
+        .draw(canvas, camera);
+
+        self.keyword.draw(canvas, camera);
+        self.title.draw(canvas, camera);
+    }
+
+    fn bounds(&self) -> Rect {
+        Rect::new(self.x, self.y, self.width(), self.height())
+    }
+}
+
+impl Interactive for Artifact {
+    fn get_interaction(&self) -> InteractionState {
+        self.interaction_state
+    }
+
+    fn get_interaction_mut(&mut self) -> &mut InteractionState {
+        &mut self.interaction_state
+    }
+
+    fn adjust_position(&mut self, delta_x: f64, delta_y: f64) {
+        self.x += delta_x;
+        self.y += delta_y;
+        self.keyword.adjust_position(delta_x, delta_y);
+        self.title.adjust_position(delta_x, delta_y);
+    }
+
+    #[allow(unused_variables)]
+    fn click(&mut self, x: f64, y: f64) -> Option<Prompt> {
+        Some(Prompt::Text {
+            explanation: "Provide this artifact with a new name".into(),
+            placeholder: "Artifact name".into(),
+            value: self.title.text().to_string(),
+            metadata: Rc::new(()),
+        })
+    }
+
+    fn prompt(&mut self, response: PromptResponse) {
+        let PromptResponse::Text {
+            response,
+            metadata: _,
+        } = response;
+
+        if response.is_empty() {
+            return;
+        }
+
+        self.title.set_text(response);
+    }
+}
@@ -0,0 +1,300 @@
+use std::rc::Rc;
+
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    camera::Camera,
+    canvas::Canvas,
+    color::Color,
+    drawable::Drawable,
+    geometry::{Point, Rect},
+    id::Id,
+    interaction::{InteractionState, Interactive},
+    prompt::{Prompt, PromptResponse},
+    stroke::Stroke,
+};
+
+use super::{C4Kind, Label, Path, TextProperties};
+
+const DEFAULT_STROKE: Stroke = const {
+    Stroke::new(
+        2,
+        Color::Rgb {
+            red: 140,
+            green: 140,
+            blue: 140,
+        },
+    )
+};
+
+const HIGHLIGHT_STROKE: Stroke = const {
+    Stroke::new(
+        2,
+        Color::Rgb {
+            red: 142,
+            green: 202,
+            blue: 230,
+        },
+    )
+};
+
+const TEXT_COLOR: Color = const {
+    Color::Rgb {
+        red: 31,
+        green: 31,
+        blue: 31,
+    }
+};
+
+const MARGIN: f64 = 10.0;
+const SPACING: f64 = 4.0;
+const MIN_WIDTH: f64 = 240.0;
+const MIN_HEIGHT: f64 = 160.0;
+/// Space between the border of a boundary and the elements in it.
+const PADDING: f64 = 20.0;
+const HIT_DISTANCE: f64 = 6.0;
+
+/// A dashed box around the C4 elements that make up a software system or a
+/// container, or that belong to an enterprise. The name and the kind of the
+/// boundary (e.g. `[Software System]`) are written in its top left corner.
+/// Like packages, boundaries grow to enclose their elements and move them
+/// along, and can only be grabbed by their header and border once they have
+/// elements.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct C4Boundary {
+    x: f64,
+    y: f64,
+    title: Label,
+    /// What the boundary is the boundary of, if anything.
+    #[serde(default)]
+    kind: Option<C4Kind>,
+    #[serde(default)]
+    elements: Vec<Id>,
+
+    #[serde(skip, default = "kind_label")]
+    kind_label: Label,
+    /// The bounds of the elements.
+    #[serde(skip)]
+    contents: Option<Rect>,
+    #[serde(skip)]
+    interaction_state: InteractionState,
+}
+
+impl C4Boundary {
+    pub fn new(x: f64, y: f64, name: String, kind: Option<C4Kind>) -> Self {
+        let mut boundary = Self {
+            x,
+            y,
+            title: Label::new(
+                0.0,
+                0.0,
+                name,
+                TextProperties::default().weight(700),
+                TEXT_COLOR,
+            ),
+            kind,
+            elements: vec![],
+            kind_label: kind_label(),
+            contents: None,
+            interaction_state: InteractionState::default(),
+        };
+        boundary.refresh_kind();
+        boundary
+    }
+
+    pub fn name(&self) -> &str {
+        self.title.text()
+    }
+
+    pub fn set_name(&mut self, value: String) {
+        self.title.set_text(value);
+    }
+
+    pub fn kind(&self) -> Option<C4Kind> {
+        self.kind
+    }
+
+    pub fn set_kind(&mut self, value: Option<C4Kind>) {
+        self.kind = value;
+        self.refresh_kind();
+    }
+
+    fn refresh_kind(&mut self) {
+        let text = self
+            .kind
+            .map(|kind| format!("[{}]", kind.name()))
+            .unwrap_or_default();
+        self.kind_label.set_text(text);
+    }
+
+    /// The elements within this boundary.
+    pub fn elements(&self) -> &[Id] {
+        &self.elements
+    }
+
+    pub fn add_element(&mut self, id: Id) {
+        if !self.elements.contains(&id) {
+            self.elements.push(id);
+        }
+    }
+
+    pub fn remove_element(&mut self, id: Id) {
+        self.elements.retain(|e| *e != id);
+    }
+
+    pub fn x(&self) -> f64 {
+        self.bounds().left()
+    }
+
+    pub fn y(&self) -> f64 {
+        self.y
+    }
+
+    fn header_height(&self) -> f64 {
+        let mut height = self.title.height().unwrap_or(0.0) + 2.0 * MARGIN;
+
+        if self.kind.is_some() {
+            height += self.kind_label.height().unwrap_or(0.0) + SPACING;
+        }
+
+        height
+    }
+
+    /// Grows the boundary to enclose `contents`, the bounds of its elements.
+    pub fn attach(&mut self, contents: Option<Rect>) {
+        self.contents = contents;
+        self.place_title();
+    }
+
+    fn place_title(&mut self) {
+        let (l, t, ..) = self.bounds().edges();
+        let x = l + MARGIN;
+        self.title.set_position(x, t + MARGIN);
+        self.kind_label.set_position(x, self.title.y() + SPACING);
+    }
+
+    pub fn cursor_intersects(&self, x: f64, y: f64) -> bool {
+        let bounds = self.bounds();
+        let cursor = Point::new(x, y);
+        let header = Rect::new(
+            bounds.left(),
+            bounds.top(),
+            bounds.width(),
+            self.header_height(),
+        );
+
+        if self.elements.is_empty() || header.contains(cursor) {
+            return bounds.contains(cursor);
+        }
+
+        bounds.distance_to_border(cursor) <= HIT_DISTANCE
+    }
+}
+
+fn kind_label() -> Label {
+    Label::new(
+        0.0,
+        0.0,
+        "",
+        TextProperties::new(14.0, "Arial,sans-serif"),
+        TEXT_COLOR,
+    )
+}
+
+impl Drawable for C4Boundary {
+    fn initalize(&mut self, canvas: &impl Canvas) {
+        self.refresh_kind();
+        self.title.initalize(canvas);
+        self.kind_label.initalize(canvas);
+        self.place_title();
+    }
+
+    fn draw(&self, canvas: &impl Canvas, camera: &Camera) {
+        let stroke = if self.is_highlighted() {
+            HIGHLIGHT_STROKE
+        } else {
+            DEFAULT_STROKE
+        };
+
+        let (l, t, r, b) = self.bounds().edges();
+
+        Path::new(vec![(l, t), (r, t), (r, b), (l, b)], Some(stroke))
+            .closed(None)
+            .dashed(true)
+            .draw(canvas, camera);
+
+        self.title.draw(canvas, camera);
+
+        if self.kind.is_some() {
+            self.kind_label.draw(canvas, camera);
+        }
+    }
+
+    /// The bounds of the box, including the elements it encloses.
+    fn bounds(&self) -> Rect {
+        let width = self.title.width().unwrap_or(0.0) + 2.0 * MARGIN;
+        let own = Rect::new(
+            self.x,
+            self.y,
+            f64::max(width, MIN_WIDTH),
+            self.header_height() + MIN_HEIGHT,
+        );
+
+        match self.contents {
+            Some(contents) => Rect::from_edges(
+                f64::min(own.left(), contents.left() - PADDING),
+                own.top(),
+                f64::max(own.right(), contents.right() + PADDING),
+                f64::max(own.bottom(), contents.bottom() + PADDING),
+            ),
+            None => own,
+        }
+    }
+}
+
+impl Interactive for C4Boundary {
+    fn get_interaction(&self) -> InteractionState {
+        self.interaction_state
+    }
+
+    fn get_interaction_mut(&mut self) -> &mut InteractionState {
+        &mut self.interaction_state
+    }
+
+    /// Moves the boundary itself. Its elements are separate elements, which
+    /// `Document::move_element` moves along with it.
+    fn adjust_position(&mut self, delta_x: f64, delta_y: f64) {
+        self.x += delta_x;
+        self.y += delta_y;
+
+        if let Some(contents) = &mut self.contents {
+            *contents = contents.translate(delta_x, delta_y);
+        }
+
+        self.title.adjust_position(delta_x, delta_y);
+        self.kind_label.adjust_position(delta_x, delta_y);
+    }
+
+    #[allow(unused_variables)]
+    fn click(&mut self, x: f64, y: f64) -> Option<Prompt> {
+        Some(Prompt::Text {
+            explanation: "Provide this boundary with a new name".into(),
+            placeholder: "Boundary name".into(),
+            value: self.title.text().to_string(),
+            metadata: Rc::new(()),
+        })
+    }
+
+    fn prompt(&mut self, response: PromptResponse) {
+        let PromptResponse::Text {
+            response,
+            metadata: _,
+        } = response;
+
+        if response.is_empty() {
+            return;
+        }
+
+        self.title.set_text(response);
+    }
+}
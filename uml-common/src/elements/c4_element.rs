@@ -0,0 +1,428 @@
+use std::rc::Rc;
+
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    camera::Camera,
+    canvas::Canvas,
+    color::{Color, WHITE},
+    drawable::Drawable,
+    geometry::Rect,
+    interaction::{InteractionState, Interactive},
+    prompt::{Prompt, PromptResponse},
+    stroke::Stroke,
+};
+
+use super::{Label, Path, Rectangle, TextProperties};
+
+const HIGHLIGHT_STROKE: Stroke = const {
+    Stroke::new(
+        2,
+        Color::Rgb {
+            red: 142,
+            green: 202,
+            blue: 230,
+        },
+    )
+};
+
+const DARK_TEXT_COLOR: Color = const {
+    Color::Rgb {
+        red: 31,
+        green: 31,
+        blue: 31,
+    }
+};
+
+const MARGIN: f64 = 12.0;
+const SPACING: f64 = 6.0;
+const MIN_WIDTH: f64 = 160.0;
+const MIN_HEIGHT: f64 = 100.0;
+const RADIUS: f64 = 8.0;
+/// Radius of the head of a person, which sits on top of the box.
+const HEAD_RADIUS: f64 = 24.0;
+
+/// The kinds of elements of the C4 model.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum C4Kind {
+    /// A user of the software systems, drawn with a head on top.
+    Person,
+    SoftwareSystem,
+    /// An application or data store within a software system.
+    Container,
+    /// A grouping of functionality within a container.
+    Component,
+    /// A software system outside of the scope of the diagram.
+    ExternalSystem,
+}
+
+impl C4Kind {
+    /// The name of the kind, shown under the name of the element.
+    pub fn name(&self) -> &'static str {
+        match self {
+            C4Kind::Person => "Person",
+            C4Kind::SoftwareSystem => "Software System",
+            C4Kind::Container => "Container",
+            C4Kind::Component => "Component",
+            C4Kind::ExternalSystem => "External System",
+        }
+    }
+
+    /// The fill, border and text colors of the usual C4 palette.
+    fn colors(&self) -> (Color, Color, Color) {
+        let rgb = |red, green, blue| Color::Rgb { red, green, blue };
+
+        match self {
+            C4Kind::Person => (rgb(8, 66, 123), rgb(7, 59, 111), WHITE),
+            C4Kind::SoftwareSystem => {
+                (rgb(17, 104, 189), rgb(15, 93, 169), WHITE)
+            }
+            C4Kind::Container => (rgb(67, 141, 213), rgb(60, 127, 192), WHITE),
+            C4Kind::Component => {
+                (rgb(133, 187, 240), rgb(120, 168, 216), DARK_TEXT_COLOR)
+            }
+            C4Kind::ExternalSystem => {
+                (rgb(153, 153, 153), rgb(138, 138, 138), WHITE)
+            }
+        }
+    }
+}
+
+/// The part of a C4 element a prompt edits.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Field {
+    Name,
+    Technology,
+    Description,
+}
+
+/// A person, software system, container or component of a C4 diagram: a
+/// colored box with the name, the kind and technology (e.g.
+/// `[Container: Rust]`) and a description of what it does.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct C4Element {
+    x: f64,
+    y: f64,
+    kind: C4Kind,
+    title: Label,
+    #[serde(default)]
+    technology: Option<String>,
+    #[serde(default)]
+    description: Vec<Label>,
+
+    /// The kind and technology, written as `[Kind: technology]`.
+    #[serde(skip, default = "kind_label")]
+    kind_label: Label,
+    #[serde(skip)]
+    interaction_state: InteractionState,
+}
+
+impl C4Element {
+    pub fn new(x: f64, y: f64, kind: C4Kind, name: String) -> Self {
+        let mut element = Self {
+            x,
+            y,
+            kind,
+            title: Label::new(
+                0.0,
+                0.0,
+                name,
+                TextProperties::default().weight(700),
+                WHITE,
+            ),
+            technology: None,
+            description: vec![],
+            kind_label: kind_label(),
+            interaction_state: InteractionState::default(),
+        };
+        element.refresh_labels();
+        element
+    }
+
+    pub fn kind(&self) -> C4Kind {
+        self.kind
+    }
+
+    pub fn set_kind(&mut self, value: C4Kind) {
+        self.kind = value;
+        self.refresh_labels();
+    }
+
+    pub fn name(&self) -> &str {
+        self.title.text()
+    }
+
+    pub fn set_name(&mut self, value: String) {
+        self.title.set_text(value);
+    }
+
+    pub fn technology(&self) -> Option<&str> {
+        self.technology.as_deref()
+    }
+
+    pub fn set_technology(&mut self, value: Option<String>) {
+        self.technology = value;
+        self.refresh_labels();
+    }
+
+    /// The description, with a line per line of text.
+    pub fn description(&self) -> String {
+        self.description
+            .iter()
+            .map(Label::text)
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+
+    pub fn set_description(&mut self, text: &str) {
+        self.description = text
+            .lines()
+            .filter(|line| !line.trim().is_empty())
+            .map(|line| {
+                Label::new(0.0, 0.0, line.trim(), description_props(), WHITE)
+            })
+            .collect();
+        self.refresh_labels();
+    }
+
+    /// The kind and technology, as shown under the name.
+    pub fn kind_text(&self) -> String {
+        match &self.technology {
+            Some(technology) => format!("[{}: {technology}]", self.kind.name()),
+            None => format!("[{}]", self.kind.name()),
+        }
+    }
+
+    fn refresh_labels(&mut self) {
+        let text = self.kind_text();
+        self.kind_label.set_text(text);
+
+        let (.., color) = self.kind.colors();
+        for label in self.labels_mut() {
+            label.set_color(color);
+        }
+    }
+
+    pub fn x(&self) -> f64 {
+        self.x
+    }
+
+    pub fn y(&self) -> f64 {
+        self.y
+    }
+
+    pub fn width(&self) -> f64 {
+        let text = self
+            .labels()
+            .map(|l| l.width().unwrap_or(0.0))
+            .fold(0.0, f64::max);
+        f64::max(text + 2.0 * MARGIN, MIN_WIDTH)
+    }
+
+    pub fn height(&self) -> f64 {
+        self.head_height() + self.body_height()
+    }
+
+    /// The height of the head of a person above its box.
+    fn head_height(&self) -> f64 {
+        match self.kind {
+            C4Kind::Person => HEAD_RADIUS * 3.0 / 2.0,
+            _ => 0.0,
+        }
+    }
+
+    fn body_height(&self) -> f64 {
+        f64::max(self.text_height() + 2.0 * MARGIN, MIN_HEIGHT)
+    }
+
+    fn text_height(&self) -> f64 {
+        let labels = self.labels().collect::<Vec<_>>();
+        let text = labels
+            .iter()
+            .map(|l| l.height().unwrap_or(0.0))
+            .sum::<f64>();
+        let mut height = text + SPACING * (labels.len() as f64 - 1.0);
+
+        // The description is set apart from the name and kind.
+        if !self.description.is_empty() {
+            height += SPACING;
+        }
+
+        height
+    }
+
+    fn labels(&self) -> impl Iterator<Item = &Label> {
+        [&self.title, &self.kind_label]
+            .into_iter()
+            .chain(&self.description)
+    }
+
+    fn labels_mut(&mut self) -> impl Iterator<Item = &mut Label> {
+        [&mut self.title, &mut self.kind_label]
+            .into_iter()
+            .chain(&mut self.description)
+    }
+
+    /// The part of the element at height `y`.
+    fn field_at(&self, y: f64) -> Field {
+        if y <= self.title.y() + (SPACING / 2.0) {
+            Field::Name
+        } else if y <= self.kind_label.y() + SPACING {
+            Field::Technology
+        } else {
+            Field::Description
+        }
+    }
+}
+
+fn kind_label() -> Label {
+    Label::new(0.0, 0.0, "", description_props(), WHITE)
+}
+
+fn description_props() -> TextProperties {
+    TextProperties::new(14.0, "Arial,sans-serif")
+}
+
+impl Drawable for C4Element {
+    fn initalize(&mut self, canvas: &impl Canvas) {
+        self.refresh_labels();
+
+        for label in self.labels_mut() {
+            label.initalize(canvas);
+        }
+
+        let (x, width) = (self.x, self.width());
+        let body = self.y + self.head_height();
+        let space = self.body_height() - self.text_height();
+        let mut offset_y = body + (space / 2.0);
+
+        let title_and_kind = 2;
+        for (index, label) in self.labels_mut().enumerate() {
+            if index == title_and_kind {
+                offset_y += SPACING;
+            }
+
+            let offset_x = (width - label.width().unwrap_or(0.0)) / 2.0;
+            label.set_position(x + offset_x, offset_y);
+            offset_y = label.y() + SPACING;
+        }
+    }
+
+    fn draw(&self, canvas: &impl Canvas, camera: &Camera) {
+        let (fill, border, _) = self.kind.colors();
+        let stroke = if self.is_highlighted() {
+            HIGHLIGHT_STROKE
+        } else {
+            Stroke::new(2, border)
+        };
+
+        let (l, t, r, b) = self.bounds().edges();
+        let body = t + self.head_height();
+
+        if self.kind == C4Kind::Person {
+            let cx = (l + r) / 2.0;
+            Path::ellipse(
+                Rect::from_edges(
+                    cx - HEAD_RADIUS,
+                    t,
+                    cx + HEAD_RADIUS,
+                    t + 2.0 * HEAD_RADIUS,
+                ),
+                Some(stroke),
+            )
+            .closed(Some(fill))
+            .draw(canvas, camera);
+        }
+
+        // People have rounder bodies than the boxes of systems.
+        let radius = match self.kind {
+            C4Kind::Person => 3.0 * RADIUS,
+            _ => RADIUS,
+        };
+
+        Rectangle::new(
+            l,
+            body,
+            r - l,
+            b - body,
+            fill,
+            Some(radius),
+            Some(stroke),
+        )
+        .draw(canvas, camera);
+
+        for label in self.labels() {
+            label.draw(canvas, camera);
+        }
+    }
+
+    fn bounds(&self) -> Rect {
+        Rect::new(self.x, self.y, self.width(), self.height())
+    }
+}
+
+impl Interactive for C4Element {
+    fn get_interaction(&self) -> InteractionState {
+        self.interaction_state
+    }
+
+    fn get_interaction_mut(&mut self) -> &mut InteractionState {
+        &mut self.interaction_state
+    }
+
+    fn adjust_position(&mut self, delta_x: f64, delta_y: f64) {
+        self.x += delta_x;
+        self.y += delta_y;
+
+        for label in self.labels_mut() {
+            label.adjust_position(delta_x, delta_y);
+        }
+    }
+
+    /// Edits the name, the technology or the description, depending on the
+    /// line clicked.
+    #[allow(unused_variables)]
+    fn click(&mut self, x: f64, y: f64) -> Option<Prompt> {
+        let field = self.field_at(self.y + y);
+
+        let (explanation, placeholder, value) = match field {
+            Field::Name => (
+                "Provide this element with a new name",
+                "Name",
+                self.name().to_string(),
+            ),
+            Field::Technology => (
+                "Provide the technology this element is built with",
+                "Technology",
+                self.technology.clone().unwrap_or_default(),
+            ),
+            Field::Description => (
+                "Describe what this element does",
+                "Description",
+                self.description(),
+            ),
+        };
+
+        Some(Prompt::Text {
+            explanation: explanation.into(),
+            placeholder: placeholder.into(),
+            value,
+            metadata: Rc::new(field),
+        })
+    }
+
+    fn prompt(&mut self, response: PromptResponse) {
+        let PromptResponse::Text { response, metadata } = response;
+
+        match metadata.downcast_ref::<Field>() {
+            Some(Field::Name) if !response.is_empty() => {
+                self.set_name(response)
+            }
+            Some(Field::Technology) => {
+                self.set_technology(Some(response).filter(|t| !t.is_empty()))
+            }
+            Some(Field::Description) => self.set_description(&response),
+            _ => (),
+        }
+    }
+}
@@ -7,12 +7,14 @@ use crate::{
     canvas::Canvas,
     color::Color,
     drawable::Drawable,
+    geometry::Rect,
     interaction::{InteractionState, Interactive},
+    member::Member,
     prompt::{Prompt, PromptResponse},
     stroke::Stroke,
 };
 
-use super::{Label, Rectangle, TextProperties};
+use super::{Label, Path, Rectangle, TextProperties};
 
 const DEFAULT_COLOR: Color = const {
     Color::Rgb {
@@ -52,20 +54,24 @@ const TEXT_COLOR: Color = const {
     }
 };
 
-const MARGIN: u32 = 20;
-const SPACING: u32 = 16;
+const MARGIN: f64 = 20.0;
+const SPACING: f64 = 16.0;
 
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct Class {
-    x: i32,
-    y: i32,
+    x: f64,
+    y: f64,
 
     color: Color,
-    radius: Option<u32>,
+    radius: Option<f64>,
     stroke: Option<Stroke>,
 
     title: Label,
+    #[serde(default)]
+    stereotype: Option<Label>,
     attributes: Vec<Label>,
+    #[serde(default)]
+    operations: Vec<Label>,
 
     #[serde(skip)]
     interaction_state: InteractionState,
@@ -73,12 +79,12 @@ pub struct Class {
 
 impl Class {
     pub fn new(
-        x: i32,
-        y: i32,
+        x: f64,
+        y: f64,
         name: String,
         color: Option<Color>,
         stroke: Option<Stroke>,
-        radius: Option<u32>,
+        radius: Option<f64>,
     ) -> Self {
         let color = color.unwrap_or(DEFAULT_COLOR);
 
@@ -89,25 +95,27 @@ impl Class {
             radius,
             stroke,
             interaction_state: InteractionState::default(),
+            stereotype: None,
+            operations: vec![],
             attributes: vec![
                 Label::new(
-                    0,
-                    0,
+                    0.0,
+                    0.0,
                     "Attribute 1",
                     TextProperties::default(),
                     TEXT_COLOR,
                 ),
                 Label::new(
-                    0,
-                    0,
+                    0.0,
+                    0.0,
                     "Attribute 2",
                     TextProperties::default(),
                     TEXT_COLOR,
                 ),
             ],
             title: Label::new(
-                0,
-                0,
+                0.0,
+                0.0,
                 name,
                 TextProperties::default().weight(700),
                 TEXT_COLOR,
@@ -115,40 +123,129 @@ impl Class {
         }
     }
 
-    pub fn x(&self) -> i32 {
+    pub fn x(&self) -> f64 {
         self.x
     }
 
-    pub fn y(&self) -> i32 {
+    pub fn y(&self) -> f64 {
         self.y
     }
 
-    pub fn width(&self) -> Option<u32> {
-        let mut w = self.title.width().map(|w| w + 2 * MARGIN)?;
+    pub fn width(&self) -> Option<f64> {
+        let mut w = self.title.width().map(|w| w + 2.0 * MARGIN)?;
 
-        for attribute in &self.attributes {
-            w = u32::max(w, attribute.width()? + 2 * MARGIN);
+        for label in self.labels().skip(1) {
+            w = f64::max(w, label.width()? + 2.0 * MARGIN);
         }
 
         Some(w)
     }
 
-    pub fn height(&self) -> Option<u32> {
-        let mut h = self.title.height().map(|h| h + 2 * MARGIN)?;
+    pub fn height(&self) -> Option<f64> {
+        let mut h = self.title.height().map(|h| h + 2.0 * MARGIN)?;
 
-        for attribute in &self.attributes {
+        for label in self.labels().skip(1) {
             h += SPACING;
-            h += attribute.height()?;
+            h += label.height()?;
         }
 
         Some(h)
     }
 
+    pub fn name(&self) -> &str {
+        self.title.text()
+    }
+
+    pub fn set_name(&mut self, value: String) {
+        self.title.set_text(value);
+    }
+
+    /// The stereotype shown above the name, without guillemets (e.g.
+    /// `interface`).
+    pub fn stereotype(&self) -> Option<&str> {
+        self.stereotype
+            .as_ref()
+            .map(|s| s.text().trim_start_matches('«').trim_end_matches('»'))
+    }
+
+    pub fn set_stereotype(&mut self, value: Option<String>) {
+        self.stereotype = value.map(|value| {
+            Label::new(
+                0.0,
+                0.0,
+                format!("«{value}»"),
+                TextProperties::default(),
+                TEXT_COLOR,
+            )
+        });
+    }
+
+    /// The attributes of the class, written in UML notation.
+    pub fn attributes(&self) -> impl Iterator<Item = &str> {
+        self.attributes.iter().map(|a| a.text())
+    }
+
+    /// The operations of the class, written in UML notation.
+    pub fn operations(&self) -> impl Iterator<Item = &str> {
+        self.operations.iter().map(|o| o.text())
+    }
+
+    pub fn members(&self) -> impl Iterator<Item = Member> {
+        self.attributes()
+            .chain(self.operations())
+            .map(Member::parse)
+    }
+
+    pub fn set_attributes(
+        &mut self,
+        values: impl IntoIterator<Item = impl Into<String>>,
+    ) {
+        self.attributes = values.into_iter().map(member_label).collect();
+    }
+
+    pub fn set_operations(
+        &mut self,
+        values: impl IntoIterator<Item = impl Into<String>>,
+    ) {
+        self.operations = values.into_iter().map(member_label).collect();
+    }
+
+    pub fn add_attribute(&mut self, value: impl Into<String>) {
+        self.attributes.push(member_label(value));
+    }
+
+    pub fn add_operation(&mut self, value: impl Into<String>) {
+        self.operations.push(member_label(value));
+    }
+
+    /// Every label of the class, starting with the title.
+    fn labels(&self) -> impl Iterator<Item = &Label> {
+        std::iter::once(&self.title)
+            .chain(&self.stereotype)
+            .chain(&self.attributes)
+            .chain(&self.operations)
+    }
+
+    /// Vertical positions of the lines separating the compartments.
+    fn separators(&self) -> Vec<f64> {
+        let mut separators = vec![];
+
+        for compartment in [&self.attributes, &self.operations] {
+            if let Some(first) = compartment.first() {
+                separators.push(
+                    first.y() - first.height().unwrap_or(0.0) - SPACING / 2.0,
+                );
+            }
+        }
+
+        separators
+    }
+
     pub fn color(&self) -> Color {
         self.color
     }
 
-    pub fn radius(&self) -> Option<u32> {
+    pub fn radius(&self) -> Option<f64> {
         self.radius
     }
 
@@ -159,19 +256,25 @@ impl Class {
 
 impl Drawable for Class {
     fn initalize(&mut self, canvas: &impl Canvas) {
-        self.title.initalize(canvas);
+        let mut offset_y = self.y + MARGIN;
 
-        let mut offset_y = self.y + MARGIN as i32;
+        if let Some(stereotype) = &mut self.stereotype {
+            stereotype.initalize(canvas);
+            stereotype.set_position(self.x + MARGIN, offset_y);
+            offset_y += stereotype.height().unwrap_or(0.0);
+            offset_y += SPACING;
+        }
 
-        self.title.set_position(self.x + MARGIN as i32, offset_y);
+        self.title.initalize(canvas);
+        self.title.set_position(self.x + MARGIN, offset_y);
 
-        offset_y += self.title.height().unwrap_or(0) as i32;
-        offset_y += SPACING as i32;
+        offset_y += self.title.height().unwrap_or(0.0);
+        offset_y += SPACING;
 
-        for attribute in &mut self.attributes {
-            attribute.initalize(canvas);
-            attribute.set_position(self.x + MARGIN as i32, offset_y);
-            offset_y += attribute.height().unwrap_or(0) as i32 + SPACING as i32;
+        for member in self.attributes.iter_mut().chain(&mut self.operations) {
+            member.initalize(canvas);
+            member.set_position(self.x + MARGIN, offset_y);
+            offset_y += member.height().unwrap_or(0.0) + SPACING;
         }
     }
 
@@ -186,7 +289,7 @@ impl Drawable for Class {
             return;
         };
 
-        let stroke = if self.is_hovered() {
+        let stroke = if self.is_highlighted() {
             HIGHLIGHT_STROKE
         } else {
             DEFAULT_STROKE
@@ -198,16 +301,29 @@ impl Drawable for Class {
             width,
             height,
             DEFAULT_COLOR,
-            Some(2),
+            Some(2.0),
             Some(stroke),
         );
 
         bg.draw(canvas, camera);
-        self.title.draw(canvas, camera);
 
-        for attribute in &self.attributes {
-            attribute.draw(canvas, camera);
+        for y in self.separators() {
+            Path::line((self.x, y), (self.x + width, y), stroke)
+                .draw(canvas, camera);
         }
+
+        for label in self.labels() {
+            label.draw(canvas, camera);
+        }
+    }
+
+    fn bounds(&self) -> Rect {
+        Rect::new(
+            self.x,
+            self.y,
+            self.width().unwrap_or(0.0),
+            self.height().unwrap_or(0.0),
+        )
     }
 }
 
@@ -220,18 +336,22 @@ impl Interactive for Class {
         &mut self.interaction_state
     }
 
-    fn adjust_position(&mut self, delta_x: i32, delta_y: i32) {
+    fn adjust_position(&mut self, delta_x: f64, delta_y: f64) {
         self.x += delta_x;
         self.y += delta_y;
         self.title.adjust_position(delta_x, delta_y);
 
-        for attribute in &mut self.attributes {
-            attribute.adjust_position(delta_x, delta_y);
+        for label in self
+            .stereotype
+            .iter_mut()
+            .chain(self.attributes.iter_mut().chain(&mut self.operations))
+        {
+            label.adjust_position(delta_x, delta_y);
         }
     }
 
     #[allow(unused_variables)]
-    fn click(&mut self, x: i32, y: i32) -> Option<Prompt> {
+    fn click(&mut self, x: f64, y: f64) -> Option<Prompt> {
         let value = self.title.text().to_string();
 
         Some(Prompt::Text {
@@ -255,3 +375,7 @@ impl Interactive for Class {
         self.title.set_text(response);
     }
 }
+
+fn member_label(text: impl Into<String>) -> Label {
+    Label::new(0.0, 0.0, text, TextProperties::default(), TEXT_COLOR)
+}
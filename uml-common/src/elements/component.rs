@@ -0,0 +1,221 @@
+use std::rc::Rc;
+
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    camera::Camera,
+    canvas::Canvas,
+    color::Color,
+    drawable::Drawable,
+    geometry::Rect,
+    interaction::{InteractionState, Interactive},
+    prompt::{Prompt, PromptResponse},
+    stroke::Stroke,
+};
+
+use super::{Label, Rectangle, TextProperties};
+
+const DEFAULT_COLOR: Color = const {
+    Color::Rgb {
+        red: 244,
+        green: 244,
+        blue: 244,
+    }
+};
+
+const DEFAULT_STROKE: Stroke = const {
+    Stroke::new(
+        2,
+        Color::Rgb {
+            red: 90,
+            green: 90,
+            blue: 90,
+        },
+    )
+};
+
+const HIGHLIGHT_STROKE: Stroke = const {
+    Stroke::new(
+        2,
+        Color::Rgb {
+            red: 142,
+            green: 202,
+            blue: 230,
+        },
+    )
+};
+
+const TEXT_COLOR: Color = const {
+    Color::Rgb {
+        red: 31,
+        green: 31,
+        blue: 31,
+    }
+};
+
+const MARGIN: f64 = 12.0;
+const MIN_WIDTH: f64 = 140.0;
+const MIN_HEIGHT: f64 = 60.0;
+/// Size of the component icon in the top right corner.
+const ICON_WIDTH: f64 = 16.0;
+const ICON_HEIGHT: f64 = 20.0;
+const TAB_WIDTH: f64 = 8.0;
+const TAB_HEIGHT: f64 = 4.0;
+
+/// A modular part of a system, drawn as a rectangle with its name in the
+/// middle and the component icon in the top right corner.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Component {
+    x: f64,
+    y: f64,
+    title: Label,
+
+    #[serde(skip)]
+    interaction_state: InteractionState,
+}
+
+impl Component {
+    pub fn new(x: f64, y: f64, name: String) -> Self {
+        Self {
+            x,
+            y,
+            title: Label::new(
+                0.0,
+                0.0,
+                name,
+                TextProperties::default().weight(700),
+                TEXT_COLOR,
+            ),
+            interaction_state: InteractionState::default(),
+        }
+    }
+
+    pub fn name(&self) -> &str {
+        self.title.text()
+    }
+
+    pub fn set_name(&mut self, value: String) {
+        self.title.set_text(value);
+    }
+
+    pub fn x(&self) -> f64 {
+        self.x
+    }
+
+    pub fn y(&self) -> f64 {
+        self.y
+    }
+
+    /// The name is kept clear of the icon on both sides.
+    pub fn width(&self) -> f64 {
+        let text = self.title.width().unwrap_or(0.0);
+        f64::max(text + 2.0 * (2.0 * MARGIN + ICON_WIDTH), MIN_WIDTH)
+    }
+
+    pub fn height(&self) -> f64 {
+        let text = self.title.height().unwrap_or(0.0);
+        f64::max(text + 2.0 * MARGIN, MIN_HEIGHT)
+    }
+}
+
+impl Drawable for Component {
+    fn initalize(&mut self, canvas: &impl Canvas) {
+        self.title.initalize(canvas);
+
+        let offset_x = (self.width() - self.title.width().unwrap_or(0.0)) / 2.0;
+        let offset_y =
+            (self.height() - self.title.height().unwrap_or(0.0)) / 2.0;
+        self.title
+            .set_position(self.x + offset_x, self.y + offset_y);
+    }
+
+    fn draw(&self, canvas: &impl Canvas, camera: &Camera) {
+        let stroke = if self.is_highlighted() {
+            HIGHLIGHT_STROKE
+        } else {
+            DEFAULT_STROKE
+        };
+
+        Rectangle::new(
+            self.x,
+            self.y,
+            self.width(),
+            self.height(),
+            DEFAULT_COLOR,
+            None,
+            Some(stroke),
+        )
+        .draw(canvas, camera);
+
+        let icon_x = self.x + (self.width() - MARGIN - ICON_WIDTH);
+        let icon_y = self.y + MARGIN;
+        Rectangle::new(
+            icon_x,
+            icon_y,
+            ICON_WIDTH,
+            ICON_HEIGHT,
+            DEFAULT_COLOR,
+            None,
+            Some(stroke),
+        )
+        .draw(canvas, camera);
+
+        for offset in [TAB_HEIGHT, ICON_HEIGHT - 2.0 * TAB_HEIGHT] {
+            Rectangle::new(
+                icon_x - (TAB_WIDTH / 2.0),
+                icon_y + offset,
+                TAB_WIDTH,
+                TAB_HEIGHT,
+                DEFAULT_COLOR,
+                None,
+                Some(stroke),
+            )
+            .draw(canvas, camera);
+        }
+
+        self.title.draw(canvas, camera);
+    }
+
+    fn bounds(&self) -> Rect {
+        Rect::new(self.x, self.y, self.width(), self.height())
+    }
+}
+
+impl Interactive for Component {
+    fn get_interaction(&self) -> InteractionState {
+        self.interaction_state
+    }
+
+    fn get_interaction_mut(&mut self) -> &mut InteractionState {
+        &mut self.interaction_state
+    }
+
+    fn adjust_position(&mut self, delta_x: f64, delta_y: f64) {
+        self.x += delta_x;
+        self.y += delta_y;
+        self.title.adjust_position(delta_x, delta_y);
+    }
+
+    #[allow(unused_variables)]
+    fn click(&mut self, x: f64, y: f64) -> Option<Prompt> {
+        Some(Prompt::Text {
+            explanation: "Provide this component with a new name".into(),
+            placeholder: "Component name".into(),
+            value: self.title.text().to_string(),
+            metadata: Rc::new(()),
+        })
+    }
+
+    fn prompt(&mut self, response: PromptResponse) {
+        let PromptResponse::Text {
+            response,
+            metadata: _,
+        } = response;
+
+        if response.is_empty() {
+            return;
+        }
+
+        self.title.set_text(response);
+    }
+}
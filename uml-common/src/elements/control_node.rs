@@ -0,0 +1,218 @@
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    camera::Camera,
+    canvas::Canvas,
+    color::{Color, WHITE},
+    drawable::Drawable,
+    geometry::Rect,
+    interaction::{InteractionState, Interactive},
+    stroke::Stroke,
+};
+
+use super::{Outline, Path, Rectangle};
+
+const DEFAULT_STROKE: Stroke = const {
+    Stroke::new(
+        2,
+        Color::Rgb {
+            red: 90,
+            green: 90,
+            blue: 90,
+        },
+    )
+};
+
+const HIGHLIGHT_STROKE: Stroke = const {
+    Stroke::new(
+        2,
+        Color::Rgb {
+            red: 142,
+            green: 202,
+            blue: 230,
+        },
+    )
+};
+
+const FILL_COLOR: Color = const {
+    Color::Rgb {
+        red: 31,
+        green: 31,
+        blue: 31,
+    }
+};
+
+/// Gap between the ring and the dot of a final node.
+const RING: f64 = 5.0;
+const BAR_THICKNESS: f64 = 6.0;
+const DEFAULT_BAR_LENGTH: f64 = 100.0;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum ControlNodeKind {
+    /// A filled circle, where the activity starts.
+    Initial,
+    /// A filled circle within a ring, where the activity ends.
+    Final,
+    /// A diamond with one incoming flow and several guarded outgoing ones.
+    Decision,
+    /// A diamond joining alternative flows back into one.
+    Merge,
+    /// A bar splitting one flow into concurrent ones.
+    Fork,
+    /// A bar waiting for concurrent flows before continuing with one.
+    Join,
+}
+
+impl ControlNodeKind {
+    pub fn is_bar(&self) -> bool {
+        matches!(self, ControlNodeKind::Fork | ControlNodeKind::Join)
+    }
+}
+
+/// One of the nodes routing the flows of an activity: initial and final
+/// nodes, decisions and merges, forks and joins. Forks and joins are
+/// horizontal bars whose length can be adjusted to the flows they span.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ControlNode {
+    x: f64,
+    y: f64,
+    kind: ControlNodeKind,
+    #[serde(default = "default_bar_length")]
+    length: f64,
+
+    #[serde(skip)]
+    interaction_state: InteractionState,
+}
+
+fn default_bar_length() -> f64 {
+    DEFAULT_BAR_LENGTH
+}
+
+impl ControlNode {
+    pub fn new(x: f64, y: f64, kind: ControlNodeKind) -> Self {
+        Self {
+            x,
+            y,
+            kind,
+            length: DEFAULT_BAR_LENGTH,
+            interaction_state: InteractionState::default(),
+        }
+    }
+
+    pub fn kind(&self) -> ControlNodeKind {
+        self.kind
+    }
+
+    pub fn x(&self) -> f64 {
+        self.x
+    }
+
+    pub fn y(&self) -> f64 {
+        self.y
+    }
+
+    /// The length of a fork or join bar.
+    pub fn length(&self) -> f64 {
+        self.length
+    }
+
+    pub fn set_length(&mut self, value: f64) {
+        self.length = value;
+    }
+
+    /// The shape flows are clipped to.
+    pub fn outline(&self) -> Outline {
+        match self.kind {
+            ControlNodeKind::Initial | ControlNodeKind::Final => {
+                Outline::Ellipse(self.bounds())
+            }
+            ControlNodeKind::Decision | ControlNodeKind::Merge => {
+                Outline::Diamond(self.bounds())
+            }
+            ControlNodeKind::Fork | ControlNodeKind::Join => {
+                Outline::Rectangle(self.bounds())
+            }
+        }
+    }
+}
+
+impl Drawable for ControlNode {
+    fn draw(&self, canvas: &impl Canvas, camera: &Camera) {
+        let stroke = if self.is_highlighted() {
+            HIGHLIGHT_STROKE
+        } else {
+            DEFAULT_STROKE
+        };
+
+        let (l, t, r, b) = self.bounds().edges();
+        let (cx, cy) = ((l + r) / 2.0, (t + b) / 2.0);
+
+        match self.kind {
+            ControlNodeKind::Initial => {
+                Path::ellipse(self.bounds(), Some(stroke))
+                    .closed(Some(FILL_COLOR))
+                    .draw(canvas, camera);
+            }
+            ControlNodeKind::Final => {
+                Path::ellipse(self.bounds(), Some(stroke))
+                    .closed(Some(WHITE))
+                    .draw(canvas, camera);
+                Path::ellipse(
+                    Rect::from_edges(l + RING, t + RING, r - RING, b - RING),
+                    None,
+                )
+                .closed(Some(FILL_COLOR))
+                .draw(canvas, camera);
+            }
+            ControlNodeKind::Decision | ControlNodeKind::Merge => {
+                Path::new(
+                    vec![(cx, t), (r, cy), (cx, b), (l, cy)],
+                    Some(stroke),
+                )
+                .closed(Some(WHITE))
+                .draw(canvas, camera);
+            }
+            ControlNodeKind::Fork | ControlNodeKind::Join => {
+                let stroke = self.is_highlighted().then_some(stroke);
+                Rectangle::new(
+                    l,
+                    t,
+                    self.length,
+                    BAR_THICKNESS,
+                    FILL_COLOR,
+                    None,
+                    stroke,
+                )
+                .draw(canvas, camera);
+            }
+        }
+    }
+
+    fn bounds(&self) -> Rect {
+        let (width, height) = match self.kind {
+            ControlNodeKind::Initial => (24.0, 24.0),
+            ControlNodeKind::Final => (30.0, 30.0),
+            ControlNodeKind::Decision | ControlNodeKind::Merge => (40.0, 40.0),
+            ControlNodeKind::Fork | ControlNodeKind::Join => {
+                (self.length, BAR_THICKNESS)
+            }
+        };
+
+        Rect::new(self.x, self.y, width, height)
+    }
+}
+
+impl Interactive for ControlNode {
+    fn get_interaction(&self) -> InteractionState {
+        self.interaction_state
+    }
+
+    fn get_interaction_mut(&mut self) -> &mut InteractionState {
+        &mut self.interaction_state
+    }
+
+    fn adjust_position(&mut self, delta_x: f64, delta_y: f64) {
+        self.x += delta_x;
+        self.y += delta_y;
+    }
+}
@@ -0,0 +1,241 @@
+use std::rc::Rc;
+
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    camera::Camera,
+    canvas::Canvas,
+    color::Color,
+    drawable::Drawable,
+    geometry::{Point, Rect},
+    interaction::{InteractionState, Interactive},
+    prompt::{Prompt, PromptResponse},
+    stroke::Stroke,
+};
+
+use super::{Label, Path, Rectangle, TextProperties};
+
+const DEFAULT_COLOR: Color = const {
+    Color::Rgb {
+        red: 252,
+        green: 252,
+        blue: 252,
+    }
+};
+
+const DEFAULT_STROKE: Stroke = const {
+    Stroke::new(
+        2,
+        Color::Rgb {
+            red: 90,
+            green: 90,
+            blue: 90,
+        },
+    )
+};
+
+const HIGHLIGHT_STROKE: Stroke = const {
+    Stroke::new(
+        2,
+        Color::Rgb {
+            red: 142,
+            green: 202,
+            blue: 230,
+        },
+    )
+};
+
+const TEXT_COLOR: Color = const {
+    Color::Rgb {
+        red: 31,
+        green: 31,
+        blue: 31,
+    }
+};
+
+const MARGIN: f64 = 12.0;
+/// How far the top and right side of the box recede.
+const DEPTH: f64 = 14.0;
+const HIT_DISTANCE: f64 = 6.0;
+
+/// A computational resource artifacts are deployed on, such as a device or
+/// an execution environment, drawn as a three-dimensional box titled with its
+/// name. Only the border and the title can be grabbed, so what is deployed
+/// on the node stays selectable.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct DeploymentNode {
+    x: f64,
+    y: f64,
+    width: f64,
+    height: f64,
+    title: Label,
+
+    #[serde(skip)]
+    interaction_state: InteractionState,
+}
+
+impl DeploymentNode {
+    pub fn new(x: f64, y: f64, width: f64, height: f64, name: String) -> Self {
+        Self {
+            x,
+            y,
+            width,
+            height,
+            title: Label::new(
+                0.0,
+                0.0,
+                name,
+                TextProperties::default().weight(700),
+                TEXT_COLOR,
+            ),
+            interaction_state: InteractionState::default(),
+        }
+    }
+
+    pub fn name(&self) -> &str {
+        self.title.text()
+    }
+
+    pub fn set_name(&mut self, value: String) {
+        self.title.set_text(value);
+    }
+
+    pub fn x(&self) -> f64 {
+        self.x
+    }
+
+    pub fn y(&self) -> f64 {
+        self.y
+    }
+
+    /// The width of the front of the box.
+    pub fn width(&self) -> f64 {
+        self.width
+    }
+
+    /// The height of the front of the box.
+    pub fn height(&self) -> f64 {
+        self.height
+    }
+
+    pub fn set_size(&mut self, width: f64, height: f64) {
+        self.width = width;
+        self.height = height;
+    }
+
+    /// The bounds of the front of the box.
+    fn front(&self) -> Rect {
+        Rect::new(self.x, self.y + DEPTH, self.width, self.height)
+    }
+
+    pub fn cursor_intersects(&self, x: f64, y: f64) -> bool {
+        let bounds = self.bounds();
+        let cursor = Point::new(x, y);
+        let title = self.front().top()
+            + (self.title.height().unwrap_or(0.0) + 2.0 * MARGIN);
+        let header = Rect::from_edges(
+            bounds.left(),
+            bounds.top(),
+            bounds.right(),
+            title,
+        );
+
+        header.contains(cursor)
+            || bounds.distance_to_border(cursor) <= HIT_DISTANCE
+    }
+}
+
+impl Drawable for DeploymentNode {
+    fn initalize(&mut self, canvas: &impl Canvas) {
+        self.title.initalize(canvas);
+
+        let (l, t, ..) = self.front().edges();
+        self.title.set_position(l + MARGIN, t + MARGIN);
+    }
+
+    fn draw(&self, canvas: &impl Canvas, camera: &Camera) {
+        let stroke = if self.is_highlighted() {
+            HIGHLIGHT_STROKE
+        } else {
+            DEFAULT_STROKE
+        };
+
+        let (l, t, r, b) = self.front().edges();
+
+        for side in [
+            vec![
+                (l, t),
+                (l + DEPTH, t - DEPTH),
+                (r + DEPTH, t - DEPTH),
+                (r, t),
+            ],
+            vec![
+                (r, t),
+                (r + DEPTH, t - DEPTH),
+                (r + DEPTH, b - DEPTH),
+                (r, b),
+            ],
+        ] {
+            Path::new(side, Some(stroke))
+                .closed(Some(DEFAULT_COLOR))
+                .draw(canvas, camera);
+        }
+
+        Rectangle::new(
+            l,
+            t,
+            self.width,
+            self.height,
+            DEFAULT_COLOR,
+            None,
+            Some(stroke),
+        )
+        .draw(canvas, camera);
+
+        self.title.draw(canvas, camera);
+    }
+
+    /// The bounds of the whole box, including its top and right side.
+    fn bounds(&self) -> Rect {
+        Rect::new(self.x, self.y, self.width + DEPTH, self.height + DEPTH)
+    }
+}
+
+impl Interactive for DeploymentNode {
+    fn get_interaction(&self) -> InteractionState {
+        self.interaction_state
+    }
+
+    fn get_interaction_mut(&mut self) -> &mut InteractionState {
+        &mut self.interaction_state
+    }
+
+    fn adjust_position(&mut self, delta_x: f64, delta_y: f64) {
+        self.x += delta_x;
+        self.y += delta_y;
+        self.title.adjust_position(delta_x, delta_y);
+    }
+
+    #[allow(unused_variables)]
+    fn click(&mut self, x: f64, y: f64) -> Option<Prompt> {
+        Some(Prompt::Text {
+            explanation: "Provide this node with a new name".into(),
+            placeholder: "Node name".into(),
+            value: self.title.text().to_string(),
+            metadata: Rc::new(()),
+        })
+    }
+
+    fn prompt(&mut self, response: PromptResponse) {
+        let PromptResponse::Text {
+            response,
+            metadata: _,
+        } = response;
+
+        if response.is_empty() {
+            return;
+        }
+
+        self.title.set_text(response);
+    }
+}
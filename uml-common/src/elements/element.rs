@@ -1,10 +1,17 @@
 use serde::{Deserialize, Serialize};
 
-use super::{Class, Label, rectangle::Rectangle};
+use super::{
+    Action, Activation, Actor, Artifact, C4Boundary, C4Element, Class,
+    Component, ControlNode, DeploymentNode, Entity, Fragment,
+    InstanceSpecification, Interface, Label, Lifeline, Message, Note,
+    ObjectNode, Outline, Package, Port, Pseudostate, Relationship, State,
+    Swimlane, SystemBoundary, UseCase, rectangle::Rectangle,
+};
 use crate::{
     camera::Camera,
     canvas::Canvas,
     drawable::Drawable,
+    geometry::{Point, Rect},
     id::Id,
     interaction::{InteractionState, Interactive},
     prompt::{Prompt, PromptResponse},
@@ -15,55 +22,641 @@ enum ElementType {
     Rectangle(Rectangle),
     Label(Label),
     Class(Class),
+    Relationship(Relationship),
+    Lifeline(Lifeline),
+    Message(Message),
+    Activation(Activation),
+    Fragment(Fragment),
+    Actor(Actor),
+    UseCase(UseCase),
+    SystemBoundary(SystemBoundary),
+    State(State),
+    Pseudostate(Pseudostate),
+    Action(Action),
+    ControlNode(ControlNode),
+    ObjectNode(ObjectNode),
+    Swimlane(Swimlane),
+    Component(Component),
+    Interface(Interface),
+    Port(Port),
+    DeploymentNode(DeploymentNode),
+    Artifact(Artifact),
+    Package(Package),
+    Note(Note),
+    InstanceSpecification(InstanceSpecification),
+    Entity(Entity),
+    C4Element(C4Element),
+    C4Boundary(C4Boundary),
 }
 
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct Element {
-    #[serde(skip)]
+    #[serde(default)]
     id: Id,
     inner: ElementType,
+
+    /// Whether the element has been laid out since it last changed.
+    #[serde(skip)]
+    laid_out: bool,
 }
 
 impl Element {
-    pub fn x(&self) -> i32 {
+    pub fn x(&self) -> f64 {
         match &self.inner {
             ElementType::Rectangle(rectangle) => rectangle.x(),
             ElementType::Label(label) => label.x(),
             ElementType::Class(class) => class.x(),
+            ElementType::Relationship(relationship) => relationship.x(),
+            ElementType::Lifeline(lifeline) => lifeline.x(),
+            ElementType::Message(message) => message.x(),
+            ElementType::Activation(activation) => activation.x(),
+            ElementType::Fragment(fragment) => fragment.x(),
+            ElementType::Actor(actor) => actor.x(),
+            ElementType::UseCase(use_case) => use_case.x(),
+            ElementType::SystemBoundary(system_boundary) => system_boundary.x(),
+            ElementType::State(state) => state.x(),
+            ElementType::Pseudostate(pseudostate) => pseudostate.x(),
+            ElementType::Action(action) => action.x(),
+            ElementType::ControlNode(control_node) => control_node.x(),
+            ElementType::ObjectNode(object_node) => object_node.x(),
+            ElementType::Swimlane(swimlane) => swimlane.x(),
+            ElementType::Component(component) => component.x(),
+            ElementType::Interface(interface) => interface.x(),
+            ElementType::Port(port) => port.x(),
+            ElementType::DeploymentNode(deployment_node) => deployment_node.x(),
+            ElementType::Artifact(artifact) => artifact.x(),
+            ElementType::Package(package) => package.x(),
+            ElementType::C4Boundary(c4_boundary) => c4_boundary.x(),
+            ElementType::Note(note) => note.x(),
+            ElementType::InstanceSpecification(instance_specification) => {
+                instance_specification.x()
+            }
+            ElementType::Entity(entity) => entity.x(),
+            ElementType::C4Element(c4_element) => c4_element.x(),
         }
     }
 
-    pub fn y(&self) -> i32 {
+    pub fn y(&self) -> f64 {
         match &self.inner {
             ElementType::Rectangle(rectangle) => rectangle.y(),
             ElementType::Label(label) => label.y(),
             ElementType::Class(class) => class.y(),
+            ElementType::Relationship(relationship) => relationship.y(),
+            ElementType::Lifeline(lifeline) => lifeline.y(),
+            ElementType::Message(message) => message.y(),
+            ElementType::Activation(activation) => activation.y(),
+            ElementType::Fragment(fragment) => fragment.y(),
+            ElementType::Actor(actor) => actor.y(),
+            ElementType::UseCase(use_case) => use_case.y(),
+            ElementType::SystemBoundary(system_boundary) => system_boundary.y(),
+            ElementType::State(state) => state.y(),
+            ElementType::Pseudostate(pseudostate) => pseudostate.y(),
+            ElementType::Action(action) => action.y(),
+            ElementType::ControlNode(control_node) => control_node.y(),
+            ElementType::ObjectNode(object_node) => object_node.y(),
+            ElementType::Swimlane(swimlane) => swimlane.y(),
+            ElementType::Component(component) => component.y(),
+            ElementType::Interface(interface) => interface.y(),
+            ElementType::Port(port) => port.y(),
+            ElementType::DeploymentNode(deployment_node) => deployment_node.y(),
+            ElementType::Artifact(artifact) => artifact.y(),
+            ElementType::Package(package) => package.y(),
+            ElementType::C4Boundary(c4_boundary) => c4_boundary.y(),
+            ElementType::Note(note) => note.y(),
+            ElementType::InstanceSpecification(instance_specification) => {
+                instance_specification.y()
+            }
+            ElementType::Entity(entity) => entity.y(),
+            ElementType::C4Element(c4_element) => c4_element.y(),
         }
     }
 
-    pub fn cursor_intersects(&self, x: i32, y: i32) -> bool {
-        let (l, t, r, b) = match &self.inner {
-            ElementType::Rectangle(r) => (
-                r.x(),
-                r.y(),
-                r.x() + r.width() as i32,
-                r.y() + r.height() as i32,
-            ),
-            ElementType::Label(l) => (
-                l.x(),
-                l.y(),
-                l.x() + l.width().unwrap_or(0) as i32,
-                l.y() + l.height().unwrap_or(0) as i32,
-            ),
-            ElementType::Class(c) => (
-                c.x(),
-                c.y(),
-                c.x() + c.width().unwrap_or(0) as i32,
-                c.y() + c.height().unwrap_or(0) as i32,
-            ),
+    /// The bounds of everything the element draws. Notes draw their anchor
+    /// lines outside their own bounds.
+    pub fn drawn_bounds(&self) -> Rect {
+        let ElementType::Note(note) = &self.inner else {
+            return self.bounds();
         };
 
-        x >= l && x <= r && y >= t && y <= b
+        let lines = Rect::from_points(
+            note.anchor_lines()
+                .iter()
+                .flat_map(|(from, to)| [*from, *to]),
+        );
+
+        match lines {
+            Some(lines) => note.bounds().union(lines),
+            None => note.bounds(),
+        }
+    }
+
+    /// The shape relationships are clipped to.
+    pub fn outline(&self) -> Outline {
+        match &self.inner {
+            ElementType::UseCase(use_case) => {
+                Outline::Ellipse(use_case.bounds())
+            }
+            ElementType::Pseudostate(pseudostate) => pseudostate.outline(),
+            ElementType::ControlNode(control_node) => control_node.outline(),
+            _ => Outline::Rectangle(self.bounds()),
+        }
+    }
+
+    /// The element this one is attached to: the owner of a port or an
+    /// interface.
+    pub fn owner(&self) -> Option<Id> {
+        match &self.inner {
+            ElementType::Port(port) => Some(port.owner()),
+            ElementType::Interface(interface) => Some(interface.owner()),
+            _ => None,
+        }
+    }
+
+    /// The elements this one contains and moves along with it: the
+    /// substates of a composite state, the nodes of a swimlane or the
+    /// members of a package.
+    pub fn contents(&self) -> &[Id] {
+        match &self.inner {
+            ElementType::State(state) => state.substates(),
+            ElementType::Swimlane(swimlane) => swimlane.nodes(),
+            ElementType::Package(package) => package.members(),
+            ElementType::C4Boundary(c4_boundary) => c4_boundary.elements(),
+            _ => &[],
+        }
+    }
+
+    /// Whether `other` can be placed in this element.
+    pub fn can_contain(&self, other: &Element) -> bool {
+        matches!(
+            (&self.inner, &other.inner),
+            (
+                ElementType::State(_),
+                ElementType::State(_) | ElementType::Pseudostate(_)
+            ) | (
+                ElementType::Swimlane(_),
+                ElementType::Action(_)
+                    | ElementType::ControlNode(_)
+                    | ElementType::ObjectNode(_)
+            ) | (
+                ElementType::Package(_),
+                ElementType::Class(_)
+                    | ElementType::Package(_)
+                    | ElementType::Note(_)
+                    | ElementType::InstanceSpecification(_)
+            ) | (
+                ElementType::C4Boundary(_),
+                ElementType::C4Element(_) | ElementType::C4Boundary(_)
+            )
+        )
+    }
+
+    /// Places the element `id` in this container. Does nothing if this is
+    /// not a container.
+    pub fn add_content(&mut self, id: Id) {
+        match &mut self.inner {
+            ElementType::State(state) => state.add_substate(id),
+            ElementType::Swimlane(swimlane) => swimlane.add_node(id),
+            ElementType::Package(package) => package.add_member(id),
+            ElementType::C4Boundary(c4_boundary) => c4_boundary.add_element(id),
+            _ => (),
+        }
+    }
+
+    pub fn remove_content(&mut self, id: Id) {
+        match &mut self.inner {
+            ElementType::State(state) => state.remove_substate(id),
+            ElementType::Swimlane(swimlane) => swimlane.remove_node(id),
+            ElementType::Package(package) => package.remove_member(id),
+            ElementType::C4Boundary(c4_boundary) => {
+                c4_boundary.remove_element(id)
+            }
+            _ => (),
+        }
+    }
+
+    /// Grows a container to enclose `contents`, the bounds of the elements
+    /// it contains.
+    pub fn attach_contents(&mut self, contents: Option<Rect>) {
+        match &mut self.inner {
+            ElementType::State(state) => state.attach(contents),
+            ElementType::Swimlane(swimlane) => swimlane.attach(contents),
+            ElementType::Package(package) => package.attach(contents),
+            ElementType::C4Boundary(c4_boundary) => {
+                c4_boundary.attach(contents)
+            }
+            _ => (),
+        }
+    }
+
+    pub fn cursor_intersects(&self, x: f64, y: f64) -> bool {
+        match &self.inner {
+            ElementType::Relationship(r) => return r.cursor_intersects(x, y),
+            ElementType::Lifeline(l) => return l.cursor_intersects(x, y),
+            ElementType::Message(m) => return m.cursor_intersects(x, y),
+            ElementType::Fragment(f) => return f.cursor_intersects(x, y),
+            ElementType::UseCase(u) => return u.cursor_intersects(x, y),
+            ElementType::SystemBoundary(s) => return s.cursor_intersects(x, y),
+            ElementType::State(s) => return s.cursor_intersects(x, y),
+            ElementType::Swimlane(s) => return s.cursor_intersects(x, y),
+            ElementType::DeploymentNode(d) => return d.cursor_intersects(x, y),
+            ElementType::Package(p) => return p.cursor_intersects(x, y),
+            ElementType::C4Boundary(c) => return c.cursor_intersects(x, y),
+            _ => (),
+        }
+
+        self.bounds().contains(Point::new(x, y))
+    }
+
+    pub fn as_rectangle(&self) -> Option<&Rectangle> {
+        match &self.inner {
+            ElementType::Rectangle(rectangle) => Some(rectangle),
+            _ => None,
+        }
+    }
+
+    pub fn as_label(&self) -> Option<&Label> {
+        match &self.inner {
+            ElementType::Label(label) => Some(label),
+            _ => None,
+        }
+    }
+
+    pub fn as_class(&self) -> Option<&Class> {
+        match &self.inner {
+            ElementType::Class(class) => Some(class),
+            _ => None,
+        }
+    }
+
+    pub fn as_class_mut(&mut self) -> Option<&mut Class> {
+        match &mut self.inner {
+            ElementType::Class(class) => Some(class),
+            _ => None,
+        }
+    }
+
+    pub fn as_relationship(&self) -> Option<&Relationship> {
+        match &self.inner {
+            ElementType::Relationship(relationship) => Some(relationship),
+            _ => None,
+        }
+    }
+
+    pub fn as_relationship_mut(&mut self) -> Option<&mut Relationship> {
+        match &mut self.inner {
+            ElementType::Relationship(relationship) => Some(relationship),
+            _ => None,
+        }
+    }
+
+    pub fn as_lifeline(&self) -> Option<&Lifeline> {
+        match &self.inner {
+            ElementType::Lifeline(lifeline) => Some(lifeline),
+            _ => None,
+        }
+    }
+
+    pub fn as_lifeline_mut(&mut self) -> Option<&mut Lifeline> {
+        match &mut self.inner {
+            ElementType::Lifeline(lifeline) => Some(lifeline),
+            _ => None,
+        }
+    }
+
+    pub fn as_message(&self) -> Option<&Message> {
+        match &self.inner {
+            ElementType::Message(message) => Some(message),
+            _ => None,
+        }
+    }
+
+    pub fn as_message_mut(&mut self) -> Option<&mut Message> {
+        match &mut self.inner {
+            ElementType::Message(message) => Some(message),
+            _ => None,
+        }
+    }
+
+    pub fn as_activation(&self) -> Option<&Activation> {
+        match &self.inner {
+            ElementType::Activation(activation) => Some(activation),
+            _ => None,
+        }
+    }
+
+    pub fn as_activation_mut(&mut self) -> Option<&mut Activation> {
+        match &mut self.inner {
+            ElementType::Activation(activation) => Some(activation),
+            _ => None,
+        }
+    }
+
+    pub fn as_fragment(&self) -> Option<&Fragment> {
+        match &self.inner {
+            ElementType::Fragment(fragment) => Some(fragment),
+            _ => None,
+        }
+    }
+
+    pub fn as_fragment_mut(&mut self) -> Option<&mut Fragment> {
+        match &mut self.inner {
+            ElementType::Fragment(fragment) => Some(fragment),
+            _ => None,
+        }
+    }
+
+    pub fn as_actor(&self) -> Option<&Actor> {
+        match &self.inner {
+            ElementType::Actor(actor) => Some(actor),
+            _ => None,
+        }
+    }
+
+    pub fn as_actor_mut(&mut self) -> Option<&mut Actor> {
+        match &mut self.inner {
+            ElementType::Actor(actor) => Some(actor),
+            _ => None,
+        }
+    }
+
+    pub fn as_use_case(&self) -> Option<&UseCase> {
+        match &self.inner {
+            ElementType::UseCase(use_case) => Some(use_case),
+            _ => None,
+        }
+    }
+
+    pub fn as_use_case_mut(&mut self) -> Option<&mut UseCase> {
+        match &mut self.inner {
+            ElementType::UseCase(use_case) => Some(use_case),
+            _ => None,
+        }
+    }
+
+    pub fn as_system_boundary(&self) -> Option<&SystemBoundary> {
+        match &self.inner {
+            ElementType::SystemBoundary(system_boundary) => {
+                Some(system_boundary)
+            }
+            _ => None,
+        }
+    }
+
+    pub fn as_system_boundary_mut(&mut self) -> Option<&mut SystemBoundary> {
+        match &mut self.inner {
+            ElementType::SystemBoundary(system_boundary) => {
+                Some(system_boundary)
+            }
+            _ => None,
+        }
+    }
+
+    pub fn as_state(&self) -> Option<&State> {
+        match &self.inner {
+            ElementType::State(state) => Some(state),
+            _ => None,
+        }
+    }
+
+    pub fn as_state_mut(&mut self) -> Option<&mut State> {
+        match &mut self.inner {
+            ElementType::State(state) => Some(state),
+            _ => None,
+        }
+    }
+
+    pub fn as_pseudostate(&self) -> Option<&Pseudostate> {
+        match &self.inner {
+            ElementType::Pseudostate(pseudostate) => Some(pseudostate),
+            _ => None,
+        }
+    }
+
+    pub fn as_pseudostate_mut(&mut self) -> Option<&mut Pseudostate> {
+        match &mut self.inner {
+            ElementType::Pseudostate(pseudostate) => Some(pseudostate),
+            _ => None,
+        }
+    }
+
+    pub fn as_action(&self) -> Option<&Action> {
+        match &self.inner {
+            ElementType::Action(action) => Some(action),
+            _ => None,
+        }
+    }
+
+    pub fn as_action_mut(&mut self) -> Option<&mut Action> {
+        match &mut self.inner {
+            ElementType::Action(action) => Some(action),
+            _ => None,
+        }
+    }
+
+    pub fn as_control_node(&self) -> Option<&ControlNode> {
+        match &self.inner {
+            ElementType::ControlNode(control_node) => Some(control_node),
+            _ => None,
+        }
+    }
+
+    pub fn as_control_node_mut(&mut self) -> Option<&mut ControlNode> {
+        match &mut self.inner {
+            ElementType::ControlNode(control_node) => Some(control_node),
+            _ => None,
+        }
+    }
+
+    pub fn as_object_node(&self) -> Option<&ObjectNode> {
+        match &self.inner {
+            ElementType::ObjectNode(object_node) => Some(object_node),
+            _ => None,
+        }
+    }
+
+    pub fn as_object_node_mut(&mut self) -> Option<&mut ObjectNode> {
+        match &mut self.inner {
+            ElementType::ObjectNode(object_node) => Some(object_node),
+            _ => None,
+        }
+    }
+
+    pub fn as_swimlane(&self) -> Option<&Swimlane> {
+        match &self.inner {
+            ElementType::Swimlane(swimlane) => Some(swimlane),
+            _ => None,
+        }
+    }
+
+    pub fn as_swimlane_mut(&mut self) -> Option<&mut Swimlane> {
+        match &mut self.inner {
+            ElementType::Swimlane(swimlane) => Some(swimlane),
+            _ => None,
+        }
+    }
+
+    pub fn as_component(&self) -> Option<&Component> {
+        match &self.inner {
+            ElementType::Component(component) => Some(component),
+            _ => None,
+        }
+    }
+
+    pub fn as_component_mut(&mut self) -> Option<&mut Component> {
+        match &mut self.inner {
+            ElementType::Component(component) => Some(component),
+            _ => None,
+        }
+    }
+
+    pub fn as_interface(&self) -> Option<&Interface> {
+        match &self.inner {
+            ElementType::Interface(interface) => Some(interface),
+            _ => None,
+        }
+    }
+
+    pub fn as_interface_mut(&mut self) -> Option<&mut Interface> {
+        match &mut self.inner {
+            ElementType::Interface(interface) => Some(interface),
+            _ => None,
+        }
+    }
+
+    pub fn as_port(&self) -> Option<&Port> {
+        match &self.inner {
+            ElementType::Port(port) => Some(port),
+            _ => None,
+        }
+    }
+
+    pub fn as_port_mut(&mut self) -> Option<&mut Port> {
+        match &mut self.inner {
+            ElementType::Port(port) => Some(port),
+            _ => None,
+        }
+    }
+
+    pub fn as_deployment_node(&self) -> Option<&DeploymentNode> {
+        match &self.inner {
+            ElementType::DeploymentNode(deployment_node) => {
+                Some(deployment_node)
+            }
+            _ => None,
+        }
+    }
+
+    pub fn as_deployment_node_mut(&mut self) -> Option<&mut DeploymentNode> {
+        match &mut self.inner {
+            ElementType::DeploymentNode(deployment_node) => {
+                Some(deployment_node)
+            }
+            _ => None,
+        }
+    }
+
+    pub fn as_artifact(&self) -> Option<&Artifact> {
+        match &self.inner {
+            ElementType::Artifact(artifact) => Some(artifact),
+            _ => None,
+        }
+    }
+
+    pub fn as_artifact_mut(&mut self) -> Option<&mut Artifact> {
+        match &mut self.inner {
+            ElementType::Artifact(artifact) => Some(artifact),
+            _ => None,
+        }
+    }
+
+    pub fn as_package(&self) -> Option<&Package> {
+        match &self.inner {
+            ElementType::Package(package) => Some(package),
+            _ => None,
+        }
+    }
+
+    pub fn as_package_mut(&mut self) -> Option<&mut Package> {
+        match &mut self.inner {
+            ElementType::Package(package) => Some(package),
+            _ => None,
+        }
+    }
+
+    pub fn as_note(&self) -> Option<&Note> {
+        match &self.inner {
+            ElementType::Note(note) => Some(note),
+            _ => None,
+        }
+    }
+
+    pub fn as_note_mut(&mut self) -> Option<&mut Note> {
+        match &mut self.inner {
+            ElementType::Note(note) => Some(note),
+            _ => None,
+        }
+    }
+
+    pub fn as_instance_specification(&self) -> Option<&InstanceSpecification> {
+        match &self.inner {
+            ElementType::InstanceSpecification(instance_specification) => {
+                Some(instance_specification)
+            }
+            _ => None,
+        }
+    }
+
+    pub fn as_instance_specification_mut(
+        &mut self,
+    ) -> Option<&mut InstanceSpecification> {
+        match &mut self.inner {
+            ElementType::InstanceSpecification(instance_specification) => {
+                Some(instance_specification)
+            }
+            _ => None,
+        }
+    }
+
+    pub fn as_entity(&self) -> Option<&Entity> {
+        match &self.inner {
+            ElementType::Entity(entity) => Some(entity),
+            _ => None,
+        }
+    }
+
+    pub fn as_entity_mut(&mut self) -> Option<&mut Entity> {
+        match &mut self.inner {
+            ElementType::Entity(entity) => Some(entity),
+            _ => None,
+        }
+    }
+
+    pub fn as_c4_element(&self) -> Option<&C4Element> {
+        match &self.inner {
+            ElementType::C4Element(c4_element) => Some(c4_element),
+            _ => None,
+        }
+    }
+
+    pub fn as_c4_element_mut(&mut self) -> Option<&mut C4Element> {
+        match &mut self.inner {
+            ElementType::C4Element(c4_element) => Some(c4_element),
+            _ => None,
+        }
+    }
+
+    pub fn as_c4_boundary(&self) -> Option<&C4Boundary> {
+        match &self.inner {
+            ElementType::C4Boundary(c4_boundary) => Some(c4_boundary),
+            _ => None,
+        }
+    }
+
+    pub fn as_c4_boundary_mut(&mut self) -> Option<&mut C4Boundary> {
+        match &mut self.inner {
+            ElementType::C4Boundary(c4_boundary) => Some(c4_boundary),
+            _ => None,
+        }
     }
 
     pub fn as_interactive(&self) -> &dyn Interactive {
@@ -71,6 +664,33 @@ impl Element {
             ElementType::Rectangle(rectangle) => rectangle,
             ElementType::Label(label) => label,
             ElementType::Class(class) => class,
+            ElementType::Relationship(relationship) => relationship,
+            ElementType::Lifeline(lifeline) => lifeline,
+            ElementType::Message(message) => message,
+            ElementType::Activation(activation) => activation,
+            ElementType::Fragment(fragment) => fragment,
+            ElementType::Actor(actor) => actor,
+            ElementType::UseCase(use_case) => use_case,
+            ElementType::SystemBoundary(system_boundary) => system_boundary,
+            ElementType::State(state) => state,
+            ElementType::Pseudostate(pseudostate) => pseudostate,
+            ElementType::Action(action) => action,
+            ElementType::ControlNode(control_node) => control_node,
+            ElementType::ObjectNode(object_node) => object_node,
+            ElementType::Swimlane(swimlane) => swimlane,
+            ElementType::Component(component) => component,
+            ElementType::Interface(interface) => interface,
+            ElementType::Port(port) => port,
+            ElementType::DeploymentNode(deployment_node) => deployment_node,
+            ElementType::Artifact(artifact) => artifact,
+            ElementType::Package(package) => package,
+            ElementType::C4Boundary(c4_boundary) => c4_boundary,
+            ElementType::Note(note) => note,
+            ElementType::InstanceSpecification(instance_specification) => {
+                instance_specification
+            }
+            ElementType::Entity(entity) => entity,
+            ElementType::C4Element(c4_element) => c4_element,
         }
     }
 
@@ -79,20 +699,104 @@ impl Element {
             ElementType::Rectangle(rectangle) => rectangle,
             ElementType::Label(label) => label,
             ElementType::Class(class) => class,
+            ElementType::Relationship(relationship) => relationship,
+            ElementType::Lifeline(lifeline) => lifeline,
+            ElementType::Message(message) => message,
+            ElementType::Activation(activation) => activation,
+            ElementType::Fragment(fragment) => fragment,
+            ElementType::Actor(actor) => actor,
+            ElementType::UseCase(use_case) => use_case,
+            ElementType::SystemBoundary(system_boundary) => system_boundary,
+            ElementType::State(state) => state,
+            ElementType::Pseudostate(pseudostate) => pseudostate,
+            ElementType::Action(action) => action,
+            ElementType::ControlNode(control_node) => control_node,
+            ElementType::ObjectNode(object_node) => object_node,
+            ElementType::Swimlane(swimlane) => swimlane,
+            ElementType::Component(component) => component,
+            ElementType::Interface(interface) => interface,
+            ElementType::Port(port) => port,
+            ElementType::DeploymentNode(deployment_node) => deployment_node,
+            ElementType::Artifact(artifact) => artifact,
+            ElementType::Package(package) => package,
+            ElementType::C4Boundary(c4_boundary) => c4_boundary,
+            ElementType::Note(note) => note,
+            ElementType::InstanceSpecification(instance_specification) => {
+                instance_specification
+            }
+            ElementType::Entity(entity) => entity,
+            ElementType::C4Element(c4_element) => c4_element,
         }
     }
 
     pub fn id(&self) -> Id {
         self.id
     }
+
+    /// Whether the element changed since it was last laid out, and has to
+    /// be initialized again before it is drawn.
+    pub fn needs_layout(&self) -> bool {
+        !self.laid_out
+    }
+
+    /// Lays the element out again before it is next drawn. Elements are
+    /// invalidated when they are moved or prompted for a new text; changes
+    /// to their size or text made through `as_*_mut` have to invalidate
+    /// them as well.
+    pub fn invalidate(&mut self) {
+        self.laid_out = false;
+    }
 }
 
 impl Drawable for Element {
     fn initalize(&mut self, canvas: &impl Canvas) {
+        self.laid_out = true;
+
         match &mut self.inner {
             ElementType::Rectangle(rectangle) => rectangle.initalize(canvas),
             ElementType::Label(label) => label.initalize(canvas),
             ElementType::Class(class) => class.initalize(canvas),
+            ElementType::Relationship(relationship) => {
+                relationship.initalize(canvas)
+            }
+            ElementType::Lifeline(lifeline) => lifeline.initalize(canvas),
+            ElementType::Message(message) => message.initalize(canvas),
+            ElementType::Activation(activation) => activation.initalize(canvas),
+            ElementType::Fragment(fragment) => fragment.initalize(canvas),
+            ElementType::Actor(actor) => actor.initalize(canvas),
+            ElementType::UseCase(use_case) => use_case.initalize(canvas),
+            ElementType::SystemBoundary(system_boundary) => {
+                system_boundary.initalize(canvas)
+            }
+            ElementType::State(state) => state.initalize(canvas),
+            ElementType::Pseudostate(pseudostate) => {
+                pseudostate.initalize(canvas)
+            }
+            ElementType::Action(action) => action.initalize(canvas),
+            ElementType::ControlNode(control_node) => {
+                control_node.initalize(canvas)
+            }
+            ElementType::ObjectNode(object_node) => {
+                object_node.initalize(canvas)
+            }
+            ElementType::Swimlane(swimlane) => swimlane.initalize(canvas),
+            ElementType::Component(component) => component.initalize(canvas),
+            ElementType::Interface(interface) => interface.initalize(canvas),
+            ElementType::Port(port) => port.initalize(canvas),
+            ElementType::DeploymentNode(deployment_node) => {
+                deployment_node.initalize(canvas)
+            }
+            ElementType::Artifact(artifact) => artifact.initalize(canvas),
+            ElementType::Package(package) => package.initalize(canvas),
+            ElementType::C4Boundary(c4_boundary) => {
+                c4_boundary.initalize(canvas)
+            }
+            ElementType::Note(note) => note.initalize(canvas),
+            ElementType::InstanceSpecification(instance_specification) => {
+                instance_specification.initalize(canvas)
+            }
+            ElementType::Entity(entity) => entity.initalize(canvas),
+            ElementType::C4Element(c4_element) => c4_element.initalize(canvas),
         }
     }
 
@@ -101,6 +805,90 @@ impl Drawable for Element {
             ElementType::Rectangle(rectangle) => rectangle.draw(canvas, camera),
             ElementType::Label(label) => label.draw(canvas, camera),
             ElementType::Class(class) => class.draw(canvas, camera),
+            ElementType::Relationship(relationship) => {
+                relationship.draw(canvas, camera)
+            }
+            ElementType::Lifeline(lifeline) => lifeline.draw(canvas, camera),
+            ElementType::Message(message) => message.draw(canvas, camera),
+            ElementType::Activation(activation) => {
+                activation.draw(canvas, camera)
+            }
+            ElementType::Fragment(fragment) => fragment.draw(canvas, camera),
+            ElementType::Actor(actor) => actor.draw(canvas, camera),
+            ElementType::UseCase(use_case) => use_case.draw(canvas, camera),
+            ElementType::SystemBoundary(system_boundary) => {
+                system_boundary.draw(canvas, camera)
+            }
+            ElementType::State(state) => state.draw(canvas, camera),
+            ElementType::Pseudostate(pseudostate) => {
+                pseudostate.draw(canvas, camera)
+            }
+            ElementType::Action(action) => action.draw(canvas, camera),
+            ElementType::ControlNode(control_node) => {
+                control_node.draw(canvas, camera)
+            }
+            ElementType::ObjectNode(object_node) => {
+                object_node.draw(canvas, camera)
+            }
+            ElementType::Swimlane(swimlane) => swimlane.draw(canvas, camera),
+            ElementType::Component(component) => component.draw(canvas, camera),
+            ElementType::Interface(interface) => interface.draw(canvas, camera),
+            ElementType::Port(port) => port.draw(canvas, camera),
+            ElementType::DeploymentNode(deployment_node) => {
+                deployment_node.draw(canvas, camera)
+            }
+            ElementType::Artifact(artifact) => artifact.draw(canvas, camera),
+            ElementType::Package(package) => package.draw(canvas, camera),
+            ElementType::C4Boundary(c4_boundary) => {
+                c4_boundary.draw(canvas, camera)
+            }
+            ElementType::Note(note) => note.draw(canvas, camera),
+            ElementType::InstanceSpecification(instance_specification) => {
+                instance_specification.draw(canvas, camera)
+            }
+            ElementType::Entity(entity) => entity.draw(canvas, camera),
+            ElementType::C4Element(c4_element) => {
+                c4_element.draw(canvas, camera)
+            }
+        }
+    }
+
+    fn bounds(&self) -> Rect {
+        match &self.inner {
+            ElementType::Rectangle(rectangle) => rectangle.bounds(),
+            ElementType::Label(label) => label.bounds(),
+            ElementType::Class(class) => class.bounds(),
+            ElementType::Relationship(relationship) => relationship.bounds(),
+            ElementType::Lifeline(lifeline) => lifeline.bounds(),
+            ElementType::Message(message) => message.bounds(),
+            ElementType::Activation(activation) => activation.bounds(),
+            ElementType::Fragment(fragment) => fragment.bounds(),
+            ElementType::Actor(actor) => actor.bounds(),
+            ElementType::UseCase(use_case) => use_case.bounds(),
+            ElementType::SystemBoundary(system_boundary) => {
+                system_boundary.bounds()
+            }
+            ElementType::State(state) => state.bounds(),
+            ElementType::Pseudostate(pseudostate) => pseudostate.bounds(),
+            ElementType::Action(action) => action.bounds(),
+            ElementType::ControlNode(control_node) => control_node.bounds(),
+            ElementType::ObjectNode(object_node) => object_node.bounds(),
+            ElementType::Swimlane(swimlane) => swimlane.bounds(),
+            ElementType::Component(component) => component.bounds(),
+            ElementType::Interface(interface) => interface.bounds(),
+            ElementType::Port(port) => port.bounds(),
+            ElementType::DeploymentNode(deployment_node) => {
+                deployment_node.bounds()
+            }
+            ElementType::Artifact(artifact) => artifact.bounds(),
+            ElementType::Package(package) => package.bounds(),
+            ElementType::C4Boundary(c4_boundary) => c4_boundary.bounds(),
+            ElementType::Note(note) => note.bounds(),
+            ElementType::InstanceSpecification(instance_specification) => {
+                instance_specification.bounds()
+            }
+            ElementType::Entity(entity) => entity.bounds(),
+            ElementType::C4Element(c4_element) => c4_element.bounds(),
         }
     }
 }
@@ -111,6 +899,47 @@ impl Interactive for Element {
             ElementType::Rectangle(rectangle) => rectangle.get_interaction(),
             ElementType::Label(label) => label.get_interaction(),
             ElementType::Class(class) => class.get_interaction(),
+            ElementType::Relationship(relationship) => {
+                relationship.get_interaction()
+            }
+            ElementType::Lifeline(lifeline) => lifeline.get_interaction(),
+            ElementType::Message(message) => message.get_interaction(),
+            ElementType::Activation(activation) => activation.get_interaction(),
+            ElementType::Fragment(fragment) => fragment.get_interaction(),
+            ElementType::Actor(actor) => actor.get_interaction(),
+            ElementType::UseCase(use_case) => use_case.get_interaction(),
+            ElementType::SystemBoundary(system_boundary) => {
+                system_boundary.get_interaction()
+            }
+            ElementType::State(state) => state.get_interaction(),
+            ElementType::Pseudostate(pseudostate) => {
+                pseudostate.get_interaction()
+            }
+            ElementType::Action(action) => action.get_interaction(),
+            ElementType::ControlNode(control_node) => {
+                control_node.get_interaction()
+            }
+            ElementType::ObjectNode(object_node) => {
+                object_node.get_interaction()
+            }
+            ElementType::Swimlane(swimlane) => swimlane.get_interaction(),
+            ElementType::Component(component) => component.get_interaction(),
+            ElementType::Interface(interface) => interface.get_interaction(),
+            ElementType::Port(port) => port.get_interaction(),
+            ElementType::DeploymentNode(deployment_node) => {
+                deployment_node.get_interaction()
+            }
+            ElementType::Artifact(artifact) => artifact.get_interaction(),
+            ElementType::Package(package) => package.get_interaction(),
+            ElementType::C4Boundary(c4_boundary) => {
+                c4_boundary.get_interaction()
+            }
+            ElementType::Note(note) => note.get_interaction(),
+            ElementType::InstanceSpecification(instance_specification) => {
+                instance_specification.get_interaction()
+            }
+            ElementType::Entity(entity) => entity.get_interaction(),
+            ElementType::C4Element(c4_element) => c4_element.get_interaction(),
         }
     }
 
@@ -121,10 +950,61 @@ impl Interactive for Element {
             }
             ElementType::Label(label) => label.get_interaction_mut(),
             ElementType::Class(class) => class.get_interaction_mut(),
+            ElementType::Relationship(relationship) => {
+                relationship.get_interaction_mut()
+            }
+            ElementType::Lifeline(lifeline) => lifeline.get_interaction_mut(),
+            ElementType::Message(message) => message.get_interaction_mut(),
+            ElementType::Activation(activation) => {
+                activation.get_interaction_mut()
+            }
+            ElementType::Fragment(fragment) => fragment.get_interaction_mut(),
+            ElementType::Actor(actor) => actor.get_interaction_mut(),
+            ElementType::UseCase(use_case) => use_case.get_interaction_mut(),
+            ElementType::SystemBoundary(system_boundary) => {
+                system_boundary.get_interaction_mut()
+            }
+            ElementType::State(state) => state.get_interaction_mut(),
+            ElementType::Pseudostate(pseudostate) => {
+                pseudostate.get_interaction_mut()
+            }
+            ElementType::Action(action) => action.get_interaction_mut(),
+            ElementType::ControlNode(control_node) => {
+                control_node.get_interaction_mut()
+            }
+            ElementType::ObjectNode(object_node) => {
+                object_node.get_interaction_mut()
+            }
+            ElementType::Swimlane(swimlane) => swimlane.get_interaction_mut(),
+            ElementType::Component(component) => {
+                component.get_interaction_mut()
+            }
+            ElementType::Interface(interface) => {
+                interface.get_interaction_mut()
+            }
+            ElementType::Port(port) => port.get_interaction_mut(),
+            ElementType::DeploymentNode(deployment_node) => {
+                deployment_node.get_interaction_mut()
+            }
+            ElementType::Artifact(artifact) => artifact.get_interaction_mut(),
+            ElementType::Package(package) => package.get_interaction_mut(),
+            ElementType::C4Boundary(c4_boundary) => {
+                c4_boundary.get_interaction_mut()
+            }
+            ElementType::Note(note) => note.get_interaction_mut(),
+            ElementType::InstanceSpecification(instance_specification) => {
+                instance_specification.get_interaction_mut()
+            }
+            ElementType::Entity(entity) => entity.get_interaction_mut(),
+            ElementType::C4Element(c4_element) => {
+                c4_element.get_interaction_mut()
+            }
         }
     }
 
-    fn adjust_position(&mut self, delta_x: i32, delta_y: i32) {
+    fn adjust_position(&mut self, delta_x: f64, delta_y: f64) {
+        self.laid_out = false;
+
         match &mut self.inner {
             ElementType::Rectangle(rectangle) => {
                 rectangle.adjust_position(delta_x, delta_y)
@@ -135,22 +1015,173 @@ impl Interactive for Element {
             ElementType::Class(class) => {
                 class.adjust_position(delta_x, delta_y)
             }
+            ElementType::Relationship(relationship) => {
+                relationship.adjust_position(delta_x, delta_y)
+            }
+            ElementType::Lifeline(lifeline) => {
+                lifeline.adjust_position(delta_x, delta_y)
+            }
+            ElementType::Message(message) => {
+                message.adjust_position(delta_x, delta_y)
+            }
+            ElementType::Activation(activation) => {
+                activation.adjust_position(delta_x, delta_y)
+            }
+            ElementType::Fragment(fragment) => {
+                fragment.adjust_position(delta_x, delta_y)
+            }
+            ElementType::Actor(actor) => {
+                actor.adjust_position(delta_x, delta_y)
+            }
+            ElementType::UseCase(use_case) => {
+                use_case.adjust_position(delta_x, delta_y)
+            }
+            ElementType::SystemBoundary(system_boundary) => {
+                system_boundary.adjust_position(delta_x, delta_y)
+            }
+            ElementType::State(state) => {
+                state.adjust_position(delta_x, delta_y)
+            }
+            ElementType::Pseudostate(pseudostate) => {
+                pseudostate.adjust_position(delta_x, delta_y)
+            }
+            ElementType::Action(action) => {
+                action.adjust_position(delta_x, delta_y)
+            }
+            ElementType::ControlNode(control_node) => {
+                control_node.adjust_position(delta_x, delta_y)
+            }
+            ElementType::ObjectNode(object_node) => {
+                object_node.adjust_position(delta_x, delta_y)
+            }
+            ElementType::Swimlane(swimlane) => {
+                swimlane.adjust_position(delta_x, delta_y)
+            }
+            ElementType::Component(component) => {
+                component.adjust_position(delta_x, delta_y)
+            }
+            ElementType::Interface(interface) => {
+                interface.adjust_position(delta_x, delta_y)
+            }
+            ElementType::Port(port) => port.adjust_position(delta_x, delta_y),
+            ElementType::DeploymentNode(deployment_node) => {
+                deployment_node.adjust_position(delta_x, delta_y)
+            }
+            ElementType::Artifact(artifact) => {
+                artifact.adjust_position(delta_x, delta_y)
+            }
+            ElementType::Package(package) => {
+                package.adjust_position(delta_x, delta_y)
+            }
+            ElementType::C4Boundary(c4_boundary) => {
+                c4_boundary.adjust_position(delta_x, delta_y)
+            }
+            ElementType::Note(note) => note.adjust_position(delta_x, delta_y),
+            ElementType::InstanceSpecification(instance_specification) => {
+                instance_specification.adjust_position(delta_x, delta_y)
+            }
+            ElementType::Entity(entity) => {
+                entity.adjust_position(delta_x, delta_y)
+            }
+            ElementType::C4Element(c4_element) => {
+                c4_element.adjust_position(delta_x, delta_y)
+            }
         }
     }
 
-    fn click(&mut self, x: i32, y: i32) -> Option<Prompt> {
+    /// Moves the element to (`x`, `y`), so it is laid out again like any
+    /// other move.
+    fn set_position(&mut self, x: f64, y: f64) {
+        self.adjust_position(x - self.x(), y - self.y());
+    }
+
+    fn click(&mut self, x: f64, y: f64) -> Option<Prompt> {
         match &mut self.inner {
             ElementType::Rectangle(rectangle) => rectangle.click(x, y),
             ElementType::Label(label) => label.click(x, y),
             ElementType::Class(class) => class.click(x, y),
+            ElementType::Relationship(relationship) => relationship.click(x, y),
+            ElementType::Lifeline(lifeline) => lifeline.click(x, y),
+            ElementType::Message(message) => message.click(x, y),
+            ElementType::Activation(activation) => activation.click(x, y),
+            ElementType::Fragment(fragment) => fragment.click(x, y),
+            ElementType::Actor(actor) => actor.click(x, y),
+            ElementType::UseCase(use_case) => use_case.click(x, y),
+            ElementType::SystemBoundary(system_boundary) => {
+                system_boundary.click(x, y)
+            }
+            ElementType::State(state) => state.click(x, y),
+            ElementType::Pseudostate(pseudostate) => pseudostate.click(x, y),
+            ElementType::Action(action) => action.click(x, y),
+            ElementType::ControlNode(control_node) => control_node.click(x, y),
+            ElementType::ObjectNode(object_node) => object_node.click(x, y),
+            ElementType::Swimlane(swimlane) => swimlane.click(x, y),
+            ElementType::Component(component) => component.click(x, y),
+            ElementType::Interface(interface) => interface.click(x, y),
+            ElementType::Port(port) => port.click(x, y),
+            ElementType::DeploymentNode(deployment_node) => {
+                deployment_node.click(x, y)
+            }
+            ElementType::Artifact(artifact) => artifact.click(x, y),
+            ElementType::Package(package) => package.click(x, y),
+            ElementType::C4Boundary(c4_boundary) => c4_boundary.click(x, y),
+            ElementType::Note(note) => note.click(x, y),
+            ElementType::InstanceSpecification(instance_specification) => {
+                instance_specification.click(x, y)
+            }
+            ElementType::Entity(entity) => entity.click(x, y),
+            ElementType::C4Element(c4_element) => c4_element.click(x, y),
         }
     }
 
     fn prompt(&mut self, response: PromptResponse) {
+        self.laid_out = false;
+
         match &mut self.inner {
             ElementType::Rectangle(rectangle) => rectangle.prompt(response),
             ElementType::Label(label) => label.prompt(response),
             ElementType::Class(class) => class.prompt(response),
+            ElementType::Relationship(relationship) => {
+                relationship.prompt(response)
+            }
+            ElementType::Lifeline(lifeline) => lifeline.prompt(response),
+            ElementType::Message(message) => message.prompt(response),
+            ElementType::Activation(activation) => activation.prompt(response),
+            ElementType::Fragment(fragment) => fragment.prompt(response),
+            ElementType::Actor(actor) => actor.prompt(response),
+            ElementType::UseCase(use_case) => use_case.prompt(response),
+            ElementType::SystemBoundary(system_boundary) => {
+                system_boundary.prompt(response)
+            }
+            ElementType::State(state) => state.prompt(response),
+            ElementType::Pseudostate(pseudostate) => {
+                pseudostate.prompt(response)
+            }
+            ElementType::Action(action) => action.prompt(response),
+            ElementType::ControlNode(control_node) => {
+                control_node.prompt(response)
+            }
+            ElementType::ObjectNode(object_node) => {
+                object_node.prompt(response)
+            }
+            ElementType::Swimlane(swimlane) => swimlane.prompt(response),
+            ElementType::Component(component) => component.prompt(response),
+            ElementType::Interface(interface) => interface.prompt(response),
+            ElementType::Port(port) => port.prompt(response),
+            ElementType::DeploymentNode(deployment_node) => {
+                deployment_node.prompt(response)
+            }
+            ElementType::Artifact(artifact) => artifact.prompt(response),
+            ElementType::Package(package) => package.prompt(response),
+            ElementType::C4Boundary(c4_boundary) => {
+                c4_boundary.prompt(response)
+            }
+            ElementType::Note(note) => note.prompt(response),
+            ElementType::InstanceSpecification(instance_specification) => {
+                instance_specification.prompt(response)
+            }
+            ElementType::Entity(entity) => entity.prompt(response),
+            ElementType::C4Element(c4_element) => c4_element.prompt(response),
         }
     }
 }
@@ -160,6 +1191,7 @@ impl From<Rectangle> for Element {
         Self {
             id: Id::default(),
             inner: ElementType::Rectangle(value),
+            laid_out: false,
         }
     }
 }
@@ -169,6 +1201,7 @@ impl From<Label> for Element {
         Self {
             id: Id::default(),
             inner: ElementType::Label(value),
+            laid_out: false,
         }
     }
 }
@@ -178,6 +1211,257 @@ impl From<Class> for Element {
         Self {
             id: Id::default(),
             inner: ElementType::Class(value),
+            laid_out: false,
+        }
+    }
+}
+
+impl From<Relationship> for Element {
+    fn from(value: Relationship) -> Self {
+        Self {
+            id: Id::default(),
+            inner: ElementType::Relationship(value),
+            laid_out: false,
+        }
+    }
+}
+
+impl From<Lifeline> for Element {
+    fn from(value: Lifeline) -> Self {
+        Self {
+            id: Id::default(),
+            inner: ElementType::Lifeline(value),
+            laid_out: false,
+        }
+    }
+}
+
+impl From<Message> for Element {
+    fn from(value: Message) -> Self {
+        Self {
+            id: Id::default(),
+            inner: ElementType::Message(value),
+            laid_out: false,
+        }
+    }
+}
+
+impl From<Activation> for Element {
+    fn from(value: Activation) -> Self {
+        Self {
+            id: Id::default(),
+            inner: ElementType::Activation(value),
+            laid_out: false,
+        }
+    }
+}
+
+impl From<Fragment> for Element {
+    fn from(value: Fragment) -> Self {
+        Self {
+            id: Id::default(),
+            inner: ElementType::Fragment(value),
+            laid_out: false,
+        }
+    }
+}
+
+impl From<Actor> for Element {
+    fn from(value: Actor) -> Self {
+        Self {
+            id: Id::default(),
+            inner: ElementType::Actor(value),
+            laid_out: false,
+        }
+    }
+}
+
+impl From<UseCase> for Element {
+    fn from(value: UseCase) -> Self {
+        Self {
+            id: Id::default(),
+            inner: ElementType::UseCase(value),
+            laid_out: false,
+        }
+    }
+}
+
+impl From<SystemBoundary> for Element {
+    fn from(value: SystemBoundary) -> Self {
+        Self {
+            id: Id::default(),
+            inner: ElementType::SystemBoundary(value),
+            laid_out: false,
+        }
+    }
+}
+
+impl From<State> for Element {
+    fn from(value: State) -> Self {
+        Self {
+            id: Id::default(),
+            inner: ElementType::State(value),
+            laid_out: false,
+        }
+    }
+}
+
+impl From<Pseudostate> for Element {
+    fn from(value: Pseudostate) -> Self {
+        Self {
+            id: Id::default(),
+            inner: ElementType::Pseudostate(value),
+            laid_out: false,
+        }
+    }
+}
+
+impl From<Action> for Element {
+    fn from(value: Action) -> Self {
+        Self {
+            id: Id::default(),
+            inner: ElementType::Action(value),
+            laid_out: false,
+        }
+    }
+}
+
+impl From<ControlNode> for Element {
+    fn from(value: ControlNode) -> Self {
+        Self {
+            id: Id::default(),
+            inner: ElementType::ControlNode(value),
+            laid_out: false,
+        }
+    }
+}
+
+impl From<ObjectNode> for Element {
+    fn from(value: ObjectNode) -> Self {
+        Self {
+            id: Id::default(),
+            inner: ElementType::ObjectNode(value),
+            laid_out: false,
+        }
+    }
+}
+
+impl From<Swimlane> for Element {
+    fn from(value: Swimlane) -> Self {
+        Self {
+            id: Id::default(),
+            inner: ElementType::Swimlane(value),
+            laid_out: false,
+        }
+    }
+}
+
+impl From<Component> for Element {
+    fn from(value: Component) -> Self {
+        Self {
+            id: Id::default(),
+            inner: ElementType::Component(value),
+            laid_out: false,
+        }
+    }
+}
+
+impl From<Interface> for Element {
+    fn from(value: Interface) -> Self {
+        Self {
+            id: Id::default(),
+            inner: ElementType::Interface(value),
+            laid_out: false,
+        }
+    }
+}
+
+impl From<Port> for Element {
+    fn from(value: Port) -> Self {
+        Self {
+            id: Id::default(),
+            inner: ElementType::Port(value),
+            laid_out: false,
+        }
+    }
+}
+
+impl From<DeploymentNode> for Element {
+    fn from(value: DeploymentNode) -> Self {
+        Self {
+            id: Id::default(),
+            inner: ElementType::DeploymentNode(value),
+            laid_out: false,
+        }
+    }
+}
+
+impl From<Artifact> for Element {
+    fn from(value: Artifact) -> Self {
+        Self {
+            id: Id::default(),
+            inner: ElementType::Artifact(value),
+            laid_out: false,
+        }
+    }
+}
+
+impl From<Package> for Element {
+    fn from(value: Package) -> Self {
+        Self {
+            id: Id::default(),
+            inner: ElementType::Package(value),
+            laid_out: false,
+        }
+    }
+}
+
+impl From<Note> for Element {
+    fn from(value: Note) -> Self {
+        Self {
+            id: Id::default(),
+            inner: ElementType::Note(value),
+            laid_out: false,
+        }
+    }
+}
+
+impl From<InstanceSpecification> for Element {
+    fn from(value: InstanceSpecification) -> Self {
+        Self {
+            id: Id::default(),
+            inner: ElementType::InstanceSpecification(value),
+            laid_out: false,
+        }
+    }
+}
+
+impl From<Entity> for Element {
+    fn from(value: Entity) -> Self {
+        Self {
+            id: Id::default(),
+            inner: ElementType::Entity(value),
+            laid_out: false,
+        }
+    }
+}
+
+impl From<C4Element> for Element {
+    fn from(value: C4Element) -> Self {
+        Self {
+            id: Id::default(),
+            inner: ElementType::C4Element(value),
+            laid_out: false,
+        }
+    }
+}
+
+impl From<C4Boundary> for Element {
+    fn from(value: C4Boundary) -> Self {
+        Self {
+            id: Id::default(),
+            inner: ElementType::C4Boundary(value),
+            laid_out: false,
         }
     }
 }
@@ -0,0 +1,460 @@
+use std::rc::Rc;
+
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    camera::Camera,
+    canvas::Canvas,
+    color::Color,
+    drawable::Drawable,
+    geometry::Rect,
+    interaction::{InteractionState, Interactive},
+    prompt::{Prompt, PromptResponse},
+    stroke::Stroke,
+};
+
+use super::{Label, Path, Rectangle, TextProperties};
+
+const DEFAULT_COLOR: Color = const {
+    Color::Rgb {
+        red: 252,
+        green: 252,
+        blue: 252,
+    }
+};
+
+const DEFAULT_STROKE: Stroke = const {
+    Stroke::new(
+        2,
+        Color::Rgb {
+            red: 90,
+            green: 90,
+            blue: 90,
+        },
+    )
+};
+
+const HIGHLIGHT_STROKE: Stroke = const {
+    Stroke::new(
+        2,
+        Color::Rgb {
+            red: 142,
+            green: 202,
+            blue: 230,
+        },
+    )
+};
+
+const TEXT_COLOR: Color = const {
+    Color::Rgb {
+        red: 31,
+        green: 31,
+        blue: 31,
+    }
+};
+
+const HEADER_COLOR: Color = const {
+    Color::Rgb {
+        red: 232,
+        green: 238,
+        blue: 244,
+    }
+};
+
+const MARGIN: f64 = 10.0;
+const SPACING: f64 = 8.0;
+const MIN_WIDTH: f64 = 140.0;
+
+/// A key an attribute of an entity takes part in.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum Key {
+    Primary,
+    Foreign,
+    Unique,
+}
+
+impl Key {
+    /// The abbreviation shown in the key column (e.g. `PK`).
+    pub fn abbreviation(&self) -> &'static str {
+        match self {
+            Key::Primary => "PK",
+            Key::Foreign => "FK",
+            Key::Unique => "UK",
+        }
+    }
+
+    fn parse(text: &str) -> Option<Self> {
+        [Key::Primary, Key::Foreign, Key::Unique]
+            .into_iter()
+            .find(|key| key.abbreviation().eq_ignore_ascii_case(text))
+    }
+}
+
+/// A row of an entity: the keys the attribute takes part in, and the
+/// attribute itself, written as `name: type`.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct EntityAttribute {
+    keys: Label,
+    text: Label,
+}
+
+impl EntityAttribute {
+    pub fn new(name: &str, ty: Option<&str>, keys: &[Key]) -> Self {
+        let text = match ty {
+            Some(ty) => format!("{name}: {ty}"),
+            None => name.to_string(),
+        };
+        let keys = keys
+            .iter()
+            .map(Key::abbreviation)
+            .collect::<Vec<_>>()
+            .join(", ");
+
+        Self {
+            keys: Label::new(
+                0.0,
+                0.0,
+                keys,
+                TextProperties::default().weight(700),
+                TEXT_COLOR,
+            ),
+            text: Label::new(
+                0.0,
+                0.0,
+                text,
+                TextProperties::default(),
+                TEXT_COLOR,
+            ),
+        }
+    }
+
+    /// Parses a row written as `PK, FK name: type`. The keys and the type
+    /// are optional.
+    pub fn parse(text: &str) -> Self {
+        let mut keys = vec![];
+        let mut rest = text.trim();
+
+        while let Some((word, tail)) = rest.split_once([' ', ',']) {
+            let Some(key) = Key::parse(word) else {
+                break;
+            };
+            keys.push(key);
+            rest = tail.trim_start_matches([' ', ',']);
+        }
+
+        let (name, ty) = match rest.split_once(':') {
+            Some((name, ty)) => (name.trim(), Some(ty.trim())),
+            None => (rest.trim(), None),
+        };
+
+        Self::new(name, ty, &keys)
+    }
+
+    pub fn keys(&self) -> Vec<Key> {
+        self.keys
+            .text()
+            .split(", ")
+            .filter_map(Key::parse)
+            .collect()
+    }
+
+    pub fn name(&self) -> &str {
+        match self.text.text().split_once(':') {
+            Some((name, _)) => name.trim(),
+            None => self.text.text(),
+        }
+    }
+
+    pub fn ty(&self) -> Option<&str> {
+        self.text.text().split_once(':').map(|(_, ty)| ty.trim())
+    }
+
+    /// The row as written by [`EntityAttribute::parse`].
+    pub fn text(&self) -> String {
+        match self.keys.text() {
+            "" => self.text.text().to_string(),
+            keys => format!("{keys} {}", self.text.text()),
+        }
+    }
+
+    fn labels_mut(&mut self) -> [&mut Label; 2] {
+        [&mut self.keys, &mut self.text]
+    }
+}
+
+/// An entity of an entity-relationship diagram: a table-like box with the
+/// name of the entity in its header and a row per attribute, with the keys
+/// the attribute takes part in (`PK`, `FK`, `UK`) in a column of their own.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Entity {
+    x: f64,
+    y: f64,
+    title: Label,
+    #[serde(default)]
+    attributes: Vec<EntityAttribute>,
+
+    #[serde(skip)]
+    interaction_state: InteractionState,
+}
+
+impl Entity {
+    pub fn new(x: f64, y: f64, name: String) -> Self {
+        Self {
+            x,
+            y,
+            title: Label::new(
+                0.0,
+                0.0,
+                name,
+                TextProperties::default().weight(700),
+                TEXT_COLOR,
+            ),
+            attributes: vec![],
+            interaction_state: InteractionState::default(),
+        }
+    }
+
+    pub fn name(&self) -> &str {
+        self.title.text()
+    }
+
+    pub fn set_name(&mut self, value: String) {
+        self.title.set_text(value);
+    }
+
+    pub fn attributes(&self) -> &[EntityAttribute] {
+        &self.attributes
+    }
+
+    pub fn set_attributes(
+        &mut self,
+        values: impl IntoIterator<Item = EntityAttribute>,
+    ) {
+        self.attributes = values.into_iter().collect();
+    }
+
+    pub fn add_attribute(&mut self, value: EntityAttribute) {
+        self.attributes.push(value);
+    }
+
+    /// The names of the attributes that make up the primary key.
+    pub fn primary_key(&self) -> Vec<&str> {
+        self.attributes
+            .iter()
+            .filter(|a| a.keys().contains(&Key::Primary))
+            .map(EntityAttribute::name)
+            .collect()
+    }
+
+    pub fn x(&self) -> f64 {
+        self.x
+    }
+
+    pub fn y(&self) -> f64 {
+        self.y
+    }
+
+    pub fn width(&self) -> f64 {
+        let rows = self
+            .attributes
+            .iter()
+            .map(|a| a.text.width().unwrap_or(0.0))
+            .fold(0.0, f64::max);
+        let title = self.title.width().unwrap_or(0.0);
+
+        f64::max(
+            f64::max(title, self.key_column() + rows) + 2.0 * MARGIN,
+            MIN_WIDTH,
+        )
+    }
+
+    pub fn height(&self) -> f64 {
+        let mut height = self.header_height();
+
+        for attribute in &self.attributes {
+            height += attribute.text.height().unwrap_or(0.0) + SPACING;
+        }
+
+        if !self.attributes.is_empty() {
+            height += MARGIN;
+        }
+
+        height
+    }
+
+    fn header_height(&self) -> f64 {
+        self.title.height().unwrap_or(0.0) + 2.0 * MARGIN
+    }
+
+    /// The width of the key column, including the space after it. Entities
+    /// without keys have none.
+    fn key_column(&self) -> f64 {
+        let width = self
+            .attributes
+            .iter()
+            .map(|a| a.keys.width().unwrap_or(0.0))
+            .fold(0.0, f64::max);
+
+        if width > 0.0 { width + MARGIN } else { 0.0 }
+    }
+
+    /// The index of the row at height `y`, or the number of rows below the
+    /// last one. `None` on the header.
+    fn row_at(&self, y: f64) -> Option<usize> {
+        if y <= self.y + self.header_height() {
+            return None;
+        }
+
+        Some(
+            self.attributes
+                .iter()
+                .position(|a| y <= a.text.y() + (SPACING / 2.0))
+                .unwrap_or(self.attributes.len()),
+        )
+    }
+}
+
+impl Drawable for Entity {
+    fn initalize(&mut self, canvas: &impl Canvas) {
+        self.title.initalize(canvas);
+        self.title.set_position(self.x + MARGIN, self.y + MARGIN);
+
+        for attribute in &mut self.attributes {
+            for label in attribute.labels_mut() {
+                label.initalize(canvas);
+            }
+        }
+
+        let key_column = self.key_column();
+        let mut offset_y = self.y + (self.header_height() + SPACING);
+
+        for attribute in &mut self.attributes {
+            attribute.keys.set_position(self.x + MARGIN, offset_y);
+            attribute
+                .text
+                .set_position(self.x + MARGIN + key_column, offset_y);
+            offset_y = attribute.text.y() + SPACING;
+        }
+    }
+
+    fn draw(&self, canvas: &impl Canvas, camera: &Camera) {
+        let stroke = if self.is_highlighted() {
+            HIGHLIGHT_STROKE
+        } else {
+            DEFAULT_STROKE
+        };
+
+        let (width, header) = (self.width(), self.header_height());
+
+        Rectangle::new(
+            self.x,
+            self.y,
+            width,
+            self.height(),
+            DEFAULT_COLOR,
+            None,
+            Some(stroke),
+        )
+        .draw(canvas, camera);
+
+        Rectangle::new(
+            self.x,
+            self.y,
+            width,
+            header,
+            HEADER_COLOR,
+            None,
+            Some(stroke),
+        )
+        .draw(canvas, camera);
+
+        let key_column = self.key_column();
+
+        if key_column > 0.0 && !self.attributes.is_empty() {
+            let x = self.x + (MARGIN + key_column) - MARGIN / 2.0;
+            let (top, bottom) = (self.y + header, self.bounds().bottom());
+            Path::line((x, top), (x, bottom), stroke).draw(canvas, camera);
+        }
+
+        self.title.draw(canvas, camera);
+
+        for attribute in &self.attributes {
+            attribute.keys.draw(canvas, camera);
+            attribute.text.draw(canvas, camera);
+        }
+    }
+
+    fn bounds(&self) -> Rect {
+        Rect::new(self.x, self.y, self.width(), self.height())
+    }
+}
+
+impl Interactive for Entity {
+    fn get_interaction(&self) -> InteractionState {
+        self.interaction_state
+    }
+
+    fn get_interaction_mut(&mut self) -> &mut InteractionState {
+        &mut self.interaction_state
+    }
+
+    fn adjust_position(&mut self, delta_x: f64, delta_y: f64) {
+        self.x += delta_x;
+        self.y += delta_y;
+        self.title.adjust_position(delta_x, delta_y);
+
+        for attribute in &mut self.attributes {
+            for label in attribute.labels_mut() {
+                label.adjust_position(delta_x, delta_y);
+            }
+        }
+    }
+
+    /// Edits the name when clicking the header, or the clicked row.
+    /// Clicking below the last row adds one.
+    #[allow(unused_variables)]
+    fn click(&mut self, x: f64, y: f64) -> Option<Prompt> {
+        let Some(index) = self.row_at(self.y + y) else {
+            return Some(Prompt::Text {
+                explanation: "Provide this entity with a new name".into(),
+                placeholder: "Entity name".into(),
+                value: self.title.text().to_string(),
+                metadata: Rc::new(()),
+            });
+        };
+
+        Some(Prompt::Text {
+            explanation: "Provide the keys, name and type of an attribute"
+                .into(),
+            placeholder: "PK, FK name: type".into(),
+            value: self
+                .attributes
+                .get(index)
+                .map(EntityAttribute::text)
+                .unwrap_or_default(),
+            metadata: Rc::new(index),
+        })
+    }
+
+    fn prompt(&mut self, response: PromptResponse) {
+        let PromptResponse::Text { response, metadata } = response;
+
+        let Some(index) = metadata.downcast_ref::<usize>().copied() else {
+            if !response.is_empty() {
+                self.title.set_text(response);
+            }
+            return;
+        };
+
+        let attribute = EntityAttribute::parse(&response);
+
+        match (self.attributes.get_mut(index), response.is_empty()) {
+            (Some(row), false) => *row = attribute,
+            (Some(_), true) => {
+                self.attributes.remove(index);
+            }
+            (None, false) => self.attributes.push(attribute),
+            (None, true) => (),
+        }
+    }
+}
@@ -0,0 +1,367 @@
+use std::rc::Rc;
+
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    camera::Camera,
+    canvas::Canvas,
+    color::{Color, WHITE},
+    drawable::Drawable,
+    geometry::{Point, Rect},
+    id::Id,
+    interaction::{InteractionState, Interactive},
+    prompt::{Prompt, PromptResponse},
+    stroke::Stroke,
+};
+
+use super::{Label, Path, TextProperties};
+
+const DEFAULT_STROKE: Stroke = const {
+    Stroke::new(
+        2,
+        Color::Rgb {
+            red: 90,
+            green: 90,
+            blue: 90,
+        },
+    )
+};
+
+const HIGHLIGHT_STROKE: Stroke = const {
+    Stroke::new(
+        2,
+        Color::Rgb {
+            red: 142,
+            green: 202,
+            blue: 230,
+        },
+    )
+};
+
+const TEXT_COLOR: Color = const {
+    Color::Rgb {
+        red: 31,
+        green: 31,
+        blue: 31,
+    }
+};
+
+/// Space between the frame and the headers of the outermost lifelines.
+const PADDING: f64 = 20.0;
+/// Extra inset of a fragment for every fragment it is nested in.
+const NESTING: f64 = 8.0;
+const TAB_HEIGHT: f64 = 24.0;
+const TAB_MARGIN: f64 = 8.0;
+const TAB_CORNER: f64 = 8.0;
+const HIT_DISTANCE: f64 = 6.0;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum FragmentOperator {
+    /// Alternatives, of which the one whose guard holds is executed.
+    Alt,
+    /// An optional part, executed if its guard holds.
+    Opt,
+    /// A part repeated while its guard holds.
+    Loop,
+    /// Operands executed in parallel.
+    Par,
+    /// A reference to an interaction shown elsewhere.
+    Ref,
+}
+
+impl FragmentOperator {
+    pub fn keyword(&self) -> &'static str {
+        match self {
+            FragmentOperator::Alt => "alt",
+            FragmentOperator::Opt => "opt",
+            FragmentOperator::Loop => "loop",
+            FragmentOperator::Par => "par",
+            FragmentOperator::Ref => "ref",
+        }
+    }
+}
+
+/// One part of a combined fragment, from `y` down to the next operand (or the
+/// bottom of the fragment).
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Operand {
+    y: f64,
+    guard: Option<String>,
+}
+
+impl Operand {
+    pub fn y(&self) -> f64 {
+        self.y
+    }
+
+    /// The guard condition, without brackets (e.g. `stock > 0`).
+    pub fn guard(&self) -> Option<&str> {
+        self.guard.as_deref()
+    }
+}
+
+/// A combined fragment of a sequence diagram: a frame around the messages of
+/// several lifelines, with the interaction operator in a tab at the top left
+/// and dashed lines separating the operands. The frame spans the lifelines it
+/// covers, so it follows them when they move; vertically it keeps its own
+/// position like messages do.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Fragment {
+    operator: FragmentOperator,
+    lifelines: Vec<Id>,
+    operands: Vec<Operand>,
+    bottom: f64,
+    /// The referenced interaction of `ref` fragments.
+    #[serde(default)]
+    text: Option<String>,
+
+    #[serde(skip)]
+    left: f64,
+    #[serde(skip)]
+    right: f64,
+    #[serde(skip)]
+    tab_width: f64,
+    #[serde(skip)]
+    interaction_state: InteractionState,
+}
+
+impl Fragment {
+    pub fn new(
+        operator: FragmentOperator,
+        lifelines: Vec<Id>,
+        top: f64,
+        bottom: f64,
+    ) -> Self {
+        Self {
+            operator,
+            lifelines,
+            operands: vec![Operand {
+                y: f64::min(top, bottom),
+                guard: None,
+            }],
+            bottom: f64::max(top, bottom),
+            text: None,
+            left: 0.0,
+            right: 0.0,
+            tab_width: 0.0,
+            interaction_state: InteractionState::default(),
+        }
+    }
+
+    pub fn operator(&self) -> FragmentOperator {
+        self.operator
+    }
+
+    /// The lifelines covered by the fragment.
+    pub fn lifelines(&self) -> &[Id] {
+        &self.lifelines
+    }
+
+    pub fn operands(&self) -> &[Operand] {
+        &self.operands
+    }
+
+    /// Adds an operand starting at `y`, which splits the operand it falls in.
+    /// Heights outside of the fragment are ignored.
+    pub fn add_operand(&mut self, y: f64, guard: Option<String>) {
+        if y <= self.y() || y >= self.bottom {
+            return;
+        }
+
+        let index = self.operands.partition_point(|o| o.y < y);
+        self.operands.insert(index, Operand { y, guard });
+    }
+
+    pub fn set_guard(&mut self, index: usize, value: Option<String>) {
+        if let Some(operand) = self.operands.get_mut(index) {
+            operand.guard = value;
+        }
+    }
+
+    pub fn text(&self) -> Option<&str> {
+        self.text.as_deref()
+    }
+
+    pub fn set_text(&mut self, value: Option<String>) {
+        self.text = value;
+    }
+
+    pub fn x(&self) -> f64 {
+        self.left
+    }
+
+    pub fn y(&self) -> f64 {
+        self.operands[0].y
+    }
+
+    pub fn bottom(&self) -> f64 {
+        self.bottom
+    }
+
+    /// Stretches the frame over `span`, the horizontal extent of the headers
+    /// of its lifelines, inset by `depth` levels of nesting.
+    pub fn attach(&mut self, span: (f64, f64), depth: f64) {
+        let inset = PADDING - depth * NESTING;
+        self.left = span.0 - inset;
+        self.right = span.1 + inset;
+    }
+
+    pub fn cursor_intersects(&self, x: f64, y: f64) -> bool {
+        let bounds = self.bounds();
+        let cursor = Point::new(x, y);
+        let tab =
+            Rect::new(bounds.left(), bounds.top(), self.tab_width, TAB_HEIGHT);
+
+        tab.contains(cursor)
+            || bounds.distance_to_border(cursor) <= HIT_DISTANCE
+    }
+
+    /// The operand at height `y`, if any.
+    fn operand_at(&self, y: f64) -> Option<usize> {
+        if y < self.y() || y > self.bottom {
+            return None;
+        }
+
+        Some(
+            self.operands
+                .partition_point(|o| o.y <= y)
+                .saturating_sub(1),
+        )
+    }
+
+    fn props() -> TextProperties {
+        TextProperties::new(16.0, "Arial,sans-serif")
+    }
+}
+
+impl Drawable for Fragment {
+    fn initalize(&mut self, canvas: &impl Canvas) {
+        let keyword = self.operator.keyword();
+        let width = canvas
+            .measure_text(keyword, &Self::props().weight(700))
+            .map(|size| size.width() as f64)
+            .unwrap_or(keyword.len() as f64 * 9.0);
+
+        self.tab_width = width + 2.0 * TAB_MARGIN + TAB_CORNER;
+    }
+
+    fn draw(&self, canvas: &impl Canvas, camera: &Camera) {
+        let stroke = if self.is_highlighted() {
+            HIGHLIGHT_STROKE
+        } else {
+            DEFAULT_STROKE
+        };
+
+        let (l, t, r, b) = self.bounds().edges();
+        let label_at = |text: &str, (x, y): (f64, f64), bold: bool| {
+            let props = match bold {
+                true => Self::props().weight(700),
+                false => Self::props(),
+            };
+            Label::new(x, y, text, props, TEXT_COLOR).draw(canvas, camera);
+        };
+
+        Path::new(vec![(l, t), (r, t), (r, b), (l, b)], Some(stroke))
+            .closed(None)
+            .draw(canvas, camera);
+
+        for operand in &self.operands[1..] {
+            Path::line((l, operand.y), (r, operand.y), stroke)
+                .dashed(true)
+                .draw(canvas, camera);
+        }
+
+        let tab_right = l + self.tab_width;
+        Path::new(
+            vec![
+                (l, t),
+                (tab_right, t),
+                (tab_right, t + TAB_HEIGHT - TAB_CORNER),
+                (tab_right - TAB_CORNER, t + TAB_HEIGHT),
+                (l, t + TAB_HEIGHT),
+            ],
+            Some(stroke),
+        )
+        .closed(Some(WHITE))
+        .draw(canvas, camera);
+
+        let baseline = TAB_HEIGHT - TAB_MARGIN + 2.0;
+        label_at(
+            self.operator.keyword(),
+            (l + TAB_MARGIN, t + baseline),
+            true,
+        );
+
+        if let Some(text) = self.text() {
+            label_at(text, (l + TAB_MARGIN, t + TAB_HEIGHT + baseline), false);
+        }
+
+        for (i, operand) in self.operands.iter().enumerate() {
+            let Some(guard) = operand.guard() else {
+                continue;
+            };
+
+            // The guard of the first operand sits next to the tab.
+            let x = match i {
+                0 => tab_right + TAB_MARGIN,
+                _ => l + TAB_MARGIN,
+            };
+            label_at(&format!("[{guard}]"), (x, operand.y + baseline), false);
+        }
+    }
+
+    fn bounds(&self) -> Rect {
+        Rect::from_edges(self.left, self.y(), self.right, self.bottom)
+    }
+}
+
+impl Interactive for Fragment {
+    fn get_interaction(&self) -> InteractionState {
+        self.interaction_state
+    }
+
+    fn get_interaction_mut(&mut self) -> &mut InteractionState {
+        &mut self.interaction_state
+    }
+
+    /// Fragments follow their lifelines sideways, so they can only be moved
+    /// up and down.
+    fn adjust_position(&mut self, _: f64, delta_y: f64) {
+        self.bottom += delta_y;
+
+        for operand in &mut self.operands {
+            operand.y += delta_y;
+        }
+    }
+
+    #[allow(unused_variables)]
+    fn click(&mut self, x: f64, y: f64) -> Option<Prompt> {
+        if self.operator == FragmentOperator::Ref {
+            return Some(Prompt::Text {
+                explanation: "Provide the referenced interaction".into(),
+                placeholder: "Interaction name".into(),
+                value: self.text.clone().unwrap_or_default(),
+                metadata: Rc::new(()),
+            });
+        }
+
+        let index = self.operand_at(self.y() + y)?;
+
+        Some(Prompt::Text {
+            explanation: "Provide this operand with a guard condition".into(),
+            placeholder: "condition".into(),
+            value: self.operands[index].guard.clone().unwrap_or_default(),
+            metadata: Rc::new(index),
+        })
+    }
+
+    fn prompt(&mut self, response: PromptResponse) {
+        let PromptResponse::Text { response, metadata } = response;
+        let value = (!response.is_empty()).then_some(response);
+
+        match metadata.downcast_ref::<usize>() {
+            Some(index) => self.set_guard(*index, value),
+            None => self.text = value,
+        }
+    }
+}
@@ -3,13 +3,14 @@ use crate::{
     canvas::Canvas,
     color::{BLACK, Color},
     drawable::Drawable,
+    geometry::Rect,
     stroke::Stroke,
 };
 
 use super::{Label, Rectangle, TextProperties};
 
-const MARGIN: i32 = 30;
-const PADDING: i32 = 10;
+const MARGIN: f64 = 30.0;
+const PADDING: f64 = 10.0;
 
 const TEXT_COLOR: Color = BLACK;
 const BACKGROUND_COLOR: Color = const {
@@ -21,7 +22,7 @@ const BACKGROUND_COLOR: Color = const {
 };
 
 const STROKE: Stroke = const { Stroke::new(2, BLACK) };
-const BORDER_RADIUS: u32 = 2;
+const BORDER_RADIUS: f64 = 2.0;
 
 #[derive(Debug, Clone, PartialEq)]
 pub struct Info {
@@ -44,13 +45,13 @@ impl Drawable for Info {
             return;
         };
         let (width, height) =
-            (measurement.width() as i32, measurement.height() as i32);
+            (measurement.width() as f64, measurement.height() as f64);
 
         let bg = Rectangle::new(
             MARGIN,
             MARGIN,
-            (width + PADDING * 2) as u32,
-            (height + PADDING * 2) as u32,
+            width + PADDING * 2.0,
+            height + PADDING * 2.0,
             BACKGROUND_COLOR,
             Some(BORDER_RADIUS),
             Some(STROKE),
@@ -66,6 +67,12 @@ impl Drawable for Info {
         );
         label.draw_fixed(canvas);
     }
+
+    /// The info box is only measured as it is drawn, so this is just its
+    /// top left corner on the canvas.
+    fn bounds(&self) -> Rect {
+        Rect::new(MARGIN, MARGIN, 0.0, 0.0)
+    }
 }
 
 impl Default for Info {
@@ -0,0 +1,422 @@
+use std::rc::Rc;
+
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    camera::Camera,
+    canvas::Canvas,
+    color::Color,
+    drawable::Drawable,
+    geometry::Rect,
+    id::Id,
+    interaction::{InteractionState, Interactive},
+    prompt::{Prompt, PromptResponse},
+    stroke::Stroke,
+};
+
+use super::{Label, Path, Rectangle, TextProperties};
+
+const DEFAULT_COLOR: Color = const {
+    Color::Rgb {
+        red: 252,
+        green: 252,
+        blue: 252,
+    }
+};
+
+const DEFAULT_STROKE: Stroke = const {
+    Stroke::new(
+        2,
+        Color::Rgb {
+            red: 90,
+            green: 90,
+            blue: 90,
+        },
+    )
+};
+
+const HIGHLIGHT_STROKE: Stroke = const {
+    Stroke::new(
+        2,
+        Color::Rgb {
+            red: 142,
+            green: 202,
+            blue: 230,
+        },
+    )
+};
+
+const TEXT_COLOR: Color = const {
+    Color::Rgb {
+        red: 31,
+        green: 31,
+        blue: 31,
+    }
+};
+
+/// Color of slots for attributes the class does not have.
+const ERROR_COLOR: Color = const {
+    Color::Rgb {
+        red: 200,
+        green: 40,
+        blue: 40,
+    }
+};
+
+const MARGIN: f64 = 12.0;
+const SPACING: f64 = 8.0;
+const MIN_WIDTH: f64 = 140.0;
+const MIN_HEIGHT: f64 = 50.0;
+/// Gap between the title and its underline.
+const UNDERLINE: f64 = 2.0;
+
+/// An instance specification of an object diagram: a rectangle titled with
+/// the underlined `name : Class`, and a slot compartment listing the values
+/// of its attributes (`attribute = value`). It can be linked to a class of
+/// the document, whose name it then shows and whose attributes its slots are
+/// checked against.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct InstanceSpecification {
+    x: f64,
+    y: f64,
+    name: String,
+    /// The name of the classifier, used unless the instance is linked to a
+    /// class.
+    #[serde(default)]
+    classifier: Option<String>,
+    #[serde(default)]
+    class: Option<Id>,
+    #[serde(default)]
+    slots: Vec<Label>,
+
+    #[serde(skip, default = "title")]
+    title: Label,
+    /// The name and the attribute names of the linked class.
+    #[serde(skip)]
+    class_info: Option<(String, Vec<String>)>,
+    #[serde(skip)]
+    interaction_state: InteractionState,
+}
+
+impl InstanceSpecification {
+    pub fn new(
+        x: f64,
+        y: f64,
+        name: String,
+        classifier: Option<String>,
+    ) -> Self {
+        let mut instance = Self {
+            x,
+            y,
+            name,
+            classifier,
+            class: None,
+            slots: vec![],
+            title: title(),
+            class_info: None,
+            interaction_state: InteractionState::default(),
+        };
+        instance.refresh_title();
+        instance
+    }
+
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    pub fn set_name(&mut self, value: String) {
+        self.name = value;
+        self.refresh_title();
+    }
+
+    /// The name of the class of the instance: the linked class if there is
+    /// one, or the written classifier.
+    pub fn classifier(&self) -> Option<&str> {
+        match &self.class_info {
+            Some((name, _)) => Some(name),
+            None => self.classifier.as_deref(),
+        }
+    }
+
+    pub fn set_classifier(&mut self, value: Option<String>) {
+        self.classifier = value;
+        self.refresh_title();
+    }
+
+    /// The class of the document this is an instance of.
+    pub fn class(&self) -> Option<Id> {
+        self.class
+    }
+
+    pub fn set_class(&mut self, value: Option<Id>) {
+        self.class = value;
+    }
+
+    /// The title, written as `name : Class`.
+    pub fn title(&self) -> &str {
+        self.title.text()
+    }
+
+    /// The slots of the instance, as `(attribute, value)` pairs.
+    pub fn slots(&self) -> impl Iterator<Item = (&str, &str)> {
+        self.slots.iter().map(|slot| split_slot(slot.text()))
+    }
+
+    /// Sets the value of the slot for `attribute`, adding the slot if there
+    /// is none.
+    pub fn set_slot(&mut self, attribute: &str, value: &str) {
+        let text = format!("{attribute} = {value}");
+
+        match self
+            .slots
+            .iter_mut()
+            .find(|slot| split_slot(slot.text()).0 == attribute)
+        {
+            Some(slot) => slot.set_text(text),
+            None => self.slots.push(slot(text)),
+        }
+
+        self.check_slots();
+    }
+
+    pub fn remove_slot(&mut self, attribute: &str) {
+        self.slots
+            .retain(|slot| split_slot(slot.text()).0 != attribute);
+    }
+
+    /// The slots for attributes the linked class does not have. Instances
+    /// without a linked class are not checked.
+    pub fn unknown_slots(&self) -> Vec<&str> {
+        let Some((_, attributes)) = &self.class_info else {
+            return vec![];
+        };
+
+        self.slots()
+            .map(|(attribute, _)| attribute)
+            .filter(|attribute| !attributes.iter().any(|a| a == attribute))
+            .collect()
+    }
+
+    /// Takes the name and the attribute names of the linked class, or `None`
+    /// if the instance is not linked to a class of the document.
+    pub fn attach(&mut self, class: Option<(String, Vec<String>)>) {
+        self.class_info = class;
+        self.refresh_title();
+        self.check_slots();
+    }
+
+    fn refresh_title(&mut self) {
+        let text = match self.classifier() {
+            Some(classifier) => format!("{} : {classifier}", self.name),
+            None => self.name.clone(),
+        };
+        self.title.set_text(text.trim().to_string());
+    }
+
+    /// Colors the slots for attributes the class does not have.
+    fn check_slots(&mut self) {
+        let unknown = self
+            .unknown_slots()
+            .into_iter()
+            .map(String::from)
+            .collect::<Vec<_>>();
+
+        for slot in &mut self.slots {
+            let attribute = split_slot(slot.text()).0;
+            let color = if unknown.iter().any(|u| u == attribute) {
+                ERROR_COLOR
+            } else {
+                TEXT_COLOR
+            };
+            slot.set_color(color);
+        }
+    }
+
+    pub fn x(&self) -> f64 {
+        self.x
+    }
+
+    pub fn y(&self) -> f64 {
+        self.y
+    }
+
+    pub fn width(&self) -> f64 {
+        let text = std::iter::once(&self.title)
+            .chain(&self.slots)
+            .map(|l| l.width().unwrap_or(0.0))
+            .fold(0.0, f64::max);
+        f64::max(text + 2.0 * MARGIN, MIN_WIDTH)
+    }
+
+    pub fn height(&self) -> f64 {
+        let mut height = self.title.height().unwrap_or(0.0) + 2.0 * MARGIN;
+
+        if !self.slots.is_empty() {
+            height += MARGIN;
+        }
+
+        for slot in &self.slots {
+            height += slot.height().unwrap_or(0.0) + SPACING;
+        }
+
+        f64::max(height, MIN_HEIGHT)
+    }
+
+    /// The index of the slot at height `y`, or the number of slots below
+    /// the last one. `None` on the title.
+    fn slot_at(&self, y: f64) -> Option<usize> {
+        if y <= self.title.y() + MARGIN {
+            return None;
+        }
+
+        Some(
+            self.slots
+                .iter()
+                .position(|s| y <= s.y() + (SPACING / 2.0))
+                .unwrap_or(self.slots.len()),
+        )
+    }
+}
+
+fn title() -> Label {
+    Label::new(
+        0.0,
+        0.0,
+        "",
+        TextProperties::default().weight(700),
+        TEXT_COLOR,
+    )
+}
+
+fn slot(text: String) -> Label {
+    Label::new(0.0, 0.0, text, TextProperties::default(), TEXT_COLOR)
+}
+
+fn split_slot(text: &str) -> (&str, &str) {
+    match text.split_once('=') {
+        Some((attribute, value)) => (attribute.trim(), value.trim()),
+        None => (text.trim(), ""),
+    }
+}
+
+impl Drawable for InstanceSpecification {
+    fn initalize(&mut self, canvas: &impl Canvas) {
+        self.refresh_title();
+        self.title.initalize(canvas);
+
+        let width = self.width();
+        let offset_x = (width - self.title.width().unwrap_or(0.0)) / 2.0;
+        self.title.set_position(self.x + offset_x, self.y + MARGIN);
+
+        let mut offset_y = self.title.y() + (2.0 * MARGIN);
+
+        for slot in &mut self.slots {
+            slot.initalize(canvas);
+            slot.set_position(self.x + MARGIN, offset_y);
+            offset_y = slot.y() + SPACING;
+        }
+    }
+
+    fn draw(&self, canvas: &impl Canvas, camera: &Camera) {
+        let stroke = if self.is_highlighted() {
+            HIGHLIGHT_STROKE
+        } else {
+            DEFAULT_STROKE
+        };
+
+        let (l, t, r, b) = self.bounds().edges();
+
+        Rectangle::new(l, t, r - l, b - t, DEFAULT_COLOR, None, Some(stroke))
+            .draw(canvas, camera);
+
+        // Instances are told apart from classes by their underlined title.
+        let underline = self.title.y() + UNDERLINE;
+        let start = self.title.x();
+        let end = start + self.title.width().unwrap_or(0.0);
+        Path::line(
+            (start, underline),
+            (end, underline),
+            Stroke::new(1, TEXT_COLOR),
+        )
+        .draw(canvas, camera);
+
+        if !self.slots.is_empty() {
+            let y = self.title.y() + MARGIN;
+            Path::line((l, y), (r, y), stroke).draw(canvas, camera);
+        }
+
+        self.title.draw(canvas, camera);
+
+        for slot in &self.slots {
+            slot.draw(canvas, camera);
+        }
+    }
+
+    fn bounds(&self) -> Rect {
+        Rect::new(self.x, self.y, self.width(), self.height())
+    }
+}
+
+impl Interactive for InstanceSpecification {
+    fn get_interaction(&self) -> InteractionState {
+        self.interaction_state
+    }
+
+    fn get_interaction_mut(&mut self) -> &mut InteractionState {
+        &mut self.interaction_state
+    }
+
+    fn adjust_position(&mut self, delta_x: f64, delta_y: f64) {
+        self.x += delta_x;
+        self.y += delta_y;
+
+        for label in std::iter::once(&mut self.title).chain(&mut self.slots) {
+            label.adjust_position(delta_x, delta_y);
+        }
+    }
+
+    /// Edits the name when clicking the title, or the clicked slot.
+    /// Clicking below the last slot adds one.
+    #[allow(unused_variables)]
+    fn click(&mut self, x: f64, y: f64) -> Option<Prompt> {
+        let Some(index) = self.slot_at(self.y + y) else {
+            return Some(Prompt::Text {
+                explanation: "Provide this instance with a new name".into(),
+                placeholder: "Instance name".into(),
+                value: self.name.clone(),
+                metadata: Rc::new(()),
+            });
+        };
+
+        Some(Prompt::Text {
+            explanation: "Provide the value of a slot".into(),
+            placeholder: "attribute = value".into(),
+            value: self
+                .slots
+                .get(index)
+                .map(|s| s.text().to_string())
+                .unwrap_or_default(),
+            metadata: Rc::new(index),
+        })
+    }
+
+    fn prompt(&mut self, response: PromptResponse) {
+        let PromptResponse::Text { response, metadata } = response;
+
+        let Some(index) = metadata.downcast_ref::<usize>().copied() else {
+            self.set_name(response);
+            return;
+        };
+
+        match (self.slots.get_mut(index), response.is_empty()) {
+            (Some(slot), false) => slot.set_text(response),
+            (Some(_), true) => {
+                self.slots.remove(index);
+            }
+            (None, false) => self.slots.push(slot(response)),
+            (None, true) => (),
+        }
+
+        self.check_slots();
+    }
+}
@@ -0,0 +1,251 @@
+use std::{f64::consts::FRAC_PI_2, rc::Rc};
+
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    camera::Camera,
+    canvas::Canvas,
+    color::{Color, WHITE},
+    drawable::Drawable,
+    geometry::Rect,
+    id::Id,
+    interaction::{InteractionState, Interactive},
+    prompt::{Prompt, PromptResponse},
+    stroke::Stroke,
+};
+
+use super::{Label, Outline, Path, TextProperties};
+
+const DEFAULT_STROKE: Stroke = const {
+    Stroke::new(
+        2,
+        Color::Rgb {
+            red: 90,
+            green: 90,
+            blue: 90,
+        },
+    )
+};
+
+const HIGHLIGHT_STROKE: Stroke = const {
+    Stroke::new(
+        2,
+        Color::Rgb {
+            red: 142,
+            green: 202,
+            blue: 230,
+        },
+    )
+};
+
+const TEXT_COLOR: Color = const {
+    Color::Rgb {
+        red: 31,
+        green: 31,
+        blue: 31,
+    }
+};
+
+const SPACING: f64 = 4.0;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum InterfaceKind {
+    /// A ball (lollipop): the owner implements the interface.
+    Provided,
+    /// A socket: the owner depends on the interface.
+    Required,
+}
+
+impl InterfaceKind {
+    fn radius(&self) -> f64 {
+        match self {
+            InterfaceKind::Provided => 10.0,
+            InterfaceKind::Required => 14.0,
+        }
+    }
+}
+
+/// An interface provided or required by a component or port, drawn as a
+/// ball or a socket at the end of a line from its owner, with the name of
+/// the interface below. The socket opens away from the owner, so the ball
+/// of a matching provided interface fits into it.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Interface {
+    owner: Id,
+    x: f64,
+    y: f64,
+    kind: InterfaceKind,
+    title: Label,
+
+    /// Where the line from the owner starts.
+    #[serde(skip)]
+    stem: Option<(f64, f64)>,
+    #[serde(skip)]
+    interaction_state: InteractionState,
+}
+
+impl Interface {
+    pub fn new(
+        owner: Id,
+        x: f64,
+        y: f64,
+        kind: InterfaceKind,
+        name: String,
+    ) -> Self {
+        Self {
+            owner,
+            x,
+            y,
+            kind,
+            title: Label::new(
+                0.0,
+                0.0,
+                name,
+                TextProperties::default(),
+                TEXT_COLOR,
+            ),
+            stem: None,
+            interaction_state: InteractionState::default(),
+        }
+    }
+
+    /// The component or port providing or requiring the interface.
+    pub fn owner(&self) -> Id {
+        self.owner
+    }
+
+    pub fn kind(&self) -> InterfaceKind {
+        self.kind
+    }
+
+    pub fn name(&self) -> &str {
+        self.title.text()
+    }
+
+    pub fn set_name(&mut self, value: String) {
+        self.title.set_text(value);
+    }
+
+    pub fn x(&self) -> f64 {
+        self.x
+    }
+
+    pub fn y(&self) -> f64 {
+        self.y
+    }
+
+    pub fn center(&self) -> (f64, f64) {
+        let radius = self.kind.radius();
+        (self.x + radius, self.y + radius)
+    }
+
+    /// Where the line from the owner starts, once attached.
+    pub fn stem(&self) -> Option<(f64, f64)> {
+        self.stem
+    }
+
+    /// Starts the line to the ball or socket on the outline of the owner.
+    pub fn attach(&mut self, owner: Outline) {
+        self.stem = Some(owner.clip(self.center()));
+    }
+}
+
+impl Drawable for Interface {
+    fn initalize(&mut self, canvas: &impl Canvas) {
+        self.title.initalize(canvas);
+
+        let width = self.title.width().unwrap_or(0.0);
+        let (l, _, r, b) = self.bounds().edges();
+        self.title.set_position((l + r - width) / 2.0, b + SPACING);
+    }
+
+    fn draw(&self, canvas: &impl Canvas, camera: &Camera) {
+        let stroke = if self.is_highlighted() {
+            HIGHLIGHT_STROKE
+        } else {
+            DEFAULT_STROKE
+        };
+
+        let center = self.center();
+        let radius = self.kind.radius();
+
+        // The direction from the owner towards the symbol.
+        let angle = match self.stem {
+            Some(stem) => f64::atan2(center.1 - stem.1, center.0 - stem.0),
+            None => 0.0,
+        };
+        let back = (
+            center.0 - radius * angle.cos(),
+            center.1 - radius * angle.sin(),
+        );
+
+        if let Some(stem) = self.stem {
+            Path::line(stem, back, stroke).draw(canvas, camera);
+        }
+
+        match self.kind {
+            InterfaceKind::Provided => {
+                Path::ellipse(self.bounds(), Some(stroke))
+                    .closed(Some(WHITE))
+                    .draw(canvas, camera);
+            }
+            InterfaceKind::Required => {
+                Path::arc(
+                    center,
+                    radius,
+                    angle + FRAC_PI_2,
+                    angle + 3.0 * FRAC_PI_2,
+                    stroke,
+                )
+                .draw(canvas, camera);
+            }
+        }
+
+        self.title.draw(canvas, camera);
+    }
+
+    /// The bounds of the ball or socket.
+    fn bounds(&self) -> Rect {
+        let size = 2.0 * self.kind.radius();
+        Rect::new(self.x, self.y, size, size)
+    }
+}
+
+impl Interactive for Interface {
+    fn get_interaction(&self) -> InteractionState {
+        self.interaction_state
+    }
+
+    fn get_interaction_mut(&mut self) -> &mut InteractionState {
+        &mut self.interaction_state
+    }
+
+    fn adjust_position(&mut self, delta_x: f64, delta_y: f64) {
+        self.x += delta_x;
+        self.y += delta_y;
+        self.title.adjust_position(delta_x, delta_y);
+    }
+
+    #[allow(unused_variables)]
+    fn click(&mut self, x: f64, y: f64) -> Option<Prompt> {
+        Some(Prompt::Text {
+            explanation: "Provide this interface with a new name".into(),
+            placeholder: "Interface name".into(),
+            value: self.title.text().to_string(),
+            metadata: Rc::new(()),
+        })
+    }
+
+    fn prompt(&mut self, response: PromptResponse) {
+        let PromptResponse::Text {
+            response,
+            metadata: _,
+        } = response;
+
+        if response.is_empty() {
+            return;
+        }
+
+        self.title.set_text(response);
+    }
+}
@@ -5,6 +5,7 @@ use crate::{
     canvas::Canvas,
     color::Color,
     drawable::Drawable,
+    geometry::Rect,
     interaction::{InteractionState, Interactive},
 };
 
@@ -12,10 +13,10 @@ use super::TextProperties;
 
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct Label {
-    x: i32,
-    y: i32,
-    width: Option<u32>,
-    height: Option<u32>,
+    x: f64,
+    y: f64,
+    width: Option<f64>,
+    height: Option<f64>,
     text: String,
     properties: TextProperties,
     color: Color,
@@ -25,8 +26,8 @@ pub struct Label {
 
 impl Label {
     pub fn new(
-        x: i32,
-        y: i32,
+        x: f64,
+        y: f64,
         text: impl Into<String>,
         props: TextProperties,
         color: Color,
@@ -43,19 +44,19 @@ impl Label {
         }
     }
 
-    pub fn x(&self) -> i32 {
+    pub fn x(&self) -> f64 {
         self.x
     }
 
-    pub fn y(&self) -> i32 {
-        self.y + self.height().unwrap_or(0) as i32
+    pub fn y(&self) -> f64 {
+        self.y + self.height().unwrap_or(0.0)
     }
 
-    pub fn width(&self) -> Option<u32> {
+    pub fn width(&self) -> Option<f64> {
         self.width
     }
 
-    pub fn height(&self) -> Option<u32> {
+    pub fn height(&self) -> Option<f64> {
         self.height
     }
 
@@ -74,6 +75,10 @@ impl Label {
     pub fn set_text(&mut self, value: String) {
         self.text = value;
     }
+
+    pub fn set_color(&mut self, value: Color) {
+        self.color = value;
+    }
 }
 
 impl Drawable for Label {
@@ -84,13 +89,23 @@ impl Drawable for Label {
             return;
         };
 
-        self.width = Some(size.width() as u32);
-        self.height = Some(size.height() as u32);
+        self.width = Some(size.width() as f64);
+        self.height = Some(size.height() as f64);
     }
 
     fn draw(&self, canvas: &impl Canvas, camera: &Camera) {
         canvas.draw_text(self, camera);
     }
+
+    /// The text, from its top at the stored `y` down to its baseline.
+    fn bounds(&self) -> Rect {
+        Rect::new(
+            self.x,
+            self.y,
+            self.width.unwrap_or(0.0),
+            self.height.unwrap_or(0.0),
+        )
+    }
 }
 
 impl Interactive for Label {
@@ -102,12 +117,12 @@ impl Interactive for Label {
         &mut self.interaction_state
     }
 
-    fn adjust_position(&mut self, delta_x: i32, delta_y: i32) {
+    fn adjust_position(&mut self, delta_x: f64, delta_y: f64) {
         self.x += delta_x;
         self.y += delta_y;
     }
 
-    fn set_position(&mut self, x: i32, y: i32) {
+    fn set_position(&mut self, x: f64, y: f64) {
         self.x = x;
         self.y = y;
     }
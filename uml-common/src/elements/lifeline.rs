@@ -0,0 +1,305 @@
+use std::rc::Rc;
+
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    camera::Camera,
+    canvas::Canvas,
+    color::Color,
+    drawable::Drawable,
+    geometry::{Point, Rect},
+    interaction::{InteractionState, Interactive},
+    prompt::{Prompt, PromptResponse},
+    stroke::Stroke,
+};
+
+use super::{Label, Path, Rectangle, TextProperties};
+
+const DEFAULT_COLOR: Color = const {
+    Color::Rgb {
+        red: 244,
+        green: 244,
+        blue: 244,
+    }
+};
+
+const DEFAULT_STROKE: Stroke = const {
+    Stroke::new(
+        2,
+        Color::Rgb {
+            red: 90,
+            green: 90,
+            blue: 90,
+        },
+    )
+};
+
+const HIGHLIGHT_STROKE: Stroke = const {
+    Stroke::new(
+        2,
+        Color::Rgb {
+            red: 142,
+            green: 202,
+            blue: 230,
+        },
+    )
+};
+
+const TEXT_COLOR: Color = const {
+    Color::Rgb {
+        red: 31,
+        green: 31,
+        blue: 31,
+    }
+};
+
+const MARGIN: f64 = 12.0;
+const MIN_WIDTH: f64 = 100.0;
+const DEFAULT_LENGTH: f64 = 300.0;
+/// Space left on the line below the last message or activation.
+const TAIL: f64 = 30.0;
+const CROSS_SIZE: f64 = 10.0;
+const HIT_DISTANCE: f64 = 6.0;
+
+/// A participant of a sequence diagram: a header box naming it (e.g.
+/// `shop: Shop`) above a dashed line on which messages arrive and leave.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Lifeline {
+    x: f64,
+    y: f64,
+    title: Label,
+    length: f64,
+
+    /// Height of the create message, which the header is centered on.
+    #[serde(skip)]
+    created_at: Option<f64>,
+    /// Height of the destroy message, where the line ends.
+    #[serde(skip)]
+    destroyed_at: Option<f64>,
+    /// Lowest message or activation on the lifeline.
+    #[serde(skip)]
+    last_event: Option<f64>,
+    #[serde(skip)]
+    interaction_state: InteractionState,
+}
+
+impl Lifeline {
+    pub fn new(x: f64, y: f64, name: String) -> Self {
+        Self {
+            x,
+            y,
+            title: Label::new(
+                0.0,
+                0.0,
+                name,
+                TextProperties::default(),
+                TEXT_COLOR,
+            ),
+            length: DEFAULT_LENGTH,
+            created_at: None,
+            destroyed_at: None,
+            last_event: None,
+            interaction_state: InteractionState::default(),
+        }
+    }
+
+    pub fn name(&self) -> &str {
+        self.title.text()
+    }
+
+    pub fn set_name(&mut self, value: String) {
+        self.title.set_text(value);
+    }
+
+    pub fn x(&self) -> f64 {
+        self.x
+    }
+
+    /// The top of the header. Lifelines created by a message start at that
+    /// message rather than at their own position.
+    pub fn y(&self) -> f64 {
+        match self.created_at {
+            Some(y) => y - self.height() / 2.0,
+            None => self.y,
+        }
+    }
+
+    pub fn width(&self) -> f64 {
+        let width = self.title.width().unwrap_or(0.0) + 2.0 * MARGIN;
+        f64::max(width, MIN_WIDTH)
+    }
+
+    /// The height of the header.
+    pub fn height(&self) -> f64 {
+        self.title.height().unwrap_or(0.0) + 2.0 * MARGIN
+    }
+
+    /// The length of the line below the header, unless messages further down
+    /// make it longer.
+    pub fn length(&self) -> f64 {
+        self.length
+    }
+
+    pub fn set_length(&mut self, value: f64) {
+        self.length = value;
+    }
+
+    /// The horizontal position of the line.
+    pub fn center(&self) -> f64 {
+        self.x + self.width() / 2.0
+    }
+
+    pub fn header_bounds(&self) -> Rect {
+        Rect::new(self.x, self.y(), self.width(), self.height())
+    }
+
+    /// The end of the line: the destroy message if there is one, otherwise
+    /// far enough down to cover every message.
+    pub fn bottom(&self) -> f64 {
+        if let Some(y) = self.destroyed_at {
+            return y;
+        }
+
+        let bottom = self.y() + self.height() + self.length;
+        self.last_event
+            .map(|y| f64::max(bottom, y + TAIL))
+            .unwrap_or(bottom)
+    }
+
+    pub fn is_destroyed(&self) -> bool {
+        self.destroyed_at.is_some()
+    }
+
+    /// Fits the lifeline to the messages on it: `created_at` and
+    /// `destroyed_at` are the heights of its create and destroy messages, and
+    /// `last_event` is the lowest message or activation.
+    pub fn attach(
+        &mut self,
+        created_at: Option<f64>,
+        destroyed_at: Option<f64>,
+        last_event: Option<f64>,
+    ) {
+        self.created_at = created_at;
+        self.destroyed_at = destroyed_at;
+        self.last_event = last_event;
+        self.place_title();
+    }
+
+    /// Centers the title in the header.
+    fn place_title(&mut self) {
+        let (l, t, _, _) = self.header_bounds().edges();
+        let offset = (self.width() - self.title.width().unwrap_or(0.0)) / 2.0;
+        self.title.set_position(l + offset, t + MARGIN);
+    }
+
+    pub fn cursor_intersects(&self, x: f64, y: f64) -> bool {
+        let header = self.header_bounds();
+        let cursor = Point::new(x, y);
+
+        if header.contains(cursor) {
+            return true;
+        }
+
+        let center = self.center();
+        cursor.distance_to_segment(
+            Point::new(center, header.bottom()),
+            Point::new(center, self.bottom()),
+        ) <= HIT_DISTANCE
+    }
+}
+
+impl Drawable for Lifeline {
+    fn initalize(&mut self, canvas: &impl Canvas) {
+        self.title.initalize(canvas);
+        self.place_title();
+    }
+
+    fn draw(&self, canvas: &impl Canvas, camera: &Camera) {
+        let stroke = if self.is_highlighted() {
+            HIGHLIGHT_STROKE
+        } else {
+            DEFAULT_STROKE
+        };
+
+        let (l, t, _, b) = self.header_bounds().edges();
+        let (center, bottom) = (self.center(), self.bottom());
+
+        Path::line((center, b), (center, bottom), stroke)
+            .dashed(true)
+            .draw(canvas, camera);
+
+        if self.is_destroyed() {
+            for (dx, dy) in [(1.0, 1.0), (1.0, -1.0)] {
+                Path::line(
+                    (center - dx * CROSS_SIZE, bottom - dy * CROSS_SIZE),
+                    (center + dx * CROSS_SIZE, bottom + dy * CROSS_SIZE),
+                    stroke,
+                )
+                .draw(canvas, camera);
+            }
+        }
+
+        Rectangle::new(
+            l,
+            t,
+            self.width(),
+            self.height(),
+            DEFAULT_COLOR,
+            Some(2.0),
+            Some(stroke),
+        )
+        .draw(canvas, camera);
+
+        self.title.draw(canvas, camera);
+    }
+
+    /// The header and the line below it.
+    fn bounds(&self) -> Rect {
+        let header = self.header_bounds();
+        Rect::from_edges(
+            header.left(),
+            header.top(),
+            header.right(),
+            self.bottom(),
+        )
+    }
+}
+
+impl Interactive for Lifeline {
+    fn get_interaction(&self) -> InteractionState {
+        self.interaction_state
+    }
+
+    fn get_interaction_mut(&mut self) -> &mut InteractionState {
+        &mut self.interaction_state
+    }
+
+    fn adjust_position(&mut self, delta_x: f64, delta_y: f64) {
+        self.x += delta_x;
+        self.y += delta_y;
+        self.title.adjust_position(delta_x, delta_y);
+    }
+
+    #[allow(unused_variables)]
+    fn click(&mut self, x: f64, y: f64) -> Option<Prompt> {
+        Some(Prompt::Text {
+            explanation: "Provide this lifeline with a new name".into(),
+            placeholder: "name: Class".into(),
+            value: self.title.text().to_string(),
+            metadata: Rc::new(()),
+        })
+    }
+
+    fn prompt(&mut self, response: PromptResponse) {
+        let PromptResponse::Text {
+            response,
+            metadata: _,
+        } = response;
+
+        if response.is_empty() {
+            return;
+        }
+
+        self.title.set_text(response);
+    }
+}
@@ -0,0 +1,286 @@
+use std::rc::Rc;
+
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    camera::Camera,
+    canvas::Canvas,
+    color::Color,
+    drawable::Drawable,
+    geometry::{Point, Rect},
+    id::Id,
+    interaction::{InteractionState, Interactive},
+    prompt::{Prompt, PromptResponse},
+    stroke::Stroke,
+};
+
+use super::{Label, Path, TextProperties};
+
+const DEFAULT_STROKE: Stroke = const {
+    Stroke::new(
+        2,
+        Color::Rgb {
+            red: 90,
+            green: 90,
+            blue: 90,
+        },
+    )
+};
+
+const HIGHLIGHT_STROKE: Stroke = const {
+    Stroke::new(
+        2,
+        Color::Rgb {
+            red: 142,
+            green: 202,
+            blue: 230,
+        },
+    )
+};
+
+const TEXT_COLOR: Color = const {
+    Color::Rgb {
+        red: 31,
+        green: 31,
+        blue: 31,
+    }
+};
+
+const HEAD_LENGTH: f64 = 12.0;
+const HEAD_WIDTH: f64 = 6.0;
+const LOOP_WIDTH: f64 = 40.0;
+const LOOP_HEIGHT: f64 = 20.0;
+const HIT_DISTANCE: f64 = 6.0;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum MessageKind {
+    /// Solid line with a filled arrowhead; the sender waits for the reply.
+    Synchronous,
+    /// Solid line with an open arrowhead.
+    Asynchronous,
+    /// Dashed line with an open arrowhead back to the caller.
+    Return,
+    /// Dashed line with an open arrowhead to the header of the lifeline it
+    /// creates.
+    Create,
+    /// Solid line with a filled arrowhead ending the target lifeline.
+    Destroy,
+}
+
+impl MessageKind {
+    pub fn is_dashed(&self) -> bool {
+        matches!(self, MessageKind::Return | MessageKind::Create)
+    }
+
+    /// The stereotype shown for messages without a label of their own.
+    fn default_label(&self) -> Option<&'static str> {
+        match self {
+            MessageKind::Create => Some("«create»"),
+            MessageKind::Destroy => Some("«destroy»"),
+            _ => None,
+        }
+    }
+}
+
+/// A horizontal arrow between two lifelines of a sequence diagram. The
+/// height of a message is its own, so messages keep their order when the
+/// lifelines are moved; only the ends follow the lifelines sideways.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Message {
+    source: Id,
+    target: Id,
+    kind: MessageKind,
+    label: Option<String>,
+    y: f64,
+
+    #[serde(skip)]
+    points: Vec<(f64, f64)>,
+    #[serde(skip)]
+    interaction_state: InteractionState,
+}
+
+impl Message {
+    pub fn new(source: Id, target: Id, kind: MessageKind, y: f64) -> Self {
+        Self {
+            source,
+            target,
+            kind,
+            label: None,
+            y,
+            points: vec![],
+            interaction_state: InteractionState::default(),
+        }
+    }
+
+    pub fn source(&self) -> Id {
+        self.source
+    }
+
+    pub fn target(&self) -> Id {
+        self.target
+    }
+
+    pub fn kind(&self) -> MessageKind {
+        self.kind
+    }
+
+    pub fn label(&self) -> Option<&str> {
+        self.label.as_deref()
+    }
+
+    pub fn set_label(&mut self, value: Option<String>) {
+        self.label = value;
+    }
+
+    pub fn x(&self) -> f64 {
+        self.points.first().map(|p| p.0).unwrap_or(0.0)
+    }
+
+    pub fn y(&self) -> f64 {
+        self.y
+    }
+
+    /// The lowest point of the message; below `y` for messages to self.
+    pub fn bottom(&self) -> f64 {
+        match self.source == self.target {
+            true => self.y + LOOP_HEIGHT,
+            false => self.y,
+        }
+    }
+
+    /// The line of the message, from the source to the target.
+    pub fn points(&self) -> &[(f64, f64)] {
+        &self.points
+    }
+
+    /// Routes the message from the horizontal position `from` on the source
+    /// lifeline to `to` on the target lifeline. Messages to self loop out to
+    /// the right of `from` and come back below it.
+    pub fn attach(&mut self, from: f64, to: f64) {
+        let y = self.y;
+
+        if self.source == self.target {
+            self.points = vec![
+                (from, y),
+                (from + LOOP_WIDTH, y),
+                (from + LOOP_WIDTH, y + LOOP_HEIGHT),
+                (to, y + LOOP_HEIGHT),
+            ];
+            return;
+        }
+
+        self.points = vec![(from, y), (to, y)];
+    }
+
+    pub fn cursor_intersects(&self, x: f64, y: f64) -> bool {
+        self.points
+            .windows(2)
+            .map(|w| {
+                Point::new(x, y).distance_to_segment(w[0].into(), w[1].into())
+            })
+            .any(|distance| distance <= HIT_DISTANCE)
+    }
+
+    fn draw_head(&self, canvas: &impl Canvas, camera: &Camera, stroke: Stroke) {
+        let [.., from, tip] = self.points[..] else {
+            return;
+        };
+
+        // The last segment is always horizontal.
+        let back = if from.0 <= tip.0 {
+            tip.0 - HEAD_LENGTH
+        } else {
+            tip.0 + HEAD_LENGTH
+        };
+        let points =
+            vec![(back, tip.1 - HEAD_WIDTH), tip, (back, tip.1 + HEAD_WIDTH)];
+
+        let path = match self.kind {
+            MessageKind::Synchronous | MessageKind::Destroy => {
+                Path::new(points, Some(stroke)).closed(Some(stroke.color()))
+            }
+            MessageKind::Asynchronous
+            | MessageKind::Return
+            | MessageKind::Create => Path::new(points, Some(stroke)),
+        };
+
+        path.draw(canvas, camera);
+    }
+}
+
+impl Drawable for Message {
+    fn draw(&self, canvas: &impl Canvas, camera: &Camera) {
+        if self.points.len() < 2 {
+            return;
+        }
+
+        let stroke = if self.is_highlighted() {
+            HIGHLIGHT_STROKE
+        } else {
+            DEFAULT_STROKE
+        };
+
+        Path::new(self.points.clone(), Some(stroke))
+            .dashed(self.kind.is_dashed())
+            .draw(canvas, camera);
+
+        self.draw_head(canvas, camera, stroke);
+
+        let text = self.label.as_deref().or(self.kind.default_label());
+
+        if let Some(text) = text {
+            let left = self
+                .points
+                .iter()
+                .map(|p| p.0)
+                .reduce(f64::min)
+                .unwrap_or(0.0);
+            let props = TextProperties::new(16.0, "Arial,sans-serif");
+            Label::new(left + 8.0, self.y - 6.0, text, props, TEXT_COLOR)
+                .draw(canvas, camera);
+        }
+    }
+
+    fn bounds(&self) -> Rect {
+        Rect::from_points(self.points.iter().copied()).unwrap_or_default()
+    }
+}
+
+impl Interactive for Message {
+    fn get_interaction(&self) -> InteractionState {
+        self.interaction_state
+    }
+
+    fn get_interaction_mut(&mut self) -> &mut InteractionState {
+        &mut self.interaction_state
+    }
+
+    /// Messages can only be moved up and down; their ends stay on the
+    /// lifelines.
+    fn adjust_position(&mut self, _: f64, delta_y: f64) {
+        self.y += delta_y;
+
+        for point in &mut self.points {
+            point.1 += delta_y;
+        }
+    }
+
+    #[allow(unused_variables)]
+    fn click(&mut self, x: f64, y: f64) -> Option<Prompt> {
+        Some(Prompt::Text {
+            explanation: "Provide this message with a new label".into(),
+            placeholder: "operation(arguments)".into(),
+            value: self.label.clone().unwrap_or_default(),
+            metadata: Rc::new(()),
+        })
+    }
+
+    fn prompt(&mut self, response: PromptResponse) {
+        let PromptResponse::Text {
+            response,
+            metadata: _,
+        } = response;
+
+        self.label = (!response.is_empty()).then_some(response);
+    }
+}
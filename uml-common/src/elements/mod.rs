@@ -1,13 +1,65 @@
+mod action;
+mod activation;
+mod actor;
+mod artifact;
+mod c4_boundary;
+mod c4_element;
 mod class;
+mod component;
+mod control_node;
+mod deployment_node;
 mod element;
+mod entity;
+mod fragment;
 mod info;
+mod instance_specification;
+mod interface;
 mod label;
+mod lifeline;
+mod message;
+mod note;
+mod object_node;
+mod package;
+mod path;
+mod port;
+mod pseudostate;
 mod rectangle;
+mod relationship;
+mod state;
+mod swimlane;
+mod system_boundary;
 mod text;
+mod use_case;
 
+pub use action::*;
+pub use activation::*;
+pub use actor::*;
+pub use artifact::*;
+pub use c4_boundary::*;
+pub use c4_element::*;
 pub use class::*;
+pub use component::*;
+pub use control_node::*;
+pub use deployment_node::*;
 pub use element::*;
+pub use entity::*;
+pub use fragment::*;
 pub use info::*;
+pub use instance_specification::*;
+pub use interface::*;
 pub use label::*;
+pub use lifeline::*;
+pub use message::*;
+pub use note::*;
+pub use object_node::*;
+pub use package::*;
+pub use path::*;
+pub use port::*;
+pub use pseudostate::*;
 pub use rectangle::*;
+pub use relationship::*;
+pub use state::*;
+pub use swimlane::*;
+pub use system_boundary::*;
 pub use text::*;
+pub use use_case::*;
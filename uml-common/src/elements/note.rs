@@ -0,0 +1,285 @@
+use std::rc::Rc;
+
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    camera::Camera,
+    canvas::Canvas,
+    color::Color,
+    drawable::Drawable,
+    geometry::Rect,
+    id::Id,
+    interaction::{InteractionState, Interactive},
+    prompt::{Prompt, PromptResponse},
+    stroke::Stroke,
+};
+
+use super::{Label, Outline, Path, TextProperties};
+
+const DEFAULT_COLOR: Color = const {
+    Color::Rgb {
+        red: 255,
+        green: 249,
+        blue: 196,
+    }
+};
+
+const DEFAULT_STROKE: Stroke = const {
+    Stroke::new(
+        2,
+        Color::Rgb {
+            red: 90,
+            green: 90,
+            blue: 90,
+        },
+    )
+};
+
+const HIGHLIGHT_STROKE: Stroke = const {
+    Stroke::new(
+        2,
+        Color::Rgb {
+            red: 142,
+            green: 202,
+            blue: 230,
+        },
+    )
+};
+
+const TEXT_COLOR: Color = const {
+    Color::Rgb {
+        red: 31,
+        green: 31,
+        blue: 31,
+    }
+};
+
+const MARGIN: f64 = 10.0;
+const SPACING: f64 = 4.0;
+/// Size of the folded corner.
+const FOLD: f64 = 12.0;
+const MIN_WIDTH: f64 = 80.0;
+const MIN_HEIGHT: f64 = 40.0;
+
+/// A straight line between two points.
+type Segment = ((f64, f64), (f64, f64));
+
+/// A comment, drawn as a sheet with a folded corner holding one or more lines
+/// of text. Dashed anchor lines connect it to the elements it annotates and
+/// follow them when either side moves.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Note {
+    x: f64,
+    y: f64,
+    lines: Vec<Label>,
+    #[serde(default)]
+    anchors: Vec<Id>,
+
+    /// The anchor lines, from the note to each annotated element.
+    #[serde(skip)]
+    anchor_lines: Vec<Segment>,
+    #[serde(skip)]
+    interaction_state: InteractionState,
+}
+
+impl Note {
+    pub fn new(x: f64, y: f64, text: &str) -> Self {
+        let mut note = Self {
+            x,
+            y,
+            lines: vec![],
+            anchors: vec![],
+            anchor_lines: vec![],
+            interaction_state: InteractionState::default(),
+        };
+        note.set_text(text);
+        note
+    }
+
+    /// The text of the note, with its lines separated by `\n`.
+    pub fn text(&self) -> String {
+        self.lines
+            .iter()
+            .map(Label::text)
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+
+    pub fn set_text(&mut self, value: &str) {
+        self.lines = value.lines().map(line).collect();
+    }
+
+    /// The elements the note is anchored to.
+    pub fn anchors(&self) -> &[Id] {
+        &self.anchors
+    }
+
+    pub fn add_anchor(&mut self, id: Id) {
+        if !self.anchors.contains(&id) {
+            self.anchors.push(id);
+        }
+    }
+
+    pub fn remove_anchor(&mut self, id: Id) {
+        self.anchors.retain(|a| *a != id);
+    }
+
+    /// The anchor lines, once attached.
+    pub fn anchor_lines(&self) -> &[Segment] {
+        &self.anchor_lines
+    }
+
+    pub fn x(&self) -> f64 {
+        self.x
+    }
+
+    pub fn y(&self) -> f64 {
+        self.y
+    }
+
+    pub fn width(&self) -> f64 {
+        let text = self
+            .lines
+            .iter()
+            .map(|l| l.width().unwrap_or(0.0))
+            .fold(0.0, f64::max);
+        f64::max(text + 2.0 * MARGIN + FOLD, MIN_WIDTH)
+    }
+
+    pub fn height(&self) -> f64 {
+        let text = self
+            .lines
+            .iter()
+            .map(|l| l.height().unwrap_or(0.0) + SPACING)
+            .sum::<f64>();
+        f64::max(text + 2.0 * MARGIN, MIN_HEIGHT)
+    }
+
+    /// Draws the anchor lines between the note and the outlines of the
+    /// annotated elements, in the order of `anchors`.
+    pub fn attach(&mut self, targets: &[Outline]) {
+        let own = Outline::Rectangle(self.bounds());
+
+        self.anchor_lines = targets
+            .iter()
+            .map(|target| {
+                let from = own.clip(target.bounds().center().into());
+                let to = target.clip(own.bounds().center().into());
+                (from, to)
+            })
+            .collect();
+    }
+
+    /// The index of the line at height `y`, or the number of lines below the
+    /// last one.
+    fn line_at(&self, y: f64) -> usize {
+        self.lines
+            .iter()
+            .position(|l| y <= l.y() + (SPACING / 2.0))
+            .unwrap_or(self.lines.len())
+    }
+}
+
+fn line(text: &str) -> Label {
+    Label::new(0.0, 0.0, text, TextProperties::default(), TEXT_COLOR)
+}
+
+impl Drawable for Note {
+    fn initalize(&mut self, canvas: &impl Canvas) {
+        let mut offset_y = self.y + MARGIN;
+
+        for label in &mut self.lines {
+            label.initalize(canvas);
+            label.set_position(self.x + MARGIN, offset_y);
+            offset_y = label.y() + SPACING;
+        }
+    }
+
+    fn draw(&self, canvas: &impl Canvas, camera: &Camera) {
+        let stroke = if self.is_highlighted() {
+            HIGHLIGHT_STROKE
+        } else {
+            DEFAULT_STROKE
+        };
+
+        for (from, to) in &self.anchor_lines {
+            Path::line(*from, *to, stroke)
+                .dashed(true)
+                .draw(canvas, camera);
+        }
+
+        let (l, t, r, b) = self.bounds().edges();
+        Path::new(
+            vec![(l, t), (r - FOLD, t), (r, t + FOLD), (r, b), (l, b)],
+            Some(stroke),
+        )
+        .closed(Some(DEFAULT_COLOR))
+        .draw(canvas, camera);
+        Path::new(
+            vec![(r - FOLD, t), (r - FOLD, t + FOLD), (r, t + FOLD)],
+            Some(stroke),
+        )
+        .draw(canvas, camera);
+
+        for label in &self.lines {
+            label.draw(canvas, camera);
+        }
+    }
+
+    fn bounds(&self) -> Rect {
+        Rect::new(self.x, self.y, self.width(), self.height())
+    }
+}
+
+impl Interactive for Note {
+    fn get_interaction(&self) -> InteractionState {
+        self.interaction_state
+    }
+
+    fn get_interaction_mut(&mut self) -> &mut InteractionState {
+        &mut self.interaction_state
+    }
+
+    fn adjust_position(&mut self, delta_x: f64, delta_y: f64) {
+        self.x += delta_x;
+        self.y += delta_y;
+
+        for label in &mut self.lines {
+            label.adjust_position(delta_x, delta_y);
+        }
+    }
+
+    /// Edits the clicked line. Clicking below the last line adds one.
+    #[allow(unused_variables)]
+    fn click(&mut self, x: f64, y: f64) -> Option<Prompt> {
+        let index = self.line_at(self.y + y);
+
+        Some(Prompt::Text {
+            explanation: "Provide this line of the note with a new text".into(),
+            placeholder: "Leave empty to remove the line".into(),
+            value: self
+                .lines
+                .get(index)
+                .map(|l| l.text().to_string())
+                .unwrap_or_default(),
+            metadata: Rc::new(index),
+        })
+    }
+
+    fn prompt(&mut self, response: PromptResponse) {
+        let PromptResponse::Text { response, metadata } = response;
+
+        let Some(index) = metadata.downcast_ref::<usize>().copied() else {
+            return;
+        };
+
+        match (self.lines.get_mut(index), response.is_empty()) {
+            (Some(label), false) => label.set_text(response),
+            (Some(_), true) => {
+                self.lines.remove(index);
+            }
+            (None, false) => self.lines.push(line(&response)),
+            (None, true) => (),
+        }
+    }
+}
@@ -0,0 +1,242 @@
+use std::rc::Rc;
+
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    camera::Camera,
+    canvas::Canvas,
+    color::Color,
+    drawable::Drawable,
+    geometry::Rect,
+    interaction::{InteractionState, Interactive},
+    prompt::{Prompt, PromptResponse},
+    stroke::Stroke,
+};
+
+use super::{Label, Rectangle, TextProperties};
+
+const DEFAULT_COLOR: Color = const {
+    Color::Rgb {
+        red: 252,
+        green: 252,
+        blue: 252,
+    }
+};
+
+const DEFAULT_STROKE: Stroke = const {
+    Stroke::new(
+        2,
+        Color::Rgb {
+            red: 90,
+            green: 90,
+            blue: 90,
+        },
+    )
+};
+
+const HIGHLIGHT_STROKE: Stroke = const {
+    Stroke::new(
+        2,
+        Color::Rgb {
+            red: 142,
+            green: 202,
+            blue: 230,
+        },
+    )
+};
+
+const TEXT_COLOR: Color = const {
+    Color::Rgb {
+        red: 31,
+        green: 31,
+        blue: 31,
+    }
+};
+
+const MARGIN: f64 = 10.0;
+const SPACING: f64 = 4.0;
+const MIN_WIDTH: f64 = 100.0;
+const MIN_HEIGHT: f64 = 40.0;
+
+/// An object passed along the flows of an activity, drawn as a rectangle
+/// with its type and, optionally, the state it is in (e.g. `[paid]`).
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ObjectNode {
+    x: f64,
+    y: f64,
+    title: Label,
+    #[serde(default)]
+    in_state: Option<Label>,
+
+    #[serde(skip)]
+    interaction_state: InteractionState,
+}
+
+impl ObjectNode {
+    pub fn new(x: f64, y: f64, name: String) -> Self {
+        Self {
+            x,
+            y,
+            title: Label::new(
+                0.0,
+                0.0,
+                name,
+                TextProperties::default(),
+                TEXT_COLOR,
+            ),
+            in_state: None,
+            interaction_state: InteractionState::default(),
+        }
+    }
+
+    pub fn name(&self) -> &str {
+        self.title.text()
+    }
+
+    pub fn set_name(&mut self, value: String) {
+        self.title.set_text(value);
+    }
+
+    /// The state the object is in, without the brackets.
+    pub fn in_state(&self) -> Option<&str> {
+        let text = self.in_state.as_ref()?.text();
+        text.strip_prefix('[')?.strip_suffix(']')
+    }
+
+    pub fn set_in_state(&mut self, value: Option<String>) {
+        self.in_state = value.map(|value| {
+            Label::new(
+                0.0,
+                0.0,
+                format!("[{value}]"),
+                TextProperties::default(),
+                TEXT_COLOR,
+            )
+        });
+    }
+
+    pub fn x(&self) -> f64 {
+        self.x
+    }
+
+    pub fn y(&self) -> f64 {
+        self.y
+    }
+
+    pub fn width(&self) -> f64 {
+        let text = self
+            .labels()
+            .map(|l| l.width().unwrap_or(0.0))
+            .fold(0.0, f64::max);
+        f64::max(text + 2.0 * MARGIN, MIN_WIDTH)
+    }
+
+    pub fn height(&self) -> f64 {
+        let text = self.text_height();
+        f64::max(text + 2.0 * MARGIN, MIN_HEIGHT)
+    }
+
+    fn text_height(&self) -> f64 {
+        let mut height = self.title.height().unwrap_or(0.0);
+
+        if let Some(label) = &self.in_state {
+            height += SPACING + label.height().unwrap_or(0.0);
+        }
+
+        height
+    }
+
+    fn labels(&self) -> impl Iterator<Item = &Label> {
+        std::iter::once(&self.title).chain(&self.in_state)
+    }
+}
+
+impl Drawable for ObjectNode {
+    fn initalize(&mut self, canvas: &impl Canvas) {
+        self.title.initalize(canvas);
+
+        if let Some(label) = &mut self.in_state {
+            label.initalize(canvas);
+        }
+
+        let (width, height) = (self.width(), self.height());
+        let mut offset_y = self.y + ((height - self.text_height()) / 2.0);
+
+        for label in std::iter::once(&mut self.title).chain(&mut self.in_state)
+        {
+            let offset_x = (width - label.width().unwrap_or(0.0)) / 2.0;
+            label.set_position(self.x + offset_x, offset_y);
+            offset_y = label.y() + SPACING;
+        }
+    }
+
+    fn draw(&self, canvas: &impl Canvas, camera: &Camera) {
+        let stroke = if self.is_highlighted() {
+            HIGHLIGHT_STROKE
+        } else {
+            DEFAULT_STROKE
+        };
+
+        Rectangle::new(
+            self.x,
+            self.y,
+            self.width(),
+            self.height(),
+            DEFAULT_COLOR,
+            None,
+            Some(stroke),
+        )
+        .draw(canvas, camera);
+
+        for label in self.labels() {
+            label.draw(canvas, camera);
+        }
+    }
+
+    fn bounds(&self) -> Rect {
+        Rect::new(self.x, self.y, self.width(), self.height())
+    }
+}
+
+impl Interactive for ObjectNode {
+    fn get_interaction(&self) -> InteractionState {
+        self.interaction_state
+    }
+
+    fn get_interaction_mut(&mut self) -> &mut InteractionState {
+        &mut self.interaction_state
+    }
+
+    fn adjust_position(&mut self, delta_x: f64, delta_y: f64) {
+        self.x += delta_x;
+        self.y += delta_y;
+
+        for label in std::iter::once(&mut self.title).chain(&mut self.in_state)
+        {
+            label.adjust_position(delta_x, delta_y);
+        }
+    }
+
+    #[allow(unused_variables)]
+    fn click(&mut self, x: f64, y: f64) -> Option<Prompt> {
+        Some(Prompt::Text {
+            explanation: "Provide this object node with a new type".into(),
+            placeholder: "Type name".into(),
+            value: self.title.text().to_string(),
+            metadata: Rc::new(()),
+        })
+    }
+
+    fn prompt(&mut self, response: PromptResponse) {
+        let PromptResponse::Text {
+            response,
+            metadata: _,
+        } = response;
+
+        if response.is_empty() {
+            return;
+        }
+
+        self.title.set_text(response);
+    }
+}
@@ -0,0 +1,280 @@
+use std::rc::Rc;
+
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    camera::Camera,
+    canvas::Canvas,
+    color::Color,
+    drawable::Drawable,
+    geometry::{Point, Rect},
+    id::Id,
+    interaction::{InteractionState, Interactive},
+    prompt::{Prompt, PromptResponse},
+    stroke::Stroke,
+};
+
+use super::{Label, Rectangle, TextProperties};
+
+const DEFAULT_COLOR: Color = const {
+    Color::Rgb {
+        red: 250,
+        green: 246,
+        blue: 228,
+    }
+};
+
+const DEFAULT_STROKE: Stroke = const {
+    Stroke::new(
+        2,
+        Color::Rgb {
+            red: 90,
+            green: 90,
+            blue: 90,
+        },
+    )
+};
+
+const HIGHLIGHT_STROKE: Stroke = const {
+    Stroke::new(
+        2,
+        Color::Rgb {
+            red: 142,
+            green: 202,
+            blue: 230,
+        },
+    )
+};
+
+const TEXT_COLOR: Color = const {
+    Color::Rgb {
+        red: 31,
+        green: 31,
+        blue: 31,
+    }
+};
+
+const MARGIN: f64 = 6.0;
+const MIN_TAB_WIDTH: f64 = 60.0;
+const MIN_WIDTH: f64 = 160.0;
+const MIN_HEIGHT: f64 = 100.0;
+/// Space between the border of a package and its members.
+const PADDING: f64 = 20.0;
+const HIT_DISTANCE: f64 = 6.0;
+
+/// A namespace grouping classes and other packages, drawn as a tabbed folder
+/// with the name on the tab. Packages grow to enclose their members and move
+/// them along. Once a package has members, only its tab and border can be
+/// grabbed, so the members inside stay selectable.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Package {
+    x: f64,
+    y: f64,
+    title: Label,
+    #[serde(default)]
+    members: Vec<Id>,
+
+    /// The bounds of the members.
+    #[serde(skip)]
+    contents: Option<Rect>,
+    #[serde(skip)]
+    interaction_state: InteractionState,
+}
+
+impl Package {
+    pub fn new(x: f64, y: f64, name: String) -> Self {
+        Self {
+            x,
+            y,
+            title: Label::new(
+                0.0,
+                0.0,
+                name,
+                TextProperties::default().weight(700),
+                TEXT_COLOR,
+            ),
+            members: vec![],
+            contents: None,
+            interaction_state: InteractionState::default(),
+        }
+    }
+
+    pub fn name(&self) -> &str {
+        self.title.text()
+    }
+
+    pub fn set_name(&mut self, value: String) {
+        self.title.set_text(value);
+    }
+
+    /// The elements owned by this package.
+    pub fn members(&self) -> &[Id] {
+        &self.members
+    }
+
+    pub fn add_member(&mut self, id: Id) {
+        if !self.members.contains(&id) {
+            self.members.push(id);
+        }
+    }
+
+    pub fn remove_member(&mut self, id: Id) {
+        self.members.retain(|m| *m != id);
+    }
+
+    pub fn x(&self) -> f64 {
+        self.bounds().left()
+    }
+
+    pub fn y(&self) -> f64 {
+        self.y
+    }
+
+    fn tab_size(&self) -> (f64, f64) {
+        let width = self.title.width().unwrap_or(0.0) + 2.0 * MARGIN;
+        let height = self.title.height().unwrap_or(0.0) + 2.0 * MARGIN;
+        (f64::max(width, MIN_TAB_WIDTH), height)
+    }
+
+    /// Grows the package to enclose `contents`, the bounds of its members.
+    pub fn attach(&mut self, contents: Option<Rect>) {
+        self.contents = contents;
+        self.place_title();
+    }
+
+    fn place_title(&mut self) {
+        let (l, t, ..) = self.bounds().edges();
+        self.title.set_position(l + MARGIN, t + MARGIN);
+    }
+
+    pub fn cursor_intersects(&self, x: f64, y: f64) -> bool {
+        let bounds = self.bounds();
+        let cursor = Point::new(x, y);
+        let (tab_width, tab_height) = self.tab_size();
+        let tab = Rect::new(bounds.left(), bounds.top(), tab_width, tab_height);
+
+        if tab.contains(cursor) {
+            return true;
+        }
+
+        let body = Rect::from_edges(
+            bounds.left(),
+            tab.bottom(),
+            bounds.right(),
+            bounds.bottom(),
+        );
+
+        if self.members.is_empty() {
+            return body.contains(cursor);
+        }
+
+        body.distance_to_border(cursor) <= HIT_DISTANCE
+    }
+}
+
+impl Drawable for Package {
+    fn initalize(&mut self, canvas: &impl Canvas) {
+        self.title.initalize(canvas);
+        self.place_title();
+    }
+
+    fn draw(&self, canvas: &impl Canvas, camera: &Camera) {
+        let stroke = if self.is_highlighted() {
+            HIGHLIGHT_STROKE
+        } else {
+            DEFAULT_STROKE
+        };
+
+        let (l, t, r, b) = self.bounds().edges();
+        let (tab_width, tab_height) = self.tab_size();
+
+        Rectangle::new(
+            l,
+            t,
+            tab_width,
+            tab_height,
+            DEFAULT_COLOR,
+            None,
+            Some(stroke),
+        )
+        .draw(canvas, camera);
+
+        let body = t + tab_height;
+        Rectangle::new(
+            l,
+            body,
+            r - l,
+            b - body,
+            DEFAULT_COLOR,
+            None,
+            Some(stroke),
+        )
+        .draw(canvas, camera);
+
+        self.title.draw(canvas, camera);
+    }
+
+    /// The bounds of the folder, including the tab and the members it
+    /// encloses.
+    fn bounds(&self) -> Rect {
+        let (tab_width, tab_height) = self.tab_size();
+        let width = f64::max(tab_width, MIN_WIDTH);
+        let own = Rect::new(self.x, self.y, width, tab_height + MIN_HEIGHT);
+
+        match self.contents {
+            Some(contents) => Rect::from_edges(
+                f64::min(own.left(), contents.left() - PADDING),
+                own.top(),
+                f64::max(own.right(), contents.right() + PADDING),
+                f64::max(own.bottom(), contents.bottom() + PADDING),
+            ),
+            None => own,
+        }
+    }
+}
+
+impl Interactive for Package {
+    fn get_interaction(&self) -> InteractionState {
+        self.interaction_state
+    }
+
+    fn get_interaction_mut(&mut self) -> &mut InteractionState {
+        &mut self.interaction_state
+    }
+
+    /// Moves the package itself. Its members are separate elements, which
+    /// `Document::move_element` moves along with it.
+    fn adjust_position(&mut self, delta_x: f64, delta_y: f64) {
+        self.x += delta_x;
+        self.y += delta_y;
+
+        if let Some(contents) = &mut self.contents {
+            *contents = contents.translate(delta_x, delta_y);
+        }
+
+        self.title.adjust_position(delta_x, delta_y);
+    }
+
+    #[allow(unused_variables)]
+    fn click(&mut self, x: f64, y: f64) -> Option<Prompt> {
+        Some(Prompt::Text {
+            explanation: "Provide this package with a new name".into(),
+            placeholder: "Package name".into(),
+            value: self.title.text().to_string(),
+            metadata: Rc::new(()),
+        })
+    }
+
+    fn prompt(&mut self, response: PromptResponse) {
+        let PromptResponse::Text {
+            response,
+            metadata: _,
+        } = response;
+
+        if response.is_empty() {
+            return;
+        }
+
+        self.title.set_text(response);
+    }
+}
@@ -0,0 +1,116 @@
+use std::f64::consts::TAU;
+
+use crate::{
+    camera::Camera, canvas::Canvas, color::Color, drawable::Drawable,
+    stroke::Stroke,
+};
+
+use crate::geometry::Rect;
+
+/// Number of segments approximating the outline of an ellipse.
+const ELLIPSE_SEGMENTS: usize = 48;
+
+/// A sequence of connected line segments. Closed paths can be filled, which
+/// is how arrowheads, diamonds and other decorations are drawn.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Path {
+    points: Vec<(f64, f64)>,
+    stroke: Option<Stroke>,
+    fill: Option<Color>,
+    closed: bool,
+    dashed: bool,
+}
+
+impl Path {
+    pub fn new(points: Vec<(f64, f64)>, stroke: Option<Stroke>) -> Self {
+        Self {
+            points,
+            stroke,
+            fill: None,
+            closed: false,
+            dashed: false,
+        }
+    }
+
+    pub fn line(from: (f64, f64), to: (f64, f64), stroke: Stroke) -> Self {
+        Self::new(vec![from, to], Some(stroke))
+    }
+
+    /// The outline of the ellipse inscribed in `bounds`, as a closed path.
+    pub fn ellipse(bounds: Rect, stroke: Option<Stroke>) -> Self {
+        let (l, t, r, b) = bounds.edges();
+        let (cx, cy) = ((l + r) / 2.0, (t + b) / 2.0);
+        let (rx, ry) = ((r - l) / 2.0, (b - t) / 2.0);
+        let points = (0..ELLIPSE_SEGMENTS)
+            .map(|i| {
+                let angle = TAU * i as f64 / ELLIPSE_SEGMENTS as f64;
+                (cx + rx * angle.cos(), cy + ry * angle.sin())
+            })
+            .collect();
+
+        Self::new(points, stroke).closed(None)
+    }
+
+    /// An open arc of the circle around `center`, from angle `from` to `to`
+    /// (in radians, clockwise on screen).
+    pub fn arc(
+        (cx, cy): (f64, f64),
+        radius: f64,
+        from: f64,
+        to: f64,
+        stroke: Stroke,
+    ) -> Self {
+        let segments =
+            ((to - from).abs() / TAU * ELLIPSE_SEGMENTS as f64).ceil() as usize;
+        let segments = segments.max(1);
+        let points = (0..=segments)
+            .map(|i| {
+                let angle = from + (to - from) * i as f64 / segments as f64;
+                (cx + radius * angle.cos(), cy + radius * angle.sin())
+            })
+            .collect();
+
+        Self::new(points, Some(stroke))
+    }
+
+    pub fn closed(mut self, fill: Option<Color>) -> Self {
+        self.closed = true;
+        self.fill = fill;
+        self
+    }
+
+    pub fn dashed(mut self, value: bool) -> Self {
+        self.dashed = value;
+        self
+    }
+
+    pub fn points(&self) -> &[(f64, f64)] {
+        &self.points
+    }
+
+    pub fn stroke(&self) -> Option<Stroke> {
+        self.stroke
+    }
+
+    pub fn fill(&self) -> Option<Color> {
+        self.fill
+    }
+
+    pub fn is_closed(&self) -> bool {
+        self.closed
+    }
+
+    pub fn is_dashed(&self) -> bool {
+        self.dashed
+    }
+}
+
+impl Drawable for Path {
+    fn draw(&self, canvas: &impl Canvas, camera: &Camera) {
+        canvas.draw_path(self, camera);
+    }
+
+    fn bounds(&self) -> Rect {
+        Rect::from_points(self.points.iter().copied()).unwrap_or_default()
+    }
+}
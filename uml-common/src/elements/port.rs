@@ -0,0 +1,209 @@
+use std::rc::Rc;
+
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    camera::Camera,
+    canvas::Canvas,
+    color::Color,
+    drawable::Drawable,
+    geometry::Rect,
+    id::Id,
+    interaction::{InteractionState, Interactive},
+    prompt::{Prompt, PromptResponse},
+    stroke::Stroke,
+};
+
+use super::{Label, Rectangle, TextProperties};
+
+const DEFAULT_COLOR: Color = const {
+    Color::Rgb {
+        red: 252,
+        green: 252,
+        blue: 252,
+    }
+};
+
+const DEFAULT_STROKE: Stroke = const {
+    Stroke::new(
+        2,
+        Color::Rgb {
+            red: 90,
+            green: 90,
+            blue: 90,
+        },
+    )
+};
+
+const HIGHLIGHT_STROKE: Stroke = const {
+    Stroke::new(
+        2,
+        Color::Rgb {
+            red: 142,
+            green: 202,
+            blue: 230,
+        },
+    )
+};
+
+const TEXT_COLOR: Color = const {
+    Color::Rgb {
+        red: 31,
+        green: 31,
+        blue: 31,
+    }
+};
+
+const SIZE: f64 = 14.0;
+const SPACING: f64 = 4.0;
+
+/// A point of interaction of a component with its environment, drawn as a
+/// small square on the border of its owner. Ports slide along the border
+/// when dragged and move along with their owner.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Port {
+    owner: Id,
+    x: f64,
+    y: f64,
+    title: Label,
+
+    #[serde(skip)]
+    interaction_state: InteractionState,
+}
+
+impl Port {
+    pub fn new(owner: Id, x: f64, y: f64, name: String) -> Self {
+        Self {
+            owner,
+            x,
+            y,
+            title: Label::new(
+                0.0,
+                0.0,
+                name,
+                TextProperties::default(),
+                TEXT_COLOR,
+            ),
+            interaction_state: InteractionState::default(),
+        }
+    }
+
+    /// The element the port is on.
+    pub fn owner(&self) -> Id {
+        self.owner
+    }
+
+    pub fn name(&self) -> &str {
+        self.title.text()
+    }
+
+    pub fn set_name(&mut self, value: String) {
+        self.title.set_text(value);
+    }
+
+    pub fn x(&self) -> f64 {
+        self.x
+    }
+
+    pub fn y(&self) -> f64 {
+        self.y
+    }
+
+    /// Moves the port onto the nearest point of the border of its owner, and
+    /// places the name outside of the owner, next to the port.
+    pub fn attach(&mut self, owner: Rect) {
+        let (l, t, r, b) = owner.edges();
+        let cx = (self.x + SIZE / 2.0).clamp(l, r);
+        let cy = (self.y + SIZE / 2.0).clamp(t, b);
+
+        let distances = [cx - l, r - cx, cy - t, b - cy];
+        let side = (0..4)
+            .min_by(|a, b| distances[*a].total_cmp(&distances[*b]))
+            .unwrap_or(0);
+        let (cx, cy) = match side {
+            0 => (l, cy),
+            1 => (r, cy),
+            2 => (cx, t),
+            _ => (cx, b),
+        };
+
+        self.x = cx - SIZE / 2.0;
+        self.y = cy - SIZE / 2.0;
+
+        let width = self.title.width().unwrap_or(0.0);
+        let height = self.title.height().unwrap_or(0.0);
+        let (x, y) = match side {
+            0 => (self.x - SPACING - width, self.y - height),
+            1 => (self.x + SIZE + SPACING, self.y - height),
+            2 => (self.x + SIZE + SPACING, self.y - SPACING - height),
+            _ => (self.x + SIZE + SPACING, self.y + SIZE + SPACING),
+        };
+        self.title.set_position(x, y);
+    }
+}
+
+impl Drawable for Port {
+    fn initalize(&mut self, canvas: &impl Canvas) {
+        self.title.initalize(canvas);
+    }
+
+    fn draw(&self, canvas: &impl Canvas, camera: &Camera) {
+        let stroke = if self.is_highlighted() {
+            HIGHLIGHT_STROKE
+        } else {
+            DEFAULT_STROKE
+        };
+
+        Rectangle::new(
+            self.x,
+            self.y,
+            SIZE,
+            SIZE,
+            DEFAULT_COLOR,
+            None,
+            Some(stroke),
+        )
+        .draw(canvas, camera);
+
+        self.title.draw(canvas, camera);
+    }
+
+    fn bounds(&self) -> Rect {
+        Rect::new(self.x, self.y, SIZE, SIZE)
+    }
+}
+
+impl Interactive for Port {
+    fn get_interaction(&self) -> InteractionState {
+        self.interaction_state
+    }
+
+    fn get_interaction_mut(&mut self) -> &mut InteractionState {
+        &mut self.interaction_state
+    }
+
+    fn adjust_position(&mut self, delta_x: f64, delta_y: f64) {
+        self.x += delta_x;
+        self.y += delta_y;
+        self.title.adjust_position(delta_x, delta_y);
+    }
+
+    #[allow(unused_variables)]
+    fn click(&mut self, x: f64, y: f64) -> Option<Prompt> {
+        Some(Prompt::Text {
+            explanation: "Provide this port with a new name".into(),
+            placeholder: "Port name".into(),
+            value: self.title.text().to_string(),
+            metadata: Rc::new(()),
+        })
+    }
+
+    fn prompt(&mut self, response: PromptResponse) {
+        let PromptResponse::Text {
+            response,
+            metadata: _,
+        } = response;
+
+        self.title.set_text(response);
+    }
+}
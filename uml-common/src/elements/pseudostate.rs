@@ -0,0 +1,190 @@
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    camera::Camera,
+    canvas::Canvas,
+    color::{Color, WHITE},
+    drawable::Drawable,
+    geometry::Rect,
+    interaction::{InteractionState, Interactive},
+    stroke::Stroke,
+};
+
+use super::{Label, Outline, Path, TextProperties};
+
+const DEFAULT_STROKE: Stroke = const {
+    Stroke::new(
+        2,
+        Color::Rgb {
+            red: 90,
+            green: 90,
+            blue: 90,
+        },
+    )
+};
+
+const HIGHLIGHT_STROKE: Stroke = const {
+    Stroke::new(
+        2,
+        Color::Rgb {
+            red: 142,
+            green: 202,
+            blue: 230,
+        },
+    )
+};
+
+const FILL_COLOR: Color = const {
+    Color::Rgb {
+        red: 31,
+        green: 31,
+        blue: 31,
+    }
+};
+
+/// Gap between the ring and the dot of a final state.
+const RING: f64 = 5.0;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum PseudostateKind {
+    /// A filled circle, where the state machine (or region) starts.
+    Initial,
+    /// A filled circle within a ring, where the state machine ends.
+    Final,
+    /// A diamond, from which one of several guarded transitions is taken.
+    Choice,
+    /// A circled `H`, resuming the most recent substate.
+    ShallowHistory,
+    /// A circled `H*`, resuming the most recent nested substates.
+    DeepHistory,
+}
+
+impl PseudostateKind {
+    fn size(&self) -> f64 {
+        match self {
+            PseudostateKind::Initial => 20.0,
+            PseudostateKind::Final => 26.0,
+            PseudostateKind::Choice
+            | PseudostateKind::ShallowHistory
+            | PseudostateKind::DeepHistory => 30.0,
+        }
+    }
+}
+
+/// One of the small nodes of a state machine that are not states of their
+/// own: initial and final nodes, choices and history.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Pseudostate {
+    x: f64,
+    y: f64,
+    kind: PseudostateKind,
+
+    #[serde(skip)]
+    interaction_state: InteractionState,
+}
+
+impl Pseudostate {
+    pub fn new(x: f64, y: f64, kind: PseudostateKind) -> Self {
+        Self {
+            x,
+            y,
+            kind,
+            interaction_state: InteractionState::default(),
+        }
+    }
+
+    pub fn kind(&self) -> PseudostateKind {
+        self.kind
+    }
+
+    pub fn x(&self) -> f64 {
+        self.x
+    }
+
+    pub fn y(&self) -> f64 {
+        self.y
+    }
+
+    /// The shape transitions are clipped to.
+    pub fn outline(&self) -> Outline {
+        match self.kind {
+            PseudostateKind::Choice => Outline::Diamond(self.bounds()),
+            _ => Outline::Ellipse(self.bounds()),
+        }
+    }
+}
+
+impl Drawable for Pseudostate {
+    fn draw(&self, canvas: &impl Canvas, camera: &Camera) {
+        let stroke = if self.is_highlighted() {
+            HIGHLIGHT_STROKE
+        } else {
+            DEFAULT_STROKE
+        };
+
+        let (l, t, r, b) = self.bounds().edges();
+        let (cx, cy) = ((l + r) / 2.0, (t + b) / 2.0);
+
+        match self.kind {
+            PseudostateKind::Initial => {
+                Path::ellipse(self.bounds(), Some(stroke))
+                    .closed(Some(FILL_COLOR))
+                    .draw(canvas, camera);
+            }
+            PseudostateKind::Final => {
+                Path::ellipse(self.bounds(), Some(stroke))
+                    .closed(Some(WHITE))
+                    .draw(canvas, camera);
+                Path::ellipse(
+                    Rect::from_edges(l + RING, t + RING, r - RING, b - RING),
+                    None,
+                )
+                .closed(Some(FILL_COLOR))
+                .draw(canvas, camera);
+            }
+            PseudostateKind::Choice => {
+                Path::new(
+                    vec![(cx, t), (r, cy), (cx, b), (l, cy)],
+                    Some(stroke),
+                )
+                .closed(Some(WHITE))
+                .draw(canvas, camera);
+            }
+            PseudostateKind::ShallowHistory | PseudostateKind::DeepHistory => {
+                Path::ellipse(self.bounds(), Some(stroke))
+                    .closed(Some(WHITE))
+                    .draw(canvas, camera);
+
+                let text = match self.kind {
+                    PseudostateKind::DeepHistory => "H*",
+                    _ => "H",
+                };
+                let props =
+                    TextProperties::new(16.0, "Arial,sans-serif").weight(700);
+                let offset = text.len() as f64 * 5.0;
+                Label::new(cx - offset, cy + 6.0, text, props, FILL_COLOR)
+                    .draw(canvas, camera);
+            }
+        }
+    }
+
+    fn bounds(&self) -> Rect {
+        let size = self.kind.size();
+        Rect::new(self.x, self.y, size, size)
+    }
+}
+
+impl Interactive for Pseudostate {
+    fn get_interaction(&self) -> InteractionState {
+        self.interaction_state
+    }
+
+    fn get_interaction_mut(&mut self) -> &mut InteractionState {
+        &mut self.interaction_state
+    }
+
+    fn adjust_position(&mut self, delta_x: f64, delta_y: f64) {
+        self.x += delta_x;
+        self.y += delta_y;
+    }
+}
@@ -5,18 +5,19 @@ use crate::{
     canvas::Canvas,
     color::Color,
     drawable::Drawable,
+    geometry::Rect,
     interaction::{InteractionState, Interactive},
     stroke::Stroke,
 };
 
 #[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
 pub struct Rectangle {
-    x: i32,
-    y: i32,
-    width: u32,
-    height: u32,
+    x: f64,
+    y: f64,
+    width: f64,
+    height: f64,
     color: Color,
-    radius: Option<u32>,
+    radius: Option<f64>,
     stroke: Option<Stroke>,
     #[serde(skip)]
     interaction_state: InteractionState,
@@ -24,12 +25,12 @@ pub struct Rectangle {
 
 impl Rectangle {
     pub fn new(
-        x: i32,
-        y: i32,
-        width: u32,
-        height: u32,
+        x: f64,
+        y: f64,
+        width: f64,
+        height: f64,
         color: Color,
-        radius: Option<u32>,
+        radius: Option<f64>,
         stroke: Option<Stroke>,
     ) -> Self {
         Self {
@@ -44,19 +45,19 @@ impl Rectangle {
         }
     }
 
-    pub fn x(&self) -> i32 {
+    pub fn x(&self) -> f64 {
         self.x
     }
 
-    pub fn y(&self) -> i32 {
+    pub fn y(&self) -> f64 {
         self.y
     }
 
-    pub fn width(&self) -> u32 {
+    pub fn width(&self) -> f64 {
         self.width
     }
 
-    pub fn height(&self) -> u32 {
+    pub fn height(&self) -> f64 {
         self.height
     }
 
@@ -64,7 +65,7 @@ impl Rectangle {
         self.color
     }
 
-    pub fn radius(&self) -> Option<u32> {
+    pub fn radius(&self) -> Option<f64> {
         self.radius
     }
 
@@ -77,6 +78,10 @@ impl Drawable for Rectangle {
     fn draw(&self, canvas: &impl Canvas, camera: &Camera) {
         canvas.draw_rectangle(*self, camera);
     }
+
+    fn bounds(&self) -> Rect {
+        Rect::new(self.x, self.y, self.width, self.height)
+    }
 }
 
 impl Interactive for Rectangle {
@@ -88,7 +93,7 @@ impl Interactive for Rectangle {
         &mut self.interaction_state
     }
 
-    fn adjust_position(&mut self, delta_x: i32, delta_y: i32) {
+    fn adjust_position(&mut self, delta_x: f64, delta_y: f64) {
         self.x += delta_x;
         self.y += delta_y;
     }
@@ -0,0 +1,740 @@
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    camera::Camera,
+    canvas::Canvas,
+    color::{Color, WHITE},
+    drawable::Drawable,
+    geometry::{Point, Rect},
+    id::Id,
+    interaction::{InteractionState, Interactive},
+    stroke::Stroke,
+};
+
+use super::{Label, Path, TextProperties};
+
+const DEFAULT_STROKE: Stroke = const {
+    Stroke::new(
+        2,
+        Color::Rgb {
+            red: 90,
+            green: 90,
+            blue: 90,
+        },
+    )
+};
+
+const HIGHLIGHT_STROKE: Stroke = const {
+    Stroke::new(
+        2,
+        Color::Rgb {
+            red: 142,
+            green: 202,
+            blue: 230,
+        },
+    )
+};
+
+/// Stroke of a link whose association does not connect the classes of the
+/// instances it connects.
+const ERROR_STROKE: Stroke = const {
+    Stroke::new(
+        2,
+        Color::Rgb {
+            red: 200,
+            green: 40,
+            blue: 40,
+        },
+    )
+};
+
+const TEXT_COLOR: Color = const {
+    Color::Rgb {
+        red: 31,
+        green: 31,
+        blue: 31,
+    }
+};
+
+const HEAD_LENGTH: f64 = 14.0;
+const HEAD_WIDTH: f64 = 8.0;
+const LOOP_SIZE: f64 = 30.0;
+const HIT_DISTANCE: f64 = 6.0;
+
+/// What a relationship means, which decides how its line and ends are drawn.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum RelationshipKind {
+    /// Solid line with an open arrowhead at the target.
+    Association,
+    /// Solid line without decorations.
+    Link,
+    /// Solid line with a hollow triangle at the target (the general class).
+    Generalization,
+    /// Dashed line with a hollow triangle at the target (the interface).
+    Realization,
+    /// Solid line with a hollow diamond at the target (the whole).
+    Aggregation,
+    /// Solid line with a filled diamond at the target (the whole).
+    Composition,
+    /// Dashed line with an open arrowhead at the target.
+    Dependency,
+    /// Dashed line without decorations.
+    DashedLink,
+    /// Dashed line with an open arrowhead from a use case to one it always
+    /// includes, labelled «include».
+    Include,
+    /// Dashed line with an open arrowhead from a use case to the one it
+    /// extends, labelled «extend».
+    Extend,
+    /// Solid line with an open arrowhead from one state to the next,
+    /// labelled with its trigger (`event [guard] / action`).
+    Transition,
+    /// Solid line with an open arrowhead from one activity node to the next,
+    /// labelled with its guard (`[guard]`).
+    ControlFlow,
+    /// Solid line without decorations between deployment nodes that
+    /// exchange messages, optionally labelled with the protocol.
+    CommunicationPath,
+    /// Dashed line with an open arrowhead from a package to one whose public
+    /// names it imports, labelled «import».
+    Import,
+    /// Dashed line with an open arrowhead from a package to one whose
+    /// contents it merges into its own, labelled «merge».
+    Merge,
+    /// Solid line between two entities, ending at each side in the
+    /// crow's-foot symbol of its cardinality.
+    EntityRelationship,
+    /// Dashed line with an open arrowhead between the elements of a C4
+    /// diagram, labelled with what the source uses the target for and the
+    /// technology it does so with (e.g. `[HTTPS]`).
+    Uses,
+}
+
+impl RelationshipKind {
+    pub fn is_dashed(&self) -> bool {
+        matches!(
+            self,
+            RelationshipKind::Realization
+                | RelationshipKind::Dependency
+                | RelationshipKind::DashedLink
+                | RelationshipKind::Include
+                | RelationshipKind::Extend
+                | RelationshipKind::Import
+                | RelationshipKind::Merge
+                | RelationshipKind::Uses
+        )
+    }
+
+    /// The stereotype shown on the line, without guillemets.
+    pub fn stereotype(&self) -> Option<&'static str> {
+        match self {
+            RelationshipKind::Include => Some("include"),
+            RelationshipKind::Extend => Some("extend"),
+            RelationshipKind::Import => Some("import"),
+            RelationshipKind::Merge => Some("merge"),
+            _ => None,
+        }
+    }
+}
+
+/// How many rows of an entity take part in an entity relationship, drawn
+/// with crow's-foot notation. Cardinalities are stored as the multiplicities
+/// of the relationship, so they are exported like any other multiplicity.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Cardinality {
+    /// Exactly one, drawn as two bars.
+    One,
+    /// At most one, drawn as a circle and a bar.
+    ZeroOrOne,
+    /// Any number, drawn as a circle and a crow's foot.
+    Many,
+    /// At least one, drawn as a bar and a crow's foot.
+    OneOrMany,
+}
+
+impl Cardinality {
+    pub fn multiplicity(&self) -> &'static str {
+        match self {
+            Cardinality::One => "1",
+            Cardinality::ZeroOrOne => "0..1",
+            Cardinality::Many => "*",
+            Cardinality::OneOrMany => "1..*",
+        }
+    }
+
+    pub fn from_multiplicity(text: &str) -> Option<Self> {
+        match text.trim() {
+            "1" | "1..1" => Some(Cardinality::One),
+            "0..1" => Some(Cardinality::ZeroOrOne),
+            "*" | "0..*" | "n" | "N" => Some(Cardinality::Many),
+            "1..*" | "1..n" | "1..N" => Some(Cardinality::OneOrMany),
+            _ => None,
+        }
+    }
+
+    fn is_optional(&self) -> bool {
+        matches!(self, Cardinality::ZeroOrOne | Cardinality::Many)
+    }
+
+    fn is_many(&self) -> bool {
+        matches!(self, Cardinality::Many | Cardinality::OneOrMany)
+    }
+}
+
+/// The shape of an element, which relationships are clipped to.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Outline {
+    Rectangle(Rect),
+    /// The ellipse inscribed in the bounds.
+    Ellipse(Rect),
+    /// The diamond whose corners touch the middle of each side of the
+    /// bounds.
+    Diamond(Rect),
+}
+
+impl Outline {
+    pub fn bounds(&self) -> Rect {
+        match self {
+            Outline::Rectangle(bounds)
+            | Outline::Ellipse(bounds)
+            | Outline::Diamond(bounds) => *bounds,
+        }
+    }
+
+    /// Returns the point where the line from the center towards `towards`
+    /// crosses the outline.
+    pub fn clip(&self, towards: (f64, f64)) -> (f64, f64) {
+        match self {
+            Outline::Rectangle(bounds) => clip(*bounds, towards),
+            Outline::Ellipse(bounds) => clip_ellipse(*bounds, towards),
+            Outline::Diamond(bounds) => clip_diamond(*bounds, towards),
+        }
+    }
+}
+
+/// A line connecting two elements of a document. The endpoints are derived
+/// from the bounds of the connected elements every time they are laid out.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Relationship {
+    source: Id,
+    target: Id,
+    kind: RelationshipKind,
+    label: Option<String>,
+    source_multiplicity: Option<String>,
+    target_multiplicity: Option<String>,
+    /// The association a link between two instances is an instance of.
+    #[serde(default)]
+    association: Option<Id>,
+    /// The technology a C4 relationship uses, shown under its label.
+    #[serde(default)]
+    technology: Option<String>,
+
+    #[serde(skip)]
+    points: Vec<(f64, f64)>,
+    /// The name of the association, shown on links without a label.
+    #[serde(skip)]
+    association_name: Option<String>,
+    #[serde(skip)]
+    mistyped: bool,
+    #[serde(skip)]
+    interaction_state: InteractionState,
+}
+
+impl Relationship {
+    pub fn new(source: Id, target: Id, kind: RelationshipKind) -> Self {
+        Self {
+            source,
+            target,
+            kind,
+            label: None,
+            source_multiplicity: None,
+            target_multiplicity: None,
+            association: None,
+            technology: None,
+            points: vec![],
+            association_name: None,
+            mistyped: false,
+            interaction_state: InteractionState::default(),
+        }
+    }
+
+    pub fn source(&self) -> Id {
+        self.source
+    }
+
+    pub fn target(&self) -> Id {
+        self.target
+    }
+
+    pub fn kind(&self) -> RelationshipKind {
+        self.kind
+    }
+
+    pub fn label(&self) -> Option<&str> {
+        self.label.as_deref()
+    }
+
+    pub fn source_multiplicity(&self) -> Option<&str> {
+        self.source_multiplicity.as_deref()
+    }
+
+    pub fn target_multiplicity(&self) -> Option<&str> {
+        self.target_multiplicity.as_deref()
+    }
+
+    pub fn set_label(&mut self, value: Option<String>) {
+        self.label = value;
+    }
+
+    /// Labels a control flow with the guard that must hold for it to be
+    /// taken, written as `[guard]`.
+    pub fn set_guard(&mut self, guard: Option<&str>) {
+        self.label = guard.map(|guard| format!("[{guard}]"));
+    }
+
+    /// The guard of a control flow, without the brackets.
+    pub fn guard(&self) -> Option<&str> {
+        self.label.as_deref()?.strip_prefix('[')?.strip_suffix(']')
+    }
+
+    /// Labels a transition with its trigger, written as
+    /// `event [guard] / action`. Each part is optional.
+    pub fn set_trigger(
+        &mut self,
+        event: Option<&str>,
+        guard: Option<&str>,
+        action: Option<&str>,
+    ) {
+        let mut parts = vec![];
+        parts.extend(event.map(String::from));
+        parts.extend(guard.map(|guard| format!("[{guard}]")));
+        parts.extend(action.map(|action| format!("/ {action}")));
+
+        self.label = (!parts.is_empty()).then(|| parts.join(" "));
+    }
+
+    /// The text shown along the line: the label, preceded by the stereotype
+    /// of the kind of relationship (e.g. `«extend»`). Links without a label
+    /// show the name of their association.
+    pub fn caption(&self) -> Option<String> {
+        match (self.kind.stereotype(), &self.label) {
+            (Some(stereotype), Some(label)) => {
+                Some(format!("«{stereotype}» {label}"))
+            }
+            (Some(stereotype), None) => Some(format!("«{stereotype}»")),
+            (None, Some(label)) => Some(label.clone()),
+            (None, None) => self.association_name.clone(),
+        }
+    }
+
+    pub fn technology(&self) -> Option<&str> {
+        self.technology.as_deref()
+    }
+
+    pub fn set_technology(&mut self, value: Option<String>) {
+        self.technology = value;
+    }
+
+    pub fn association(&self) -> Option<Id> {
+        self.association
+    }
+
+    /// Types a link by the association between the classes of the instances
+    /// it connects.
+    pub fn set_association(&mut self, value: Option<Id>) {
+        self.association = value;
+    }
+
+    /// Whether the association of a link does not connect the classes of the
+    /// instances the link connects.
+    pub fn is_mistyped(&self) -> bool {
+        self.mistyped
+    }
+
+    /// Takes the name of the association, and whether it fits the instances
+    /// connected by the link.
+    pub fn attach_association(&mut self, name: Option<String>, mistyped: bool) {
+        self.association_name = name;
+        self.mistyped = mistyped;
+    }
+
+    pub fn set_multiplicities(
+        &mut self,
+        source: Option<String>,
+        target: Option<String>,
+    ) {
+        self.source_multiplicity = source;
+        self.target_multiplicity = target;
+    }
+
+    pub fn source_cardinality(&self) -> Option<Cardinality> {
+        Cardinality::from_multiplicity(self.source_multiplicity.as_deref()?)
+    }
+
+    pub fn target_cardinality(&self) -> Option<Cardinality> {
+        Cardinality::from_multiplicity(self.target_multiplicity.as_deref()?)
+    }
+
+    pub fn set_cardinalities(
+        &mut self,
+        source: Option<Cardinality>,
+        target: Option<Cardinality>,
+    ) {
+        self.set_multiplicities(
+            source.map(|c| c.multiplicity().to_string()),
+            target.map(|c| c.multiplicity().to_string()),
+        );
+    }
+
+    pub fn x(&self) -> f64 {
+        self.points.first().map(|p| p.0).unwrap_or(0.0)
+    }
+
+    pub fn y(&self) -> f64 {
+        self.points.first().map(|p| p.1).unwrap_or(0.0)
+    }
+
+    /// The line of the relationship, from the source to the target.
+    pub fn points(&self) -> &[(f64, f64)] {
+        &self.points
+    }
+
+    /// Routes the relationship between the bounds of its source and target.
+    pub fn attach(&mut self, source: Rect, target: Rect) {
+        self.attach_outlines(
+            Outline::Rectangle(source),
+            Outline::Rectangle(target),
+        );
+    }
+
+    /// Routes the relationship between the outlines of its source and
+    /// target.
+    pub fn attach_outlines(&mut self, source: Outline, target: Outline) {
+        if self.source == self.target {
+            let (t, r) = (source.bounds().top(), source.bounds().right());
+            self.points = vec![
+                (r, t + LOOP_SIZE / 2.0),
+                (r + LOOP_SIZE, t + LOOP_SIZE / 2.0),
+                (r + LOOP_SIZE, t - LOOP_SIZE),
+                (r - LOOP_SIZE, t - LOOP_SIZE),
+                (r - LOOP_SIZE, t),
+            ];
+            return;
+        }
+
+        let from = source.bounds().center().into();
+        let to = target.bounds().center().into();
+        self.points = vec![source.clip(to), target.clip(from)];
+    }
+
+    pub fn distance_to(&self, x: f64, y: f64) -> f64 {
+        self.points
+            .windows(2)
+            .map(|w| {
+                Point::new(x, y).distance_to_segment(w[0].into(), w[1].into())
+            })
+            .fold(f64::INFINITY, f64::min)
+    }
+
+    pub fn cursor_intersects(&self, x: f64, y: f64) -> bool {
+        self.distance_to(x, y) <= HIT_DISTANCE
+    }
+
+    fn draw_head(&self, canvas: &impl Canvas, camera: &Camera, stroke: Stroke) {
+        let [.., from, tip] = self.points[..] else {
+            return;
+        };
+
+        let at = along_segment(from, tip);
+
+        let path = match self.kind {
+            RelationshipKind::Association
+            | RelationshipKind::Dependency
+            | RelationshipKind::Include
+            | RelationshipKind::Extend
+            | RelationshipKind::Import
+            | RelationshipKind::Merge
+            | RelationshipKind::Transition
+            | RelationshipKind::ControlFlow
+            | RelationshipKind::Uses => Path::new(
+                vec![
+                    at(HEAD_LENGTH, HEAD_WIDTH),
+                    tip,
+                    at(HEAD_LENGTH, -HEAD_WIDTH),
+                ],
+                Some(stroke),
+            ),
+            RelationshipKind::Generalization
+            | RelationshipKind::Realization => Path::new(
+                vec![
+                    tip,
+                    at(HEAD_LENGTH, HEAD_WIDTH),
+                    at(HEAD_LENGTH, -HEAD_WIDTH),
+                ],
+                Some(stroke),
+            )
+            .closed(Some(WHITE)),
+            RelationshipKind::Aggregation | RelationshipKind::Composition => {
+                let fill = match self.kind {
+                    RelationshipKind::Composition => stroke.color(),
+                    _ => WHITE,
+                };
+
+                Path::new(
+                    vec![
+                        tip,
+                        at(HEAD_LENGTH, HEAD_WIDTH),
+                        at(HEAD_LENGTH * 2.0, 0.0),
+                        at(HEAD_LENGTH, -HEAD_WIDTH),
+                    ],
+                    Some(stroke),
+                )
+                .closed(Some(fill))
+            }
+            RelationshipKind::Link
+            | RelationshipKind::DashedLink
+            | RelationshipKind::CommunicationPath
+            | RelationshipKind::EntityRelationship => return,
+        };
+
+        path.draw(canvas, camera);
+    }
+
+    /// Draws the crow's-foot symbols at both ends of an entity relationship.
+    fn draw_cardinalities(
+        &self,
+        canvas: &impl Canvas,
+        camera: &Camera,
+        stroke: Stroke,
+    ) {
+        let n = self.points.len();
+        let ends = [
+            (self.source_cardinality(), self.points[0], self.points[1]),
+            (
+                self.target_cardinality(),
+                self.points[n - 1],
+                self.points[n - 2],
+            ),
+        ];
+
+        for (cardinality, tip, from) in ends {
+            let Some(cardinality) = cardinality else {
+                continue;
+            };
+
+            for path in crows_foot(cardinality, tip, from, stroke) {
+                path.draw(canvas, camera);
+            }
+        }
+    }
+
+    fn draw_labels(&self, canvas: &impl Canvas, camera: &Camera) {
+        let props = TextProperties::new(16.0, "Arial,sans-serif");
+        let label_at = |text: &str, (x, y): (f64, f64)| {
+            Label::new(x, y, text, props.clone(), TEXT_COLOR)
+                .draw(canvas, camera);
+        };
+
+        let n = self.points.len();
+
+        // Entity relationships show their multiplicities as crow's feet.
+        let multiplicities = match self.kind {
+            RelationshipKind::EntityRelationship => [None, None],
+            _ => [&self.source_multiplicity, &self.target_multiplicity]
+                .map(Option::as_ref),
+        };
+
+        if let Some(text) = multiplicities[0] {
+            label_at(text, near_end(self.points[0], self.points[1]));
+        }
+
+        if let Some(text) = multiplicities[1] {
+            label_at(text, near_end(self.points[n - 1], self.points[n - 2]));
+        }
+
+        let (a, b) = (self.points[(n - 2) / 2], self.points[n / 2]);
+        let (x, y) = ((a.0 + b.0) / 2.0 + 6.0, (a.1 + b.1) / 2.0 - 6.0);
+
+        if let Some(text) = self.caption() {
+            label_at(&text, (x, y));
+        }
+
+        if let Some(technology) = &self.technology {
+            label_at(&format!("[{technology}]"), (x, y + 20.0));
+        }
+    }
+}
+
+impl Drawable for Relationship {
+    fn draw(&self, canvas: &impl Canvas, camera: &Camera) {
+        if self.points.len() < 2 {
+            return;
+        }
+
+        let stroke = if self.is_highlighted() {
+            HIGHLIGHT_STROKE
+        } else if self.mistyped {
+            ERROR_STROKE
+        } else {
+            DEFAULT_STROKE
+        };
+
+        Path::new(self.points.clone(), Some(stroke))
+            .dashed(self.kind.is_dashed())
+            .draw(canvas, camera);
+
+        self.draw_head(canvas, camera, stroke);
+
+        if self.kind == RelationshipKind::EntityRelationship {
+            self.draw_cardinalities(canvas, camera, stroke);
+        }
+
+        self.draw_labels(canvas, camera);
+    }
+
+    fn bounds(&self) -> Rect {
+        Rect::from_points(self.points.iter().copied()).unwrap_or_default()
+    }
+}
+
+impl Interactive for Relationship {
+    fn get_interaction(&self) -> InteractionState {
+        self.interaction_state
+    }
+
+    fn get_interaction_mut(&mut self) -> &mut InteractionState {
+        &mut self.interaction_state
+    }
+
+    /// Relationships follow the elements they connect, so they cannot be
+    /// moved on their own.
+    fn adjust_position(&mut self, _: f64, _: f64) {}
+}
+
+/// Returns the point where the line from the center of `bounds` towards
+/// `towards` crosses the border of `bounds`.
+fn clip(bounds: Rect, towards: (f64, f64)) -> (f64, f64) {
+    let center = bounds.center();
+    let direction = Point::from(towards) - center;
+    let half_w = bounds.width() / 2.0;
+    let half_h = bounds.height() / 2.0;
+
+    let tx = if direction.x() != 0.0 {
+        half_w / direction.x().abs()
+    } else {
+        f64::INFINITY
+    };
+    let ty = if direction.y() != 0.0 {
+        half_h / direction.y().abs()
+    } else {
+        f64::INFINITY
+    };
+    let t = tx.min(ty).min(1.0);
+
+    if !t.is_finite() {
+        return center.into();
+    }
+
+    center.offset(direction * t).into()
+}
+
+/// Like `clip`, for the ellipse inscribed in `bounds`.
+fn clip_ellipse(bounds: Rect, towards: (f64, f64)) -> (f64, f64) {
+    let center = bounds.center();
+    let direction = Point::from(towards) - center;
+    let rx = bounds.width() / 2.0;
+    let ry = bounds.height() / 2.0;
+
+    if rx == 0.0 || ry == 0.0 || direction.length() == 0.0 {
+        return center.into();
+    }
+
+    let (dx, dy) = (direction.x(), direction.y());
+    let t = (1.0 / ((dx / rx).powi(2) + (dy / ry).powi(2)).sqrt()).min(1.0);
+
+    center.offset(direction * t).into()
+}
+
+/// Like `clip`, for the diamond inscribed in `bounds`.
+fn clip_diamond(bounds: Rect, towards: (f64, f64)) -> (f64, f64) {
+    let center = bounds.center();
+    let direction = Point::from(towards) - center;
+    let half_w = bounds.width() / 2.0;
+    let half_h = bounds.height() / 2.0;
+
+    if half_w == 0.0 || half_h == 0.0 || direction.length() == 0.0 {
+        return center.into();
+    }
+
+    let (dx, dy) = (direction.x(), direction.y());
+    let t = (1.0 / (dx.abs() / half_w + dy.abs() / half_h)).min(1.0);
+
+    center.offset(direction * t).into()
+}
+
+/// Positions relative to the `tip` of the segment `from`-`tip`: `along` the
+/// segment back towards `from`, and `across` it to the left.
+fn along_segment(
+    from: (f64, f64),
+    tip: (f64, f64),
+) -> impl Fn(f64, f64) -> (f64, f64) {
+    let direction = (Point::from(tip) - Point::from(from)).normalized();
+
+    move |along, across| {
+        Point::from(tip)
+            .offset(direction.perpendicular() * across - direction * along)
+            .into()
+    }
+}
+
+/// The crow's-foot symbol of `cardinality` at the `tip` of the segment
+/// `from`-`tip`: the toes or a bar at the element, and a circle or a second
+/// bar behind it.
+fn crows_foot(
+    cardinality: Cardinality,
+    tip: (f64, f64),
+    from: (f64, f64),
+    stroke: Stroke,
+) -> Vec<Path> {
+    let at = along_segment(from, tip);
+    let bar = |along: f64| {
+        Path::line(at(along, HEAD_WIDTH), at(along, -HEAD_WIDTH), stroke)
+    };
+
+    let mut paths = vec![];
+
+    if cardinality.is_many() {
+        paths.push(Path::new(
+            vec![
+                at(0.0, HEAD_WIDTH),
+                at(HEAD_LENGTH, 0.0),
+                at(0.0, -HEAD_WIDTH),
+            ],
+            Some(stroke),
+        ));
+        paths.push(Path::line(at(0.0, 0.0), at(HEAD_LENGTH, 0.0), stroke));
+    } else {
+        paths.push(bar(HEAD_LENGTH * 0.5));
+    }
+
+    let behind = HEAD_LENGTH * 1.5;
+
+    if cardinality.is_optional() {
+        let radius = HEAD_WIDTH * 0.6;
+        let (cx, cy) = at(behind + radius, 0.0);
+        let circle = Rect::from_edges(cx, cy, cx, cy).inflate(radius);
+        paths.push(Path::ellipse(circle, Some(stroke)).closed(Some(WHITE)));
+    } else {
+        paths.push(bar(behind));
+    }
+
+    paths
+}
+
+/// Position for a label next to the `end` of the segment `end`-`other`.
+fn near_end(end: (f64, f64), other: (f64, f64)) -> (f64, f64) {
+    let direction = (Point::from(other) - Point::from(end)).normalized();
+    Point::from(end)
+        .offset(direction * 18.0 + direction.perpendicular() * 12.0)
+        .into()
+}
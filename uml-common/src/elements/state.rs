@@ -0,0 +1,354 @@
+use std::rc::Rc;
+
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    camera::Camera,
+    canvas::Canvas,
+    color::Color,
+    drawable::Drawable,
+    geometry::{Point, Rect},
+    id::Id,
+    interaction::{InteractionState, Interactive},
+    prompt::{Prompt, PromptResponse},
+    stroke::Stroke,
+};
+
+use super::{Label, Path, Rectangle, TextProperties};
+
+const DEFAULT_COLOR: Color = const {
+    Color::Rgb {
+        red: 244,
+        green: 244,
+        blue: 244,
+    }
+};
+
+const DEFAULT_STROKE: Stroke = const {
+    Stroke::new(
+        2,
+        Color::Rgb {
+            red: 90,
+            green: 90,
+            blue: 90,
+        },
+    )
+};
+
+const HIGHLIGHT_STROKE: Stroke = const {
+    Stroke::new(
+        2,
+        Color::Rgb {
+            red: 142,
+            green: 202,
+            blue: 230,
+        },
+    )
+};
+
+const TEXT_COLOR: Color = const {
+    Color::Rgb {
+        red: 31,
+        green: 31,
+        blue: 31,
+    }
+};
+
+const MARGIN: f64 = 12.0;
+const SPACING: f64 = 8.0;
+const RADIUS: f64 = 12.0;
+const MIN_WIDTH: f64 = 100.0;
+const MIN_HEIGHT: f64 = 50.0;
+/// Space between a composite state and its substates.
+const PADDING: f64 = 20.0;
+const HIT_DISTANCE: f64 = 6.0;
+
+/// The behaviors a state can perform, each shown on a line of its own.
+const BEHAVIORS: [&str; 3] = ["entry", "do", "exit"];
+
+/// A state of a state machine, drawn as a rounded rectangle with the name on
+/// top and the entry, do and exit behaviors below it. Composite states
+/// contain substates; they grow to enclose them and move them along.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct State {
+    x: f64,
+    y: f64,
+    title: Label,
+    /// The `entry`, `do` and `exit` behaviors, in that order.
+    #[serde(default)]
+    behaviors: Box<[Option<Label>; 3]>,
+    #[serde(default)]
+    substates: Vec<Id>,
+
+    /// The bounds of the substates.
+    #[serde(skip)]
+    contents: Option<Rect>,
+    #[serde(skip)]
+    interaction_state: InteractionState,
+}
+
+impl State {
+    pub fn new(x: f64, y: f64, name: String) -> Self {
+        Self {
+            x,
+            y,
+            title: Label::new(
+                0.0,
+                0.0,
+                name,
+                TextProperties::default().weight(700),
+                TEXT_COLOR,
+            ),
+            behaviors: Default::default(),
+            substates: vec![],
+            contents: None,
+            interaction_state: InteractionState::default(),
+        }
+    }
+
+    pub fn name(&self) -> &str {
+        self.title.text()
+    }
+
+    pub fn set_name(&mut self, value: String) {
+        self.title.set_text(value);
+    }
+
+    /// The behavior performed when entering the state.
+    pub fn entry(&self) -> Option<&str> {
+        self.behavior(0)
+    }
+
+    /// The behavior performed while in the state.
+    pub fn do_activity(&self) -> Option<&str> {
+        self.behavior(1)
+    }
+
+    /// The behavior performed when leaving the state.
+    pub fn exit(&self) -> Option<&str> {
+        self.behavior(2)
+    }
+
+    pub fn set_entry(&mut self, value: Option<String>) {
+        self.set_behavior(0, value);
+    }
+
+    pub fn set_do_activity(&mut self, value: Option<String>) {
+        self.set_behavior(1, value);
+    }
+
+    pub fn set_exit(&mut self, value: Option<String>) {
+        self.set_behavior(2, value);
+    }
+
+    fn behavior(&self, index: usize) -> Option<&str> {
+        let label = self.behaviors[index].as_ref()?;
+        let prefix = format!("{} / ", BEHAVIORS[index]);
+        label.text().strip_prefix(&prefix)
+    }
+
+    fn set_behavior(&mut self, index: usize, value: Option<String>) {
+        self.behaviors[index] = value.map(|value| {
+            Label::new(
+                0.0,
+                0.0,
+                format!("{} / {value}", BEHAVIORS[index]),
+                TextProperties::default(),
+                TEXT_COLOR,
+            )
+        });
+    }
+
+    /// The states nested in this one.
+    pub fn substates(&self) -> &[Id] {
+        &self.substates
+    }
+
+    pub fn add_substate(&mut self, id: Id) {
+        if !self.substates.contains(&id) {
+            self.substates.push(id);
+        }
+    }
+
+    pub fn remove_substate(&mut self, id: Id) {
+        self.substates.retain(|s| *s != id);
+    }
+
+    pub fn is_composite(&self) -> bool {
+        !self.substates.is_empty()
+    }
+
+    pub fn x(&self) -> f64 {
+        self.bounds().left()
+    }
+
+    pub fn y(&self) -> f64 {
+        self.y
+    }
+
+    /// The width of the name and behaviors, without any substates.
+    fn own_width(&self) -> f64 {
+        let width = self
+            .labels()
+            .map(|l| l.width().unwrap_or(0.0) + 2.0 * MARGIN)
+            .fold(0.0, f64::max);
+        f64::max(width, MIN_WIDTH)
+    }
+
+    /// The height of the name and behaviors, without any substates.
+    fn own_height(&self) -> f64 {
+        let mut height = self.title.height().unwrap_or(0.0) + 2.0 * MARGIN;
+
+        if self.behaviors.iter().any(Option::is_some) {
+            height += MARGIN;
+        }
+
+        for label in self.behaviors.iter().flatten() {
+            height += label.height().unwrap_or(0.0) + SPACING;
+        }
+
+        f64::max(height, MIN_HEIGHT)
+    }
+
+    /// Grows the state to enclose `contents`, the bounds of its substates.
+    pub fn attach(&mut self, contents: Option<Rect>) {
+        self.contents = contents;
+    }
+
+    /// Composite states can only be grabbed by their border and the name and
+    /// behaviors on top, so the substates inside stay selectable.
+    pub fn cursor_intersects(&self, x: f64, y: f64) -> bool {
+        let bounds = self.bounds();
+        let cursor = Point::new(x, y);
+
+        if !self.is_composite() {
+            return bounds.contains(cursor);
+        }
+
+        let own = Rect::new(
+            bounds.left(),
+            bounds.top(),
+            bounds.width(),
+            self.own_height(),
+        );
+
+        own.contains(cursor)
+            || bounds.distance_to_border(cursor) <= HIT_DISTANCE
+    }
+
+    fn labels(&self) -> impl Iterator<Item = &Label> {
+        std::iter::once(&self.title).chain(self.behaviors.iter().flatten())
+    }
+}
+
+impl Drawable for State {
+    fn initalize(&mut self, canvas: &impl Canvas) {
+        self.title.initalize(canvas);
+        self.title.set_position(self.x + MARGIN, self.y + MARGIN);
+
+        let mut offset_y = self.title.y() + (MARGIN + SPACING);
+
+        for label in self.behaviors.iter_mut().flatten() {
+            label.initalize(canvas);
+            label.set_position(self.x + MARGIN, offset_y);
+            offset_y += label.height().unwrap_or(0.0) + SPACING;
+        }
+    }
+
+    fn draw(&self, canvas: &impl Canvas, camera: &Camera) {
+        let stroke = if self.is_highlighted() {
+            HIGHLIGHT_STROKE
+        } else {
+            DEFAULT_STROKE
+        };
+
+        let (l, t, r, b) = self.bounds().edges();
+
+        Rectangle::new(
+            l,
+            t,
+            r - l,
+            b - t,
+            DEFAULT_COLOR,
+            Some(RADIUS),
+            Some(stroke),
+        )
+        .draw(canvas, camera);
+
+        // The behaviors, like the substates, are separated from the name.
+        if self.behaviors.iter().any(Option::is_some) || self.is_composite() {
+            let y = self.title.y() + MARGIN;
+            Path::line((l, y), (r, y), stroke).draw(canvas, camera);
+        }
+
+        for label in self.labels() {
+            label.draw(canvas, camera);
+        }
+    }
+
+    /// The bounds of the state, including the substates it encloses.
+    fn bounds(&self) -> Rect {
+        let own =
+            Rect::new(self.x, self.y, self.own_width(), self.own_height());
+
+        match self.contents {
+            Some(contents) => Rect::from_edges(
+                f64::min(own.left(), contents.left() - PADDING),
+                own.top(),
+                f64::max(own.right(), contents.right() + PADDING),
+                f64::max(own.bottom(), contents.bottom() + PADDING),
+            ),
+            None => own,
+        }
+    }
+}
+
+impl Interactive for State {
+    fn get_interaction(&self) -> InteractionState {
+        self.interaction_state
+    }
+
+    fn get_interaction_mut(&mut self) -> &mut InteractionState {
+        &mut self.interaction_state
+    }
+
+    /// Moves the state itself. Its substates are separate elements, which
+    /// `Document::move_element` moves along with it.
+    fn adjust_position(&mut self, delta_x: f64, delta_y: f64) {
+        self.x += delta_x;
+        self.y += delta_y;
+
+        if let Some(contents) = &mut self.contents {
+            *contents = contents.translate(delta_x, delta_y);
+        }
+
+        for label in std::iter::once(&mut self.title)
+            .chain(self.behaviors.iter_mut().flatten())
+        {
+            label.adjust_position(delta_x, delta_y);
+        }
+    }
+
+    #[allow(unused_variables)]
+    fn click(&mut self, x: f64, y: f64) -> Option<Prompt> {
+        Some(Prompt::Text {
+            explanation: "Provide this state with a new name".into(),
+            placeholder: "State name".into(),
+            value: self.title.text().to_string(),
+            metadata: Rc::new(()),
+        })
+    }
+
+    fn prompt(&mut self, response: PromptResponse) {
+        let PromptResponse::Text {
+            response,
+            metadata: _,
+        } = response;
+
+        if response.is_empty() {
+            return;
+        }
+
+        self.title.set_text(response);
+    }
+}
@@ -0,0 +1,255 @@
+use std::rc::Rc;
+
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    camera::Camera,
+    canvas::Canvas,
+    color::Color,
+    drawable::Drawable,
+    geometry::{Point, Rect},
+    id::Id,
+    interaction::{InteractionState, Interactive},
+    prompt::{Prompt, PromptResponse},
+    stroke::Stroke,
+};
+
+use super::{Label, Path, Rectangle, TextProperties};
+
+const DEFAULT_COLOR: Color = const {
+    Color::Rgb {
+        red: 252,
+        green: 252,
+        blue: 252,
+    }
+};
+
+const DEFAULT_STROKE: Stroke = const {
+    Stroke::new(
+        2,
+        Color::Rgb {
+            red: 90,
+            green: 90,
+            blue: 90,
+        },
+    )
+};
+
+const HIGHLIGHT_STROKE: Stroke = const {
+    Stroke::new(
+        2,
+        Color::Rgb {
+            red: 142,
+            green: 202,
+            blue: 230,
+        },
+    )
+};
+
+const TEXT_COLOR: Color = const {
+    Color::Rgb {
+        red: 31,
+        green: 31,
+        blue: 31,
+    }
+};
+
+const MARGIN: f64 = 12.0;
+/// Space between the border of a swimlane and the nodes in it.
+const PADDING: f64 = 20.0;
+const HIT_DISTANCE: f64 = 6.0;
+
+/// A partition of an activity diagram: a column titled with who performs
+/// the nodes in it. Swimlanes grow to enclose their nodes and move them
+/// along. Only the border and the title can be grabbed, so the nodes inside
+/// stay selectable.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Swimlane {
+    x: f64,
+    y: f64,
+    width: f64,
+    height: f64,
+    title: Label,
+    #[serde(default)]
+    nodes: Vec<Id>,
+
+    /// The bounds of the nodes.
+    #[serde(skip)]
+    contents: Option<Rect>,
+    #[serde(skip)]
+    interaction_state: InteractionState,
+}
+
+impl Swimlane {
+    pub fn new(x: f64, y: f64, width: f64, height: f64, name: String) -> Self {
+        Self {
+            x,
+            y,
+            width,
+            height,
+            title: Label::new(
+                0.0,
+                0.0,
+                name,
+                TextProperties::default().weight(700),
+                TEXT_COLOR,
+            ),
+            nodes: vec![],
+            contents: None,
+            interaction_state: InteractionState::default(),
+        }
+    }
+
+    pub fn name(&self) -> &str {
+        self.title.text()
+    }
+
+    pub fn set_name(&mut self, value: String) {
+        self.title.set_text(value);
+    }
+
+    /// The nodes performed within this partition.
+    pub fn nodes(&self) -> &[Id] {
+        &self.nodes
+    }
+
+    pub fn add_node(&mut self, id: Id) {
+        if !self.nodes.contains(&id) {
+            self.nodes.push(id);
+        }
+    }
+
+    pub fn remove_node(&mut self, id: Id) {
+        self.nodes.retain(|n| *n != id);
+    }
+
+    pub fn x(&self) -> f64 {
+        self.bounds().left()
+    }
+
+    pub fn y(&self) -> f64 {
+        self.y
+    }
+
+    pub fn set_size(&mut self, width: f64, height: f64) {
+        self.width = width;
+        self.height = height;
+    }
+
+    /// Grows the swimlane to enclose `contents`, the bounds of its nodes.
+    pub fn attach(&mut self, contents: Option<Rect>) {
+        self.contents = contents;
+        self.place_title();
+    }
+
+    /// Centers the title over the whole lane.
+    fn place_title(&mut self) {
+        let (l, t, r, _) = self.bounds().edges();
+        let offset = ((r - l) - self.title.width().unwrap_or(0.0)) / 2.0;
+        self.title.set_position(l + offset, t + MARGIN);
+    }
+
+    fn header_height(&self) -> f64 {
+        self.title.height().unwrap_or(0.0) + 2.0 * MARGIN
+    }
+
+    pub fn cursor_intersects(&self, x: f64, y: f64) -> bool {
+        let bounds = self.bounds();
+        let cursor = Point::new(x, y);
+        let header = Rect::new(
+            bounds.left(),
+            bounds.top(),
+            bounds.width(),
+            self.header_height(),
+        );
+
+        header.contains(cursor)
+            || bounds.distance_to_border(cursor) <= HIT_DISTANCE
+    }
+}
+
+impl Drawable for Swimlane {
+    fn initalize(&mut self, canvas: &impl Canvas) {
+        self.title.initalize(canvas);
+        self.place_title();
+    }
+
+    fn draw(&self, canvas: &impl Canvas, camera: &Camera) {
+        let stroke = if self.is_highlighted() {
+            HIGHLIGHT_STROKE
+        } else {
+            DEFAULT_STROKE
+        };
+
+        let (l, t, r, b) = self.bounds().edges();
+
+        Rectangle::new(l, t, r - l, b - t, DEFAULT_COLOR, None, Some(stroke))
+            .draw(canvas, camera);
+
+        let header = t + self.header_height();
+        Path::line((l, header), (r, header), stroke).draw(canvas, camera);
+
+        self.title.draw(canvas, camera);
+    }
+
+    /// The bounds of the swimlane, including the nodes it encloses.
+    fn bounds(&self) -> Rect {
+        let own = Rect::new(self.x, self.y, self.width, self.height);
+
+        match self.contents {
+            Some(contents) => Rect::from_edges(
+                f64::min(own.left(), contents.left() - PADDING),
+                own.top(),
+                f64::max(own.right(), contents.right() + PADDING),
+                f64::max(own.bottom(), contents.bottom() + PADDING),
+            ),
+            None => own,
+        }
+    }
+}
+
+impl Interactive for Swimlane {
+    fn get_interaction(&self) -> InteractionState {
+        self.interaction_state
+    }
+
+    fn get_interaction_mut(&mut self) -> &mut InteractionState {
+        &mut self.interaction_state
+    }
+
+    /// Moves the swimlane itself. Its nodes are separate elements, which
+    /// `Document::move_element` moves along with it.
+    fn adjust_position(&mut self, delta_x: f64, delta_y: f64) {
+        self.x += delta_x;
+        self.y += delta_y;
+
+        if let Some(contents) = &mut self.contents {
+            *contents = contents.translate(delta_x, delta_y);
+        }
+
+        self.title.adjust_position(delta_x, delta_y);
+    }
+
+    #[allow(unused_variables)]
+    fn click(&mut self, x: f64, y: f64) -> Option<Prompt> {
+        Some(Prompt::Text {
+            explanation: "Provide this swimlane with a new name".into(),
+            placeholder: "Swimlane name".into(),
+            value: self.title.text().to_string(),
+            metadata: Rc::new(()),
+        })
+    }
+
+    fn prompt(&mut self, response: PromptResponse) {
+        let PromptResponse::Text {
+            response,
+            metadata: _,
+        } = response;
+
+        if response.is_empty() {
+            return;
+        }
+
+        self.title.set_text(response);
+    }
+}
@@ -21,6 +21,18 @@ impl TextProperties {
         format!("{} {}px {}", weight, self.size, self.font)
     }
 
+    pub fn size(&self) -> f32 {
+        self.size
+    }
+
+    pub fn font(&self) -> &str {
+        &self.font
+    }
+
+    pub fn font_weight(&self) -> u32 {
+        self.weight.unwrap_or(400)
+    }
+
     pub fn weight(mut self, value: u32) -> Self {
         self.weight = Some(value);
         self
@@ -0,0 +1,193 @@
+use std::{f64::consts::SQRT_2, rc::Rc};
+
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    camera::Camera,
+    canvas::Canvas,
+    color::Color,
+    drawable::Drawable,
+    geometry::Rect,
+    interaction::{InteractionState, Interactive},
+    prompt::{Prompt, PromptResponse},
+    stroke::Stroke,
+};
+
+use super::{Label, Path, TextProperties};
+
+const DEFAULT_COLOR: Color = const {
+    Color::Rgb {
+        red: 244,
+        green: 244,
+        blue: 244,
+    }
+};
+
+const DEFAULT_STROKE: Stroke = const {
+    Stroke::new(
+        2,
+        Color::Rgb {
+            red: 90,
+            green: 90,
+            blue: 90,
+        },
+    )
+};
+
+const HIGHLIGHT_STROKE: Stroke = const {
+    Stroke::new(
+        2,
+        Color::Rgb {
+            red: 142,
+            green: 202,
+            blue: 230,
+        },
+    )
+};
+
+const TEXT_COLOR: Color = const {
+    Color::Rgb {
+        red: 31,
+        green: 31,
+        blue: 31,
+    }
+};
+
+const MIN_WIDTH: f64 = 120.0;
+const MIN_HEIGHT: f64 = 50.0;
+const PADDING: f64 = 10.0;
+
+/// Something the system does for its actors, drawn as an ellipse with the
+/// name in the middle.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct UseCase {
+    x: f64,
+    y: f64,
+    title: Label,
+
+    #[serde(skip)]
+    interaction_state: InteractionState,
+}
+
+impl UseCase {
+    pub fn new(x: f64, y: f64, name: String) -> Self {
+        Self {
+            x,
+            y,
+            title: Label::new(
+                0.0,
+                0.0,
+                name,
+                TextProperties::default(),
+                TEXT_COLOR,
+            ),
+            interaction_state: InteractionState::default(),
+        }
+    }
+
+    pub fn name(&self) -> &str {
+        self.title.text()
+    }
+
+    pub fn set_name(&mut self, value: String) {
+        self.title.set_text(value);
+    }
+
+    pub fn x(&self) -> f64 {
+        self.x
+    }
+
+    pub fn y(&self) -> f64 {
+        self.y
+    }
+
+    /// The width of the ellipse. The text fits in the rectangle inscribed in
+    /// the ellipse, which is `1 / √2` as wide.
+    pub fn width(&self) -> f64 {
+        let text = self.title.width().unwrap_or(0.0);
+        f64::max((text * SQRT_2) + PADDING, MIN_WIDTH)
+    }
+
+    pub fn height(&self) -> f64 {
+        let text = self.title.height().unwrap_or(0.0);
+        f64::max((text * SQRT_2) + PADDING, MIN_HEIGHT)
+    }
+
+    /// Whether the point lies within the ellipse.
+    pub fn cursor_intersects(&self, x: f64, y: f64) -> bool {
+        let (rx, ry) = (self.width() / 2.0, self.height() / 2.0);
+        let dx = (x - self.x) - rx;
+        let dy = (y - self.y) - ry;
+
+        (dx / rx).powi(2) + (dy / ry).powi(2) <= 1.0
+    }
+}
+
+impl Drawable for UseCase {
+    fn initalize(&mut self, canvas: &impl Canvas) {
+        self.title.initalize(canvas);
+
+        let offset_x = (self.width() - self.title.width().unwrap_or(0.0)) / 2.0;
+        let offset_y =
+            (self.height() - self.title.height().unwrap_or(0.0)) / 2.0;
+        self.title
+            .set_position(self.x + offset_x, self.y + offset_y);
+    }
+
+    fn draw(&self, canvas: &impl Canvas, camera: &Camera) {
+        let stroke = if self.is_highlighted() {
+            HIGHLIGHT_STROKE
+        } else {
+            DEFAULT_STROKE
+        };
+
+        Path::ellipse(self.bounds(), Some(stroke))
+            .closed(Some(DEFAULT_COLOR))
+            .draw(canvas, camera);
+
+        self.title.draw(canvas, camera);
+    }
+
+    fn bounds(&self) -> Rect {
+        Rect::new(self.x, self.y, self.width(), self.height())
+    }
+}
+
+impl Interactive for UseCase {
+    fn get_interaction(&self) -> InteractionState {
+        self.interaction_state
+    }
+
+    fn get_interaction_mut(&mut self) -> &mut InteractionState {
+        &mut self.interaction_state
+    }
+
+    fn adjust_position(&mut self, delta_x: f64, delta_y: f64) {
+        self.x += delta_x;
+        self.y += delta_y;
+        self.title.adjust_position(delta_x, delta_y);
+    }
+
+    #[allow(unused_variables)]
+    fn click(&mut self, x: f64, y: f64) -> Option<Prompt> {
+        Some(Prompt::Text {
+            explanation: "Provide this use case with a new name".into(),
+            placeholder: "Use case name".into(),
+            value: self.title.text().to_string(),
+            metadata: Rc::new(()),
+        })
+    }
+
+    fn prompt(&mut self, response: PromptResponse) {
+        let PromptResponse::Text {
+            response,
+            metadata: _,
+        } = response;
+
+        if response.is_empty() {
+            return;
+        }
+
+        self.title.set_text(response);
+    }
+}
@@ -0,0 +1,186 @@
+//! Export of documents as Graphviz DOT graphs.
+
+use crate::{
+    document::Document,
+    drawable::Drawable,
+    elements::{Element, Relationship, RelationshipKind},
+    id::Id,
+    layout,
+};
+
+const INDENT: &str = "    ";
+
+/// Writes a document as a directed DOT graph. Classes become `record` nodes
+/// with a field per compartment, and relationships become edges whose
+/// arrowheads follow the UML notation.
+///
+/// With `keep_positions`, every node gets a pinned `pos` attribute holding
+/// its current center, so `neato -n` renders the diagram as laid out here
+/// instead of computing a new layout.
+pub fn export(document: &Document, keep_positions: bool) -> String {
+    let mut output = String::from("digraph {\n");
+    output += &format!("{INDENT}graph [rankdir=BT];\n");
+    output += &format!("{INDENT}node [fontname=\"Arial\", fontsize=12];\n");
+    output += &format!("{INDENT}edge [fontname=\"Arial\", fontsize=10];\n");
+
+    let nodes = document
+        .elements()
+        .iter()
+        .filter(|el| el.as_relationship().is_none())
+        .collect::<Vec<_>>();
+
+    if !nodes.is_empty() {
+        output += "\n";
+    }
+
+    for el in &nodes {
+        let mut attributes = node_attributes(el);
+
+        if keep_positions {
+            let (l, t, r, b) = el.bounds().edges();
+            let (width, height) = match (r - l, b - t) {
+                (0.0, _) | (_, 0.0) => layout::estimated_size(el),
+                (width, height) => (width, height),
+            };
+            let x = el.x() + width / 2.0;
+            let y = el.y() + height / 2.0;
+
+            // Graphviz places the origin at the bottom left, so flip the y
+            // axis to keep the diagram the right way up.
+            attributes.push(format!("pos=\"{x},{}!\"", -y));
+        }
+
+        output += &format!(
+            "{INDENT}{} [{}];\n",
+            node_id(el.id()),
+            attributes.join(", ")
+        );
+    }
+
+    let edges = document
+        .elements()
+        .iter()
+        .filter_map(|el| el.as_relationship())
+        .collect::<Vec<_>>();
+
+    if !edges.is_empty() {
+        output += "\n";
+    }
+
+    for relationship in edges {
+        output += &format!(
+            "{INDENT}{} -> {} [{}];\n",
+            node_id(relationship.source()),
+            node_id(relationship.target()),
+            edge_attributes(relationship).join(", ")
+        );
+    }
+
+    output + "}\n"
+}
+
+fn node_attributes(el: &Element) -> Vec<String> {
+    if let Some(class) = el.as_class() {
+        let mut title = escape_record(class.name());
+
+        if let Some(stereotype) = class.stereotype() {
+            title = format!("«{}»\\n{title}", escape_record(stereotype));
+        }
+
+        let compartment = |lines: Vec<&str>| {
+            lines
+                .into_iter()
+                .map(|line| format!("{}\\l", escape_record(line)))
+                .collect::<String>()
+        };
+
+        let label = format!(
+            "{{{title}|{}|{}}}",
+            compartment(class.attributes().collect()),
+            compartment(class.operations().collect())
+        );
+        return vec![
+            String::from("shape=record"),
+            format!("label=\"{label}\""),
+        ];
+    }
+
+    if let Some(label) = el.as_label() {
+        return vec![
+            String::from("shape=plaintext"),
+            format!("label=\"{}\"", escape(label.text())),
+        ];
+    }
+
+    vec![String::from("shape=box"), String::from("label=\"\"")]
+}
+
+fn edge_attributes(relationship: &Relationship) -> Vec<String> {
+    let arrowhead = match relationship.kind() {
+        RelationshipKind::Association
+        | RelationshipKind::Transition
+        | RelationshipKind::ControlFlow
+        | RelationshipKind::Dependency
+        | RelationshipKind::Include
+        | RelationshipKind::Extend
+        | RelationshipKind::Import
+        | RelationshipKind::Merge
+        | RelationshipKind::Uses => "vee",
+        RelationshipKind::Link
+        | RelationshipKind::DashedLink
+        | RelationshipKind::CommunicationPath
+        | RelationshipKind::EntityRelationship => "none",
+        RelationshipKind::Generalization | RelationshipKind::Realization => {
+            "empty"
+        }
+        RelationshipKind::Aggregation => "odiamond",
+        RelationshipKind::Composition => "diamond",
+    };
+
+    let mut attributes = vec![format!("arrowhead={arrowhead}")];
+
+    if relationship.kind().is_dashed() {
+        attributes.push(String::from("style=dashed"));
+    }
+
+    let caption = relationship.caption();
+
+    for (name, value) in [
+        ("label", caption.as_deref()),
+        ("taillabel", relationship.source_multiplicity()),
+        ("headlabel", relationship.target_multiplicity()),
+    ] {
+        if let Some(value) = value {
+            attributes.push(format!("{name}=\"{}\"", escape(value)));
+        }
+    }
+
+    attributes
+}
+
+fn node_id(id: Id) -> String {
+    format!("n{id}")
+}
+
+/// Escapes text for a quoted DOT string.
+fn escape(text: &str) -> String {
+    text.replace('\\', "\\\\")
+        .replace('"', "\\\"")
+        .replace('\n', "\\n")
+}
+
+/// Escapes text for a field of a record label, in which braces, bars and
+/// angle brackets have a meaning of their own.
+fn escape_record(text: &str) -> String {
+    let mut output = String::new();
+
+    for c in escape(text).chars() {
+        if matches!(c, '{' | '}' | '|' | '<' | '>') {
+            output.push('\\');
+        }
+
+        output.push(c);
+    }
+
+    output
+}
@@ -0,0 +1,865 @@
+//! Conversion between documents and draw.io (diagrams.net) files, which store
+//! diagrams as mxGraph XML. UML class shapes, text and edges map onto their
+//! counterparts; any other shape is approximated by a rectangle.
+
+use std::collections::HashMap;
+
+use base64::Engine;
+use roxmltree::Node;
+
+use crate::{
+    color::{BLACK, Color, WHITE},
+    document::Document,
+    drawable::Drawable,
+    elements::{
+        Cardinality, Class, Element, Label, Rectangle, Relationship,
+        RelationshipKind, TextProperties,
+    },
+    id::Id,
+    layout,
+    member::Member,
+    stroke::Stroke,
+};
+
+use super::{ParseError, Writer, escape_xml};
+
+const HEADER: &str = r#"<mxfile host="uml">
+  <diagram id="diagram" name="Page-1">
+    <mxGraphModel grid="1" gridSize="10" guides="1" connect="1" arrows="1" page="0">
+      <root>
+        <mxCell id="0"/>
+        <mxCell id="1" parent="0"/>
+"#;
+
+const FOOTER: &str = r#"      </root>
+    </mxGraphModel>
+  </diagram>
+</mxfile>
+"#;
+
+const CLASS_STYLE: &str = "swimlane;fontStyle=1;align=center;verticalAlign=top;childLayout=stackLayout;horizontal=1;horizontalStack=0;resizeParent=1;resizeParentMax=0;resizeLast=0;collapsible=1;marginBottom=0;";
+const ROW_STYLE: &str = "text;strokeColor=none;fillColor=none;align=left;verticalAlign=top;spacingLeft=4;spacingRight=4;overflow=hidden;rotatable=0;points=[[0,0.5],[1,0.5]];portConstraint=eastwest;";
+const SEPARATOR_STYLE: &str = "line;strokeWidth=1;fillColor=none;align=left;verticalAlign=middle;spacingTop=-1;spacingLeft=3;spacingRight=3;rotatable=0;labelPosition=right;points=[];portConstraint=eastwest;";
+const MULTIPLICITY_STYLE: &str =
+    "edgeLabel;resizable=0;align=left;verticalAlign=bottom;";
+
+/// The tags found in the HTML labels of draw.io.
+const HTML_TAGS: [&str; 16] = [
+    "b", "br", "div", "em", "font", "hr", "i", "li", "ol", "p", "span",
+    "strike", "strong", "sub", "sup", "u",
+];
+
+/// A horizontal rule in an HTML label, as converted by [`plain_text`].
+const RULE: &str = "---";
+
+const HEADER_HEIGHT: f64 = 26.0;
+const STEREOTYPE_HEADER_HEIGHT: f64 = 40.0;
+const ROW_HEIGHT: f64 = 26.0;
+const SEPARATOR_HEIGHT: f64 = 8.0;
+const TEXT_PADDING: f64 = 10.0;
+const ROUNDED_RADIUS: f64 = 10.0;
+/// The most a compressed page may inflate to, so that a small file cannot
+/// exhaust memory.
+const MAX_INFLATED_SIZE: usize = 16 * 1024 * 1024;
+
+/// Writes a document as an uncompressed draw.io file. Classes become UML
+/// class shapes with a row per attribute and operation.
+pub fn export(document: &Document) -> String {
+    let mut w = Writer::new(HEADER, 4);
+
+    for el in document.elements() {
+        if let Some(class) = el.as_class() {
+            write_class(&mut w, el, class);
+        } else if let Some(relationship) = el.as_relationship() {
+            write_edge(&mut w, el.id(), relationship);
+        } else if let Some(rectangle) = el.as_rectangle() {
+            let rounded = rectangle.radius().is_some_and(|r| r > 0.0) as u8;
+            let stroke = match rectangle.stroke() {
+                Some(stroke) => format!(
+                    "strokeColor={};strokeWidth={};",
+                    hex(stroke.color()),
+                    stroke.width()
+                ),
+                None => String::from("strokeColor=none;"),
+            };
+
+            write_vertex(
+                &mut w,
+                &cell_id(el.id()),
+                "",
+                &format!(
+                    "rounded={rounded};whiteSpace=wrap;fillColor={};{stroke}",
+                    hex(rectangle.color())
+                ),
+                "1",
+                (rectangle.x(), rectangle.y()),
+                (rectangle.width(), rectangle.height()),
+            );
+        } else if let Some(label) = el.as_label() {
+            let bold = (label.props().font_weight() >= 700) as u8;
+            let (width, height) = layout::estimated_size(el);
+
+            // Labels are positioned by their baseline, draw.io text by its
+            // top left corner.
+            write_vertex(
+                &mut w,
+                &cell_id(el.id()),
+                label.text(),
+                &format!(
+                    "text;align=left;verticalAlign=top;fontSize={};fontStyle={bold};fontColor={};",
+                    label.props().size(),
+                    hex(label.color())
+                ),
+                "1",
+                (label.x(), label.y() - label.height().unwrap_or(0.0)),
+                (width, height),
+            );
+        }
+    }
+
+    w.finish(FOOTER)
+}
+
+fn write_class(w: &mut Writer, el: &Element, class: &Class) {
+    let id = cell_id(el.id());
+    let attributes = class.attributes().collect::<Vec<_>>();
+    let operations = class.operations().collect::<Vec<_>>();
+
+    let (name, header) = match class.stereotype() {
+        Some(stereotype) => (
+            format!("<<{stereotype}>>\n{}", class.name()),
+            STEREOTYPE_HEADER_HEIGHT,
+        ),
+        None => (class.name().to_string(), HEADER_HEIGHT),
+    };
+
+    let rows = (attributes.len() + operations.len()) as f64;
+    let height = header + rows * ROW_HEIGHT + SEPARATOR_HEIGHT;
+    let (l, _, r, _) = el.bounds().edges();
+    let width = match r - l {
+        0.0 => layout::estimated_size(el).0,
+        width => width,
+    };
+
+    w.open(&format!(
+        r#"<mxCell id="{id}" value="{}" style="{CLASS_STYLE}startSize={header};fillColor={};" vertex="1" parent="1">"#,
+        escape_value(&name),
+        hex(class.color())
+    ));
+    w.line(&format!(
+        r#"<mxGeometry x="{}" y="{}" width="{width}" height="{height}" as="geometry"/>"#,
+        class.x(),
+        class.y()
+    ));
+    w.close("</mxCell>");
+
+    let mut y = header;
+
+    for (index, attribute) in attributes.iter().enumerate() {
+        let row = format!("{id}_a{index}");
+        write_vertex(
+            w,
+            &row,
+            attribute,
+            ROW_STYLE,
+            &id,
+            (0.0, y),
+            (width, ROW_HEIGHT),
+        );
+        y += ROW_HEIGHT;
+    }
+
+    write_vertex(
+        w,
+        &format!("{id}_separator"),
+        "",
+        SEPARATOR_STYLE,
+        &id,
+        (0.0, y),
+        (width, SEPARATOR_HEIGHT),
+    );
+    y += SEPARATOR_HEIGHT;
+
+    for (index, operation) in operations.iter().enumerate() {
+        let row = format!("{id}_o{index}");
+        write_vertex(
+            w,
+            &row,
+            operation,
+            ROW_STYLE,
+            &id,
+            (0.0, y),
+            (width, ROW_HEIGHT),
+        );
+        y += ROW_HEIGHT;
+    }
+}
+
+fn write_vertex(
+    w: &mut Writer,
+    id: &str,
+    value: &str,
+    style: &str,
+    parent: &str,
+    (x, y): (f64, f64),
+    (width, height): (f64, f64),
+) {
+    w.open(&format!(
+        r#"<mxCell id="{id}" value="{}" style="{style}" vertex="1" parent="{parent}">"#,
+        escape_value(value)
+    ));
+    w.line(&format!(
+        r#"<mxGeometry x="{x}" y="{y}" width="{width}" height="{height}" as="geometry"/>"#
+    ));
+    w.close("</mxCell>");
+}
+
+fn write_edge(w: &mut Writer, id: Id, relationship: &Relationship) {
+    let crows_feet;
+    let style = match relationship.kind() {
+        RelationshipKind::Association
+        | RelationshipKind::Transition
+        | RelationshipKind::ControlFlow => "endArrow=open;endFill=0;",
+        RelationshipKind::Link | RelationshipKind::CommunicationPath => {
+            "endArrow=none;"
+        }
+        RelationshipKind::Generalization => {
+            "endArrow=block;endFill=0;endSize=12;"
+        }
+        RelationshipKind::Realization => {
+            "endArrow=block;endFill=0;endSize=12;dashed=1;"
+        }
+        RelationshipKind::Aggregation => {
+            "endArrow=diamondThin;endFill=0;endSize=14;"
+        }
+        RelationshipKind::Composition => {
+            "endArrow=diamondThin;endFill=1;endSize=14;"
+        }
+        RelationshipKind::Dependency
+        | RelationshipKind::Include
+        | RelationshipKind::Extend
+        | RelationshipKind::Import
+        | RelationshipKind::Merge
+        | RelationshipKind::Uses => "endArrow=open;endFill=0;dashed=1;",
+        RelationshipKind::DashedLink => "endArrow=none;dashed=1;",
+        RelationshipKind::EntityRelationship => {
+            let arrow = |cardinality: Option<Cardinality>| {
+                cardinality.map_or("none", er_arrow)
+            };
+            crows_feet = format!(
+                "startArrow={};endArrow={};startFill=0;endFill=0;",
+                arrow(relationship.source_cardinality()),
+                arrow(relationship.target_cardinality())
+            );
+            &crows_feet
+        }
+    };
+    let id = cell_id(id);
+
+    w.open(&format!(
+        r#"<mxCell id="{id}" value="{}" style="edgeStyle=none;rounded=0;{style}" edge="1" parent="1" source="{}" target="{}">"#,
+        escape_value(relationship.label().unwrap_or_default()),
+        cell_id(relationship.source()),
+        cell_id(relationship.target())
+    ));
+    w.line(r#"<mxGeometry relative="1" as="geometry"/>"#);
+    w.close("</mxCell>");
+
+    for (end, x, multiplicity) in [
+        ("source", -1, relationship.source_multiplicity()),
+        ("target", 1, relationship.target_multiplicity()),
+    ] {
+        let Some(multiplicity) = multiplicity else {
+            continue;
+        };
+
+        // Cardinalities of entity relationships are drawn by the arrows.
+        if relationship.kind() == RelationshipKind::EntityRelationship
+            && Cardinality::from_multiplicity(multiplicity).is_some()
+        {
+            continue;
+        }
+
+        w.open(&format!(
+            r#"<mxCell id="{id}_{end}" value="{}" style="{MULTIPLICITY_STYLE}" vertex="1" connectable="0" parent="{id}">"#,
+            escape_value(multiplicity)
+        ));
+        w.open(&format!(
+            r#"<mxGeometry x="{x}" relative="1" as="geometry">"#
+        ));
+        w.line(r#"<mxPoint as="offset"/>"#);
+        w.close("</mxGeometry>");
+        w.close("</mxCell>");
+    }
+}
+
+fn cell_id(id: Id) -> String {
+    format!("id{id}")
+}
+
+/// Escapes a value for an attribute, keeping line breaks, which XML would
+/// otherwise normalize to spaces.
+fn escape_value(text: &str) -> String {
+    escape_xml(text).replace('\n', "&#xa;")
+}
+
+fn hex(color: Color) -> String {
+    match color {
+        Color::Rgb { red, green, blue } => {
+            format!("#{red:02x}{green:02x}{blue:02x}")
+        }
+    }
+}
+
+fn parse_hex(value: &str) -> Option<Color> {
+    let value = value.strip_prefix('#')?;
+    let channel = |i: usize| {
+        value
+            .get(i..i + 2)
+            .and_then(|c| u8::from_str_radix(c, 16).ok())
+    };
+
+    match value.len() {
+        6 => Some(Color::Rgb {
+            red: channel(0)?,
+            green: channel(2)?,
+            blue: channel(4)?,
+        }),
+        _ => None,
+    }
+}
+
+/// An mxGraph style such as `text;html=1;fontSize=12;`. Entries without a
+/// value name the base style (e.g. `text`, `swimlane`).
+struct Style<'a>(Vec<(&'a str, Option<&'a str>)>);
+
+impl<'a> Style<'a> {
+    fn parse(style: &'a str) -> Self {
+        Self(
+            style
+                .split(';')
+                .filter(|entry| !entry.is_empty())
+                .map(|entry| match entry.split_once('=') {
+                    Some((key, value)) => (key, Some(value)),
+                    None => (entry, None),
+                })
+                .collect(),
+        )
+    }
+
+    fn get(&self, key: &str) -> Option<&'a str> {
+        self.0.iter().find(|(k, _)| *k == key).and_then(|(_, v)| *v)
+    }
+
+    fn is(&self, name: &str) -> bool {
+        self.0.iter().any(|(k, v)| *k == name && v.is_none())
+            || self.get("shape") == Some(name)
+    }
+}
+
+struct Cell<'a> {
+    id: &'a str,
+    parent: Option<&'a str>,
+    value: String,
+    style: Style<'a>,
+    vertex: bool,
+    edge: bool,
+    source: Option<&'a str>,
+    target: Option<&'a str>,
+    geometry: (f64, f64, f64, f64),
+}
+
+impl<'a> Cell<'a> {
+    /// Reads an `mxCell`, which may be wrapped in an `object` or `UserObject`
+    /// that holds its id and label.
+    fn parse(node: Node<'a, '_>) -> Option<Self> {
+        let (wrapper, cell) = match node.tag_name().name() {
+            "mxCell" => (None, node),
+            "object" | "UserObject" => (
+                Some(node),
+                node.children().find(|c| c.tag_name().name() == "mxCell")?,
+            ),
+            _ => return None,
+        };
+
+        let id = wrapper.unwrap_or(cell).attribute("id")?;
+        let value = wrapper
+            .and_then(|w| w.attribute("label"))
+            .or_else(|| cell.attribute("value"))
+            .unwrap_or_default();
+        let style = Style::parse(cell.attribute("style").unwrap_or_default());
+        let value = match style.get("html") {
+            Some("1") => plain_text(value),
+            _ => value.to_string(),
+        };
+
+        let geometry = cell
+            .children()
+            .find(|c| c.tag_name().name() == "mxGeometry")
+            .map(|g| {
+                let number = |name| {
+                    g.attribute(name)
+                        .and_then(|v: &str| v.parse::<f64>().ok())
+                        .unwrap_or(0.0)
+                };
+                (number("x"), number("y"), number("width"), number("height"))
+            })
+            .unwrap_or_default();
+
+        Some(Self {
+            id,
+            parent: cell.attribute("parent"),
+            value,
+            style,
+            vertex: cell.attribute("vertex") == Some("1"),
+            edge: cell.attribute("edge") == Some("1"),
+            source: cell.attribute("source"),
+            target: cell.attribute("target"),
+            geometry,
+        })
+    }
+
+    fn is_class(&self) -> bool {
+        self.style.is("swimlane")
+            && self.style.get("childLayout") == Some("stackLayout")
+    }
+}
+
+/// Parses a draw.io file, compressed or not, or a bare `mxGraphModel`. Only
+/// the first page of a file is read.
+pub fn import(source: &str) -> Result<Document, ParseError> {
+    let xml = roxmltree::Document::parse(source).map_err(xml_error)?;
+    let root = xml.root_element();
+
+    let model = match root.tag_name().name() {
+        "mxGraphModel" => root,
+        "mxfile" => {
+            let diagram = root
+                .children()
+                .find(|c| c.tag_name().name() == "diagram")
+                .ok_or_else(|| {
+                    ParseError::new(None, "the file has no pages")
+                })?;
+
+            match diagram
+                .children()
+                .find(|c| c.tag_name().name() == "mxGraphModel")
+            {
+                Some(model) => model,
+                None => {
+                    let text = decompress(diagram.text().unwrap_or_default())?;
+                    return import(&text);
+                }
+            }
+        }
+        name => {
+            return Err(ParseError::new(
+                None,
+                format!("expected a draw.io file, found <{name}>"),
+            ));
+        }
+    };
+
+    let cells = model
+        .descendants()
+        .filter(|n| n.parent().is_some_and(|p| p.tag_name().name() == "root"))
+        .filter_map(Cell::parse)
+        .collect::<Vec<_>>();
+    let by_id = cells
+        .iter()
+        .map(|cell| (cell.id, cell))
+        .collect::<HashMap<_, _>>();
+
+    let mut document = Document::default();
+    let mut ids: HashMap<&str, Id> = HashMap::new();
+
+    for cell in &cells {
+        // Groups are invisible; only their children are drawn.
+        if !cell.vertex || cell.style.is("group") {
+            continue;
+        }
+
+        let (x, y) = origin(cell, &by_id);
+        let (_, _, width, height) = cell.geometry;
+
+        match cell.parent.and_then(|p| by_id.get(p)) {
+            Some(parent) if parent.edge => continue,
+            Some(parent) if parent.is_class() => {
+                if let Some(class) = ids.get(parent.id) {
+                    ids.insert(cell.id, *class);
+                }
+                continue;
+            }
+            _ => (),
+        }
+
+        if cell.is_class() {
+            let rows = cells
+                .iter()
+                .filter(|c| c.vertex && c.parent == Some(cell.id))
+                .collect::<Vec<_>>();
+            let class = read_class(x, y, cell, &rows);
+            ids.insert(cell.id, document.add_element(class));
+        } else if cell.style.get("html") == Some("1")
+            && cell.value.lines().any(|l| l == RULE)
+        {
+            let class = read_html_class(x, y, &cell.value);
+            ids.insert(cell.id, document.add_element(class));
+        } else if cell.style.is("text") {
+            let label = read_label(x, y, cell);
+            ids.insert(cell.id, document.add_element(label));
+        } else {
+            let fill = cell.style.get("fillColor").and_then(parse_hex);
+            let stroke = match cell.style.get("strokeColor") {
+                Some("none") => None,
+                color => Some(Stroke::new(
+                    cell.style
+                        .get("strokeWidth")
+                        .and_then(|w| w.parse::<f64>().ok())
+                        .map_or(1, |w| w.round() as u32),
+                    color.and_then(parse_hex).unwrap_or(BLACK),
+                )),
+            };
+            let radius = (cell.style.get("rounded") == Some("1")
+                || cell.style.is("ellipse"))
+            .then_some(ROUNDED_RADIUS);
+
+            let rectangle = Rectangle::new(
+                x,
+                y,
+                width,
+                height,
+                fill.unwrap_or(WHITE),
+                radius,
+                stroke,
+            );
+            ids.insert(cell.id, document.add_element(rectangle));
+
+            if !cell.value.is_empty() {
+                document.add_element(Label::new(
+                    x + TEXT_PADDING,
+                    y + TEXT_PADDING,
+                    cell.value.clone(),
+                    TextProperties::default(),
+                    BLACK,
+                ));
+            }
+        }
+    }
+
+    for cell in &cells {
+        if !cell.edge {
+            continue;
+        }
+
+        // Edges that are not connected at both ends cannot be attached.
+        let (Some(source), Some(target)) = (
+            cell.source.and_then(|s| ids.get(s)),
+            cell.target.and_then(|t| ids.get(t)),
+        ) else {
+            continue;
+        };
+
+        let (kind, reversed) = edge_kind(&cell.style);
+        let (source, target) = match reversed {
+            true => (*target, *source),
+            false => (*source, *target),
+        };
+
+        let mut label = Some(cell.value.clone()).filter(|v| !v.is_empty());
+        let (mut near_source, mut near_target) = (None, None);
+
+        for child in cells.iter().filter(|c| c.parent == Some(cell.id)) {
+            if child.value.is_empty() {
+                continue;
+            }
+
+            match child.geometry.0 {
+                x if x < -0.5 => near_source = Some(child.value.clone()),
+                x if x > 0.5 => near_target = Some(child.value.clone()),
+                _ => label = label.or(Some(child.value.clone())),
+            }
+        }
+
+        if reversed {
+            std::mem::swap(&mut near_source, &mut near_target);
+        }
+
+        if kind == RelationshipKind::EntityRelationship {
+            let cardinality = |key| {
+                let arrow = cell.style.get(key)?;
+                let cardinality = er_cardinality(arrow)?;
+                Some(cardinality.multiplicity().to_string())
+            };
+            near_source = near_source.or_else(|| cardinality("startArrow"));
+            near_target = near_target.or_else(|| cardinality("endArrow"));
+        }
+
+        let mut relationship = Relationship::new(source, target, kind);
+        relationship.set_label(label);
+        relationship.set_multiplicities(near_source, near_target);
+        document.add_element(relationship);
+    }
+
+    Ok(document)
+}
+
+/// The absolute position of a cell. Children of groups and containers are
+/// positioned relative to them.
+fn origin(cell: &Cell, by_id: &HashMap<&str, &Cell>) -> (f64, f64) {
+    let (mut x, mut y) = (cell.geometry.0, cell.geometry.1);
+    let mut parent = cell.parent.and_then(|p| by_id.get(p));
+
+    while let Some(cell) = parent.filter(|p| p.vertex) {
+        x += cell.geometry.0;
+        y += cell.geometry.1;
+        parent = cell.parent.and_then(|p| by_id.get(p));
+    }
+
+    (x, y)
+}
+
+fn read_class(x: f64, y: f64, cell: &Cell, rows: &[&Cell]) -> Class {
+    let mut class = class_from_header(x, y, &cell.value);
+    let mut rows = rows.to_vec();
+    rows.sort_by(|a, b| a.geometry.1.total_cmp(&b.geometry.1));
+
+    let separator = rows.iter().position(|row| row.style.is("line"));
+
+    for (index, row) in rows.iter().enumerate() {
+        for line in row.value.lines().filter(|l| !l.trim().is_empty()) {
+            let is_operation = match separator {
+                Some(separator) => index > separator,
+                None => Member::parse(line).is_operation(),
+            };
+
+            match is_operation {
+                true => class.add_operation(line.trim()),
+                false => class.add_attribute(line.trim()),
+            }
+        }
+    }
+
+    class
+}
+
+/// Reads a class drawn as a single HTML label, with compartments separated
+/// by horizontal rules (converted to [`RULE`] lines by [`plain_text`]).
+fn read_html_class(x: f64, y: f64, value: &str) -> Class {
+    let mut sections = vec![vec![]];
+
+    for line in value.lines() {
+        match line == RULE {
+            true => sections.push(vec![]),
+            false => sections.last_mut().unwrap().push(line),
+        }
+    }
+
+    let mut sections = sections.into_iter();
+    let header = sections.next().unwrap_or_default().join("\n");
+    let mut class = class_from_header(x, y, &header);
+
+    for line in sections.next().unwrap_or_default() {
+        class.add_attribute(line);
+    }
+
+    for line in sections.flatten() {
+        class.add_operation(line);
+    }
+
+    class
+}
+
+fn class_from_header(x: f64, y: f64, header: &str) -> Class {
+    let mut lines = header.lines().map(str::trim).filter(|l| !l.is_empty());
+    let mut first = lines.next().unwrap_or_default();
+
+    let stereotype = first
+        .strip_prefix("<<")
+        .and_then(|s| s.strip_suffix(">>"))
+        .or_else(|| first.strip_prefix('«').and_then(|s| s.strip_suffix('»')))
+        .map(|s| s.trim().to_string());
+
+    if stereotype.is_some() {
+        first = lines.next().unwrap_or_default();
+    }
+
+    let name = std::iter::once(first)
+        .chain(lines)
+        .collect::<Vec<_>>()
+        .join(" ");
+    let mut class = Class::new(x, y, name, None, None, None);
+    class.set_attributes(Vec::<String>::new());
+    class.set_stereotype(stereotype);
+    class
+}
+
+fn read_label(x: f64, y: f64, cell: &Cell) -> Label {
+    let size = cell
+        .style
+        .get("fontSize")
+        .and_then(|s| s.parse::<f32>().ok())
+        .unwrap_or(12.0);
+    let mut props = TextProperties::new(size, TextProperties::default().font());
+
+    // `fontStyle` is a bit mask in which 1 stands for bold.
+    if cell
+        .style
+        .get("fontStyle")
+        .and_then(|s| s.parse::<u32>().ok())
+        .is_some_and(|s| s & 1 == 1)
+    {
+        props = props.weight(700);
+    }
+
+    let color = cell
+        .style
+        .get("fontColor")
+        .and_then(parse_hex)
+        .unwrap_or(BLACK);
+
+    Label::new(x, y, cell.value.clone(), props, color)
+}
+
+/// The kind of relationship an edge stands for, and whether its ends have to
+/// be swapped to put the decoration at the target.
+fn edge_kind(style: &Style) -> (RelationshipKind, bool) {
+    let dashed = style.get("dashed") == Some("1");
+    let filled = |key| style.get(key) != Some("0");
+    let start = style.get("startArrow").unwrap_or("none");
+    let end = style.get("endArrow").unwrap_or("classic");
+
+    match (start, end) {
+        (start, end)
+            if er_cardinality(start).is_some()
+                || er_cardinality(end).is_some() =>
+        {
+            (RelationshipKind::EntityRelationship, false)
+        }
+        (_, "block" | "blockThin") if !filled("endFill") => match dashed {
+            true => (RelationshipKind::Realization, false),
+            false => (RelationshipKind::Generalization, false),
+        },
+        (_, "diamond" | "diamondThin") => match filled("endFill") {
+            true => (RelationshipKind::Composition, false),
+            false => (RelationshipKind::Aggregation, false),
+        },
+        ("diamond" | "diamondThin", _) => match filled("startFill") {
+            true => (RelationshipKind::Composition, true),
+            false => (RelationshipKind::Aggregation, true),
+        },
+        ("block" | "blockThin", "none") if !filled("startFill") => match dashed
+        {
+            true => (RelationshipKind::Realization, true),
+            false => (RelationshipKind::Generalization, true),
+        },
+        ("none", "none") => match dashed {
+            true => (RelationshipKind::DashedLink, false),
+            false => (RelationshipKind::Link, false),
+        },
+        _ => match dashed {
+            true => (RelationshipKind::Dependency, false),
+            false => (RelationshipKind::Association, false),
+        },
+    }
+}
+
+/// The draw.io arrow drawing the crow's foot of `cardinality`.
+fn er_arrow(cardinality: Cardinality) -> &'static str {
+    match cardinality {
+        Cardinality::One => "ERmandOne",
+        Cardinality::ZeroOrOne => "ERzeroToOne",
+        Cardinality::Many => "ERzeroToMany",
+        Cardinality::OneOrMany => "ERoneToMany",
+    }
+}
+
+fn er_cardinality(arrow: &str) -> Option<Cardinality> {
+    match arrow {
+        "ERone" | "ERmandOne" => Some(Cardinality::One),
+        "ERzeroToOne" => Some(Cardinality::ZeroOrOne),
+        "ERmany" | "ERzeroToMany" => Some(Cardinality::Many),
+        "ERoneToMany" => Some(Cardinality::OneOrMany),
+        _ => None,
+    }
+}
+
+/// Decodes a compressed page: base64 encoded, deflated, URI encoded XML.
+fn decompress(text: &str) -> Result<String, ParseError> {
+    let error = |message: &str| {
+        ParseError::new(None, format!("compressed page is invalid: {message}"))
+    };
+
+    let deflated = base64::engine::general_purpose::STANDARD
+        .decode(text.trim())
+        .map_err(|e| error(&e.to_string()))?;
+    let inflated = miniz_oxide::inflate::decompress_to_vec_with_limit(
+        &deflated,
+        MAX_INFLATED_SIZE,
+    )
+    .map_err(|e| error(&e.to_string()))?;
+    let encoded =
+        String::from_utf8(inflated).map_err(|e| error(&e.to_string()))?;
+
+    percent_encoding::percent_decode_str(&encoded)
+        .decode_utf8()
+        .map(|text| text.into_owned())
+        .map_err(|e| error(&e.to_string()))
+}
+
+/// Converts an HTML label to plain text. Line breaks and paragraphs become
+/// new lines and horizontal rules become [`RULE`] lines.
+fn plain_text(html: &str) -> String {
+    let mut output = String::new();
+    let mut rest = html;
+
+    while let Some(start) = rest.find('<') {
+        output += &decode_entities(&rest[..start]);
+
+        let tag = rest[start + 1..]
+            .trim_start_matches('/')
+            .split(|c: char| !c.is_ascii_alphanumeric())
+            .next()
+            .unwrap_or_default()
+            .to_lowercase();
+        let end = rest[start..].find('>');
+
+        let (Some(end), true) = (end, HTML_TAGS.contains(&tag.as_str())) else {
+            // Not markup, e.g. the `<<` of a stereotype typed into a label.
+            output.push('<');
+            rest = &rest[start + 1..];
+            continue;
+        };
+
+        match tag.as_str() {
+            "br" | "p" | "div" => output.push('\n'),
+            "hr" => output += &format!("\n{RULE}\n"),
+            _ => (),
+        }
+
+        rest = &rest[start + end + 1..];
+    }
+
+    output += &decode_entities(rest);
+    output
+        .lines()
+        .map(str::trim)
+        .filter(|l| !l.is_empty())
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+fn decode_entities(text: &str) -> String {
+    text.replace("&nbsp;", " ")
+        .replace("&lt;", "<")
+        .replace("&gt;", ">")
+        .replace("&quot;", "\"")
+        .replace("&#39;", "'")
+        .replace("&laquo;", "«")
+        .replace("&raquo;", "»")
+        .replace("&amp;", "&")
+}
+
+fn xml_error(e: roxmltree::Error) -> ParseError {
+    ParseError::new(e.pos().row as usize, e.to_string())
+}
@@ -0,0 +1,707 @@
+//! Conversion between documents and Mermaid `classDiagram` syntax.
+
+use std::collections::HashMap;
+
+use crate::{
+    document::Document,
+    elements::{Class, Element, Relationship, RelationshipKind},
+    id::Id,
+    layout,
+    member::{Member, Parameter, Visibility, split_top_level},
+};
+
+use super::ParseError;
+
+const INDENT: &str = "    ";
+
+/// Statements that are valid Mermaid but carry nothing a document can show.
+const IGNORED_KEYWORDS: [&str; 9] = [
+    "direction",
+    "note",
+    "style",
+    "classDef",
+    "cssClass",
+    "click",
+    "link",
+    "callback",
+    "title",
+];
+
+struct RelationshipStatement {
+    source: String,
+    target: String,
+    kind: RelationshipKind,
+    source_multiplicity: Option<String>,
+    target_multiplicity: Option<String>,
+    label: Option<String>,
+}
+
+#[derive(Default)]
+struct Parser {
+    classes: Vec<(String, Class)>,
+    indices: HashMap<String, usize>,
+    relationships: Vec<RelationshipStatement>,
+    open_class: Option<String>,
+    namespaces: usize,
+}
+
+impl Parser {
+    fn class_mut(&mut self, identifier: &str) -> &mut Class {
+        let index = match self.indices.get(identifier) {
+            Some(index) => *index,
+            None => {
+                let mut class = Class::new(
+                    0.0,
+                    0.0,
+                    identifier.to_string(),
+                    None,
+                    None,
+                    None,
+                );
+                class.set_attributes(Vec::<String>::new());
+                self.classes.push((identifier.to_string(), class));
+                self.indices
+                    .insert(identifier.to_string(), self.classes.len() - 1);
+                self.classes.len() - 1
+            }
+        };
+
+        &mut self.classes[index].1
+    }
+
+    fn add_member(&mut self, identifier: &str, text: &str) {
+        let member = member_from_mermaid(text);
+        let class = self.class_mut(identifier);
+
+        if member.is_operation() {
+            class.add_operation(member.to_string());
+        } else {
+            class.add_attribute(member.to_string());
+        }
+    }
+
+    fn parse_line(
+        &mut self,
+        number: usize,
+        line: &str,
+    ) -> Result<(), ParseError> {
+        if let Some(identifier) = self.open_class.clone() {
+            if line == "}" {
+                self.open_class = None;
+            } else if let Some(annotation) = parse_annotation(line) {
+                self.class_mut(&identifier)
+                    .set_stereotype(Some(annotation.to_string()));
+            } else {
+                self.add_member(&identifier, line);
+            }
+
+            return Ok(());
+        }
+
+        let keyword = line.split_whitespace().next().unwrap_or_default();
+
+        if IGNORED_KEYWORDS.contains(&keyword) {
+            log::debug!("Ignoring Mermaid statement on line {number}: {line}");
+            return Ok(());
+        }
+
+        if line == "}" {
+            if self.namespaces == 0 {
+                return Err(ParseError::new(number, "unexpected `}`"));
+            }
+
+            self.namespaces -= 1;
+            return Ok(());
+        }
+
+        if keyword == "namespace" {
+            if !line.ends_with('{') {
+                return Err(ParseError::new(number, "expected `{`"));
+            }
+
+            self.namespaces += 1;
+            return Ok(());
+        }
+
+        if let Some(rest) = line.strip_prefix("class ") {
+            return self.parse_class(number, rest.trim());
+        }
+
+        if let Some(rest) = line.strip_prefix("<<") {
+            let Some((annotation, identifier)) = rest.split_once(">>") else {
+                return Err(ParseError::new(number, "unterminated annotation"));
+            };
+
+            let identifier = identifier_of(identifier.trim());
+            self.class_mut(&identifier)
+                .set_stereotype(Some(annotation.trim().to_string()));
+            return Ok(());
+        }
+
+        if let Some(statement) = parse_relationship(line) {
+            self.class_mut(&statement.source);
+            self.class_mut(&statement.target);
+            self.relationships.push(statement);
+            return Ok(());
+        }
+
+        if let Some((identifier, member)) = line.split_once(':') {
+            let identifier = identifier_of(identifier.trim());
+
+            if is_identifier(&identifier) {
+                self.add_member(&identifier, member.trim());
+                return Ok(());
+            }
+        }
+
+        Err(ParseError::new(
+            number,
+            format!("unrecognized statement `{line}`"),
+        ))
+    }
+
+    fn parse_class(
+        &mut self,
+        number: usize,
+        rest: &str,
+    ) -> Result<(), ParseError> {
+        let (declaration, opens_body) = match rest.strip_suffix('{') {
+            Some(declaration) => (declaration.trim(), true),
+            None => (rest, false),
+        };
+
+        let declaration = match declaration.split_once(":::") {
+            Some((declaration, _)) => declaration,
+            None => declaration,
+        };
+
+        let (name, label) = match declaration.split_once('[') {
+            Some((name, label)) => {
+                let Some(label) = label.trim().strip_suffix(']') else {
+                    return Err(ParseError::new(number, "expected `]`"));
+                };
+                (name.trim(), Some(unquote(label.trim())))
+            }
+            None => (declaration.trim(), None),
+        };
+
+        let identifier = identifier_of(name);
+
+        if !is_identifier(&identifier) {
+            return Err(ParseError::new(
+                number,
+                format!("invalid class name `{name}`"),
+            ));
+        }
+
+        let display = label.unwrap_or_else(|| from_mermaid_type(name));
+        self.class_mut(&identifier).set_name(display);
+
+        if opens_body {
+            self.open_class = Some(identifier);
+        }
+
+        Ok(())
+    }
+}
+
+/// Parses a Mermaid `classDiagram` into a document. Mermaid does not store
+/// positions, so the classes are laid out on a grid.
+pub fn import(source: &str) -> Result<Document, ParseError> {
+    let mut parser = Parser::default();
+    let mut header = false;
+
+    for (index, line) in source.lines().enumerate() {
+        let number = index + 1;
+        let line = line.trim();
+
+        if line.is_empty() || line.starts_with("%%") {
+            continue;
+        }
+
+        if !header {
+            if !line.starts_with("classDiagram") {
+                return Err(ParseError::new(
+                    number,
+                    "expected a `classDiagram` declaration",
+                ));
+            }
+
+            header = true;
+            continue;
+        }
+
+        parser.parse_line(number, line)?;
+    }
+
+    if !header {
+        return Err(ParseError::new(None, "the diagram is empty"));
+    }
+
+    if parser.open_class.is_some() || parser.namespaces > 0 {
+        return Err(ParseError::new(None, "expected `}` before end of input"));
+    }
+
+    let mut document = Document::default();
+    let mut ids = HashMap::new();
+
+    for (identifier, class) in parser.classes {
+        ids.insert(identifier, document.add_element(class));
+    }
+
+    for statement in parser.relationships {
+        let mut relationship = Relationship::new(
+            ids[&statement.source],
+            ids[&statement.target],
+            statement.kind,
+        );
+        relationship.set_multiplicities(
+            statement.source_multiplicity,
+            statement.target_multiplicity,
+        );
+        relationship.set_label(statement.label);
+        document.add_element(relationship);
+    }
+
+    layout::grid(&mut document);
+    Ok(document)
+}
+
+/// Writes the classes and relationships of a document as a Mermaid
+/// `classDiagram`. Other elements have no Mermaid equivalent and are skipped.
+pub fn export(document: &Document) -> String {
+    let mut output = String::from("classDiagram\n");
+    let mut identifiers: HashMap<Id, String> = HashMap::new();
+
+    for el in document.elements() {
+        let Some(class) = el.as_class() else {
+            continue;
+        };
+
+        let identifier = unique_identifier(class.name(), &identifiers);
+        output += &format!("{INDENT}class {}", declaration(class, &identifier));
+
+        let members = class
+            .attributes()
+            .chain(class.operations())
+            .map(|m| member_to_mermaid(&Member::parse(m)))
+            .collect::<Vec<_>>();
+
+        if class.stereotype().is_none() && members.is_empty() {
+            output += "\n";
+        } else {
+            output += " {\n";
+
+            if let Some(stereotype) = class.stereotype() {
+                output += &format!("{INDENT}{INDENT}<<{stereotype}>>\n");
+            }
+
+            for member in members {
+                output += &format!("{INDENT}{INDENT}{member}\n");
+            }
+
+            output += &format!("{INDENT}}}\n");
+        }
+
+        identifiers.insert(el.id(), identifier);
+    }
+
+    for relationship in document
+        .elements()
+        .iter()
+        .filter_map(Element::as_relationship)
+    {
+        let (Some(source), Some(target)) = (
+            identifiers.get(&relationship.source()),
+            identifiers.get(&relationship.target()),
+        ) else {
+            continue;
+        };
+
+        let quote = |m: Option<&str>| {
+            m.map(|m| format!(" \"{m}\"")).unwrap_or_default()
+        };
+
+        output += &format!(
+            "{INDENT}{source}{} {}{} {target}",
+            quote(relationship.source_multiplicity()),
+            operator(relationship.kind()),
+            quote(relationship.target_multiplicity()),
+        );
+
+        if let Some(label) = relationship.label() {
+            output += &format!(" : {label}");
+        }
+
+        output += "\n";
+    }
+
+    output
+}
+
+fn operator(kind: RelationshipKind) -> &'static str {
+    match kind {
+        RelationshipKind::Association
+        | RelationshipKind::Transition
+        | RelationshipKind::ControlFlow => "-->",
+        RelationshipKind::Link
+        | RelationshipKind::CommunicationPath
+        | RelationshipKind::EntityRelationship => "--",
+        RelationshipKind::Generalization => "--|>",
+        RelationshipKind::Realization => "..|>",
+        RelationshipKind::Aggregation => "--o",
+        RelationshipKind::Composition => "--*",
+        RelationshipKind::Dependency
+        | RelationshipKind::Include
+        | RelationshipKind::Extend
+        | RelationshipKind::Import
+        | RelationshipKind::Merge
+        | RelationshipKind::Uses => "..>",
+        RelationshipKind::DashedLink => "..",
+    }
+}
+
+fn declaration(class: &Class, identifier: &str) -> String {
+    let name = class.name();
+    let generic = to_mermaid_type(name);
+
+    if identifier_of(&generic) == identifier && is_identifier(identifier) {
+        generic
+    } else {
+        format!("{identifier}[\"{name}\"]")
+    }
+}
+
+fn unique_identifier(name: &str, taken: &HashMap<Id, String>) -> String {
+    let base = name.split('<').next().unwrap_or_default();
+    let mut base = base
+        .chars()
+        .map(|c| {
+            if c.is_alphanumeric() || c == '_' {
+                c
+            } else {
+                '_'
+            }
+        })
+        .collect::<String>();
+
+    if base.is_empty() {
+        base = String::from("Class");
+    }
+
+    let mut identifier = base.clone();
+    let mut n = 2;
+
+    while taken.values().any(|t| *t == identifier) {
+        identifier = format!("{base}_{n}");
+        n += 1;
+    }
+
+    identifier
+}
+
+fn is_identifier(text: &str) -> bool {
+    !text.is_empty()
+        && text
+            .chars()
+            .all(|c| c.is_alphanumeric() || c == '_' || c == '-')
+}
+
+/// Strips generic parameters (`Name~T~`) from a class reference.
+fn identifier_of(name: &str) -> String {
+    name.split('~')
+        .next()
+        .unwrap_or_default()
+        .trim()
+        .to_string()
+}
+
+fn unquote(text: &str) -> String {
+    text.trim_matches('"').to_string()
+}
+
+fn parse_annotation(line: &str) -> Option<&str> {
+    line.strip_prefix("<<")?.strip_suffix(">>").map(str::trim)
+}
+
+/// Converts Mermaid generics (`List~List~int~~`) to UML notation
+/// (`List<List<int>>`). A tilde between two word characters opens a
+/// parameter list, any other tilde closes one.
+fn from_mermaid_type(text: &str) -> String {
+    let chars = text.chars().collect::<Vec<_>>();
+    let is_word =
+        |c: Option<&char>| c.is_some_and(|c| c.is_alphanumeric() || *c == '_');
+
+    chars
+        .iter()
+        .enumerate()
+        .map(|(i, c)| match c {
+            '~' if i > 0
+                && is_word(chars.get(i - 1))
+                && is_word(chars.get(i + 1)) =>
+            {
+                '<'
+            }
+            '~' => '>',
+            c => *c,
+        })
+        .collect()
+}
+
+fn to_mermaid_type(text: &str) -> String {
+    text.replace(['<', '>'], "~")
+}
+
+/// Splits `Type name` (Mermaid style) or `name: Type` (UML style) into the
+/// name and the type.
+fn split_typed(text: &str) -> (String, Option<String>) {
+    if let Some((name, ty)) = text.split_once(':') {
+        return (name.trim().to_string(), Some(ty.trim().to_string()));
+    }
+
+    let words = split_top_level(text, ' ');
+    let words = words.iter().filter(|w| !w.is_empty()).collect::<Vec<_>>();
+
+    match words.split_last() {
+        Some((name, ty)) if !ty.is_empty() => (
+            name.to_string(),
+            Some(ty.iter().map(|w| w.as_str()).collect::<Vec<_>>().join(" ")),
+        ),
+        Some((name, _)) => (name.to_string(), None),
+        None => (String::new(), None),
+    }
+}
+
+fn member_from_mermaid(text: &str) -> Member {
+    // `~` marks package visibility as well as generics, so the visibility is
+    // read before the generics are converted.
+    let text = text.trim();
+    let visibility = text
+        .chars()
+        .next()
+        .and_then(Visibility::from_symbol)
+        .unwrap_or_default();
+    let text = match visibility {
+        Visibility::Unspecified => text,
+        _ => text[1..].trim_start(),
+    };
+
+    let mut text = from_mermaid_type(text);
+    let mut is_static = false;
+    let mut is_abstract = false;
+
+    loop {
+        if let Some(rest) = text.strip_suffix('$') {
+            is_static = true;
+            text = rest.trim_end().to_string();
+        } else if let Some(rest) = text.strip_suffix('*') {
+            is_abstract = true;
+            text = rest.trim_end().to_string();
+        } else {
+            break;
+        }
+    }
+
+    let rest = text.as_str();
+
+    let member = match (rest.find('('), rest.rfind(')')) {
+        (Some(open), Some(close)) if open < close => {
+            let parameters = split_top_level(&rest[open + 1..close], ',')
+                .into_iter()
+                .filter(|p| !p.is_empty())
+                .map(|p| {
+                    let (name, ty) = split_typed(&p);
+                    Parameter::new(name, ty)
+                })
+                .collect();
+
+            let mut returns = rest[close + 1..].trim();
+
+            for (classifier, flag) in
+                [('$', &mut is_static), ('*', &mut is_abstract)]
+            {
+                if let Some(r) = returns.strip_prefix(classifier) {
+                    *flag = true;
+                    returns = r.trim_start();
+                }
+            }
+
+            let returns = Some(returns.to_string()).filter(|r| !r.is_empty());
+            Member::operation(rest[..open].trim(), parameters, returns)
+        }
+        _ => {
+            let (name, ty) = split_typed(rest);
+            Member::attribute(name, ty)
+        }
+    };
+
+    member
+        .with_visibility(visibility)
+        .with_static(is_static)
+        .with_abstract(is_abstract)
+}
+
+fn member_to_mermaid(member: &Member) -> String {
+    let mut output = member
+        .visibility()
+        .symbol()
+        .map(String::from)
+        .unwrap_or_default();
+
+    match member.parameters() {
+        Some(parameters) => {
+            let parameters = parameters
+                .iter()
+                .map(|p| match p.ty() {
+                    Some(ty) => format!("{} {}", to_mermaid_type(ty), p.name()),
+                    None => p.name().to_string(),
+                })
+                .collect::<Vec<_>>()
+                .join(", ");
+            output += &format!("{}({parameters})", member.name());
+
+            if let Some(ty) = member.ty() {
+                output += &format!(" {}", to_mermaid_type(ty));
+            }
+        }
+        None => {
+            if let Some(ty) = member.ty() {
+                output += &format!("{} ", to_mermaid_type(ty));
+            }
+
+            output += member.name();
+        }
+    }
+
+    if member.is_static() {
+        output += "$";
+    }
+
+    if member.is_abstract() {
+        output += "*";
+    }
+
+    output
+}
+
+/// Finds the first occurrence of `pattern` in `text` outside of quotes.
+fn find_unquoted(text: &str, pattern: &str) -> Option<usize> {
+    let mut quoted = false;
+
+    for (i, c) in text.char_indices() {
+        if c == '"' {
+            quoted = !quoted;
+        } else if !quoted && text[i..].starts_with(pattern) {
+            return Some(i);
+        }
+    }
+
+    None
+}
+
+/// Splits a trailing (`left`) or leading (`right`) quoted cardinality from
+/// the class reference next to a relationship operator.
+fn split_cardinality(text: &str, left: bool) -> (String, Option<String>) {
+    let text = text.trim();
+
+    let quoted = if left {
+        text.strip_suffix('"')
+            .and_then(|t| t.rfind('"').map(|i| (&t[..i], &t[i + 1..])))
+    } else {
+        text.strip_prefix('"')
+            .and_then(|t| t.find('"').map(|i| (&t[i + 1..], &t[..i])))
+    };
+
+    match quoted {
+        Some((class, cardinality)) => {
+            (identifier_of(class.trim()), Some(cardinality.to_string()))
+        }
+        None => (identifier_of(text), None),
+    }
+}
+
+fn parse_relationship(line: &str) -> Option<RelationshipStatement> {
+    let (lhs, label) = match find_unquoted(line, ":") {
+        Some(i) => (&line[..i], Some(line[i + 1..].trim().to_string())),
+        None => (line, None),
+    };
+
+    let (position, dashed) =
+        match (find_unquoted(lhs, "--"), find_unquoted(lhs, "..")) {
+            (Some(solid), Some(dashed)) if dashed < solid => (dashed, true),
+            (Some(solid), _) => (solid, false),
+            (None, Some(dashed)) => (dashed, true),
+            (None, None) => return None,
+        };
+
+    let before = &lhs[..position];
+    let after = &lhs[position + 2..];
+    let boundary =
+        |c: Option<char>| c.is_none_or(|c| c.is_whitespace() || c == '"');
+
+    let left_head = ["<|", "*", "<"]
+        .into_iter()
+        .find(|h| before.ends_with(h))
+        .or_else(|| {
+            let rest = before.strip_suffix('o')?;
+            boundary(rest.chars().last()).then_some("o")
+        });
+
+    let right_head = ["|>", "*", ">"]
+        .into_iter()
+        .find(|h| after.starts_with(h))
+        .or_else(|| {
+            let rest = after.strip_prefix('o')?;
+            boundary(rest.chars().next()).then_some("o")
+        });
+
+    let (left, left_cardinality) = split_cardinality(
+        &before[..before.len() - left_head.map_or(0, str::len)],
+        true,
+    );
+    let (right, right_cardinality) =
+        split_cardinality(&after[right_head.map_or(0, str::len)..], false);
+
+    if !is_identifier(&left) || !is_identifier(&right) {
+        return None;
+    }
+
+    let (head, target_is_left) = match (left_head, right_head) {
+        (Some(head), _) => (head, true),
+        (None, Some(head)) => (head, false),
+        (None, None) => ("", false),
+    };
+
+    let kind = match (head, dashed) {
+        ("<|" | "|>", false) => RelationshipKind::Generalization,
+        ("<|" | "|>", true) => RelationshipKind::Realization,
+        ("*", _) => RelationshipKind::Composition,
+        ("o", _) => RelationshipKind::Aggregation,
+        ("<" | ">", false) => RelationshipKind::Association,
+        ("<" | ">", true) => RelationshipKind::Dependency,
+        (_, false) => RelationshipKind::Link,
+        (_, true) => RelationshipKind::DashedLink,
+    };
+
+    let statement = if target_is_left {
+        RelationshipStatement {
+            source: right,
+            target: left,
+            kind,
+            source_multiplicity: right_cardinality,
+            target_multiplicity: left_cardinality,
+            label,
+        }
+    } else {
+        RelationshipStatement {
+            source: left,
+            target: right,
+            kind,
+            source_multiplicity: left_cardinality,
+            target_multiplicity: right_cardinality,
+            label,
+        }
+    };
+
+    Some(statement)
+}
@@ -0,0 +1,102 @@
+use std::fmt::Display;
+
+pub mod dot;
+pub mod drawio;
+pub mod mermaid;
+pub mod openapi;
+pub mod rust;
+pub mod sql;
+pub mod xmi;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    line: Option<usize>,
+    message: String,
+}
+
+impl ParseError {
+    pub fn new(
+        line: impl Into<Option<usize>>,
+        message: impl Into<String>,
+    ) -> Self {
+        Self {
+            line: line.into(),
+            message: message.into(),
+        }
+    }
+
+    /// The line (starting from 1) the error was found on, if known.
+    pub fn line(&self) -> Option<usize> {
+        self.line
+    }
+
+    pub fn message(&self) -> &str {
+        &self.message
+    }
+}
+
+impl Display for ParseError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self.line {
+            Some(line) => {
+                f.write_fmt(format_args!("line {line}: {}", self.message))
+            }
+            None => f.write_str(&self.message),
+        }
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+/// Escapes text for use in XML attributes and character data.
+pub(crate) fn escape_xml(text: &str) -> String {
+    let mut output = String::with_capacity(text.len());
+
+    for c in text.chars() {
+        match c {
+            '&' => output += "&amp;",
+            '<' => output += "&lt;",
+            '>' => output += "&gt;",
+            '"' => output += "&quot;",
+            '\'' => output += "&apos;",
+            c => output.push(c),
+        }
+    }
+
+    output
+}
+
+/// Writes indented XML, one element per line.
+pub(crate) struct Writer {
+    output: String,
+    depth: usize,
+}
+
+impl Writer {
+    pub(crate) fn new(header: &str, depth: usize) -> Self {
+        Self {
+            output: String::from(header),
+            depth,
+        }
+    }
+
+    pub(crate) fn line(&mut self, text: &str) {
+        self.output += &"  ".repeat(self.depth);
+        self.output += text;
+        self.output += "\n";
+    }
+
+    pub(crate) fn open(&mut self, text: &str) {
+        self.line(text);
+        self.depth += 1;
+    }
+
+    pub(crate) fn close(&mut self, text: &str) {
+        self.depth -= 1;
+        self.line(text);
+    }
+
+    pub(crate) fn finish(self, footer: &str) -> String {
+        self.output + footer
+    }
+}
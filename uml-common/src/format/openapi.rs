@@ -0,0 +1,348 @@
+//! Import of data models from OpenAPI 3 (or Swagger 2) documents and JSON
+//! Schema files, written as either JSON or YAML.
+
+use std::collections::HashMap;
+
+use serde_norway::Value;
+
+use crate::{
+    document::Document,
+    elements::{Class, Relationship, RelationshipKind},
+    id::Id,
+    layout,
+};
+
+use super::ParseError;
+
+/// The name given to the root of a JSON Schema file without a `title`.
+const ROOT_NAME: &str = "Root";
+
+struct Association {
+    source: String,
+    target: String,
+    label: String,
+    multiplicity: &'static str,
+}
+
+#[derive(Default)]
+struct Importer {
+    classes: Vec<(String, Class)>,
+    generalizations: Vec<(String, String)>,
+    associations: Vec<Association>,
+}
+
+impl Importer {
+    fn add_schema(&mut self, name: &str, schema: &Value, names: &[String]) {
+        let mut class =
+            Class::new(0.0, 0.0, name.to_string(), None, None, None);
+        class.set_attributes(Vec::<String>::new());
+
+        if let Some(literals) = schema.get("enum").and_then(Value::as_sequence)
+        {
+            class.set_stereotype(Some(String::from("enumeration")));
+
+            for literal in literals {
+                class.add_attribute(match literal {
+                    Value::String(literal) => literal.clone(),
+                    literal => {
+                        serde_json::to_string(literal).unwrap_or_default()
+                    }
+                });
+            }
+
+            self.classes.push((name.to_string(), class));
+            return;
+        }
+
+        // Referenced parts of `allOf` are superclasses; inline parts add to
+        // the properties of the schema itself.
+        let mut parts = vec![schema];
+
+        for part in schema
+            .get("allOf")
+            .and_then(Value::as_sequence)
+            .into_iter()
+            .flatten()
+        {
+            match reference(part).filter(|r| names.contains(r)) {
+                Some(general) => {
+                    self.generalizations.push((name.to_string(), general))
+                }
+                None => parts.push(part),
+            }
+        }
+
+        for part in parts {
+            let required = part
+                .get("required")
+                .and_then(Value::as_sequence)
+                .map(|r| r.iter().filter_map(Value::as_str).collect::<Vec<_>>())
+                .unwrap_or_default();
+
+            let properties = part
+                .get("properties")
+                .and_then(Value::as_mapping)
+                .into_iter()
+                .flatten()
+                .filter_map(|(property, schema)| {
+                    Some((property.as_str()?, schema))
+                });
+
+            for (property, schema) in properties {
+                let is_required = required.contains(&property)
+                    && schema.get("nullable") != Some(&Value::Bool(true));
+                self.add_property(
+                    &mut class,
+                    name,
+                    property,
+                    schema,
+                    is_required,
+                    names,
+                );
+            }
+        }
+
+        self.classes.push((name.to_string(), class));
+    }
+
+    fn add_property(
+        &mut self,
+        class: &mut Class,
+        owner: &str,
+        property: &str,
+        schema: &Value,
+        is_required: bool,
+        names: &[String],
+    ) {
+        let is_array =
+            schema.get("type").and_then(Value::as_str) == Some("array");
+        let items = match is_array {
+            true => schema.get("items").unwrap_or(&Value::Null),
+            false => schema,
+        };
+
+        let multiplicity = match (is_array, is_required) {
+            (true, _) => "*",
+            (false, true) => "1",
+            (false, false) => "0..1",
+        };
+
+        for target in
+            referenced(items).into_iter().filter(|r| names.contains(r))
+        {
+            self.associations.push(Association {
+                source: owner.to_string(),
+                target,
+                label: property.to_string(),
+                multiplicity,
+            });
+        }
+
+        let suffix = match multiplicity {
+            "1" => "",
+            multiplicity => &format!(" [{multiplicity}]"),
+        };
+
+        class
+            .add_attribute(format!("{property}: {}{suffix}", type_name(items)));
+    }
+
+    fn into_document(self) -> Document {
+        let mut document = Document::default();
+        let mut ids: HashMap<String, Id> = HashMap::new();
+
+        for (name, class) in self.classes {
+            ids.insert(name, document.add_element(class));
+        }
+
+        for (source, target) in self.generalizations {
+            if let (Some(source), Some(target)) =
+                (ids.get(&source), ids.get(&target))
+            {
+                document.add_element(Relationship::new(
+                    *source,
+                    *target,
+                    RelationshipKind::Generalization,
+                ));
+            }
+        }
+
+        for association in self.associations {
+            let (Some(source), Some(target)) =
+                (ids.get(&association.source), ids.get(&association.target))
+            else {
+                continue;
+            };
+
+            let mut relationship = Relationship::new(
+                *source,
+                *target,
+                RelationshipKind::Association,
+            );
+            relationship.set_label(Some(association.label));
+            relationship.set_multiplicities(
+                None,
+                Some(association.multiplicity.to_string()),
+            );
+            document.add_element(relationship);
+        }
+
+        layout::layered(&mut document);
+        document
+    }
+}
+
+/// The name of the schema a `$ref` points to, e.g. `Pet` for
+/// `#/components/schemas/Pet`, `common.yaml#/Pet` or `pet.yaml`.
+fn reference(schema: &Value) -> Option<String> {
+    let reference = schema.get("$ref")?.as_str()?;
+    let pointer = reference.rsplit('#').next().unwrap_or(reference);
+    let pointer = match pointer.is_empty() {
+        true => reference.trim_end_matches('#'),
+        false => pointer,
+    };
+    let name = pointer.rsplit('/').next().unwrap_or(pointer);
+
+    [".json", ".yaml", ".yml"]
+        .iter()
+        .find_map(|extension| name.strip_suffix(extension))
+        .or(Some(name))
+        .filter(|name| !name.is_empty())
+        .map(String::from)
+}
+
+/// Every schema a property refers to, looking through `oneOf`, `anyOf` and
+/// `allOf` as well as the values of maps.
+fn referenced(schema: &Value) -> Vec<String> {
+    if let Some(name) = reference(schema) {
+        return vec![name];
+    }
+
+    let alternatives = ["oneOf", "anyOf", "allOf"]
+        .iter()
+        .filter_map(|key| schema.get(key).and_then(Value::as_sequence))
+        .flatten()
+        .flat_map(referenced);
+
+    let values = schema
+        .get("additionalProperties")
+        .filter(|v| v.is_mapping())
+        .into_iter()
+        .flat_map(referenced);
+
+    alternatives.chain(values).collect()
+}
+
+/// A short type name for a schema: the referenced schema, the format if there
+/// is one (e.g. `date-time`), the item type of arrays or the plain type.
+fn type_name(schema: &Value) -> String {
+    if let Some(name) = reference(schema) {
+        return name;
+    }
+
+    for (key, separator) in
+        [("oneOf", " | "), ("anyOf", " | "), ("allOf", " & ")]
+    {
+        if let Some(parts) = schema.get(key).and_then(Value::as_sequence) {
+            return parts
+                .iter()
+                .map(type_name)
+                .collect::<Vec<_>>()
+                .join(separator);
+        }
+    }
+
+    if let Some(format) = schema.get("format").and_then(Value::as_str) {
+        return format.to_string();
+    }
+
+    match schema.get("type") {
+        Some(Value::String(ty)) if ty == "array" => {
+            let items = schema.get("items").unwrap_or(&Value::Null);
+            format!("{}[]", type_name(items))
+        }
+        Some(Value::String(ty)) if ty == "object" => {
+            match schema
+                .get("additionalProperties")
+                .filter(|v| v.is_mapping())
+            {
+                Some(values) => format!("Map<string, {}>", type_name(values)),
+                None => String::from("object"),
+            }
+        }
+        Some(Value::String(ty)) => ty.clone(),
+        // JSON Schema allows several types, e.g. `["string", "null"]`.
+        Some(Value::Sequence(types)) => types
+            .iter()
+            .filter_map(Value::as_str)
+            .filter(|ty| *ty != "null")
+            .collect::<Vec<_>>()
+            .join(" | "),
+        _ if schema.get("properties").is_some() => String::from("object"),
+        _ => String::from("any"),
+    }
+}
+
+/// Parses JSON as well as YAML into a YAML value, whose mappings keep the
+/// properties in the order they are written in.
+fn parse(source: &str) -> Result<Value, ParseError> {
+    if source.trim_start().starts_with('{') {
+        return serde_json::from_str(source)
+            .map_err(|e| ParseError::new(e.line(), e.to_string()));
+    }
+
+    serde_norway::from_str(source).map_err(|e| {
+        ParseError::new(e.location().map(|l| l.line()), e.to_string())
+    })
+}
+
+/// Parses the schemas of an OpenAPI document (`components/schemas`), a
+/// Swagger document (`definitions`) or a JSON Schema file (the root schema and
+/// its `$defs`). Schemas become classes with an attribute per property;
+/// optional properties get a `[0..1]` multiplicity and arrays `[*]`.
+/// References to other schemas become associations, and references in
+/// `allOf` become generalizations.
+pub fn import(source: &str) -> Result<Document, ParseError> {
+    let root = parse(source)?;
+
+    let mut schemas = ["components", "definitions", "$defs"]
+        .iter()
+        .filter_map(|key| match *key {
+            "components" => root.get(key)?.get("schemas"),
+            key => root.get(key),
+        })
+        .filter_map(Value::as_mapping)
+        .flatten()
+        .filter_map(|(name, schema)| Some((name.as_str()?.to_string(), schema)))
+        .collect::<Vec<_>>();
+
+    // A JSON Schema file is itself a schema.
+    let is_schema = root.get("openapi").is_none()
+        && root.get("swagger").is_none()
+        && (root.get("properties").is_some() || root.get("allOf").is_some());
+
+    if is_schema {
+        let name = root
+            .get("title")
+            .and_then(Value::as_str)
+            .unwrap_or(ROOT_NAME)
+            .to_string();
+        schemas.insert(0, (name, &root));
+    }
+
+    if schemas.is_empty() {
+        return Err(ParseError::new(None, "the file contains no schemas"));
+    }
+
+    let names = schemas
+        .iter()
+        .map(|(name, _)| name.clone())
+        .collect::<Vec<_>>();
+    let mut importer = Importer::default();
+
+    for (name, schema) in &schemas {
+        importer.add_schema(name, schema, &names);
+    }
+
+    Ok(importer.into_document())
+}
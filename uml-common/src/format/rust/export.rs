@@ -0,0 +1,493 @@
+use std::collections::HashMap;
+
+use crate::{
+    document::Document,
+    elements::{Class, RelationshipKind},
+    id::Id,
+    member::{Member, Visibility},
+};
+
+const INDENT: &str = "    ";
+
+/// Keywords that can only be used as identifiers in their raw form, e.g.
+/// `r#type`.
+const KEYWORDS: [&str; 48] = [
+    "abstract", "as", "async", "await", "become", "box", "break", "const",
+    "continue", "do", "dyn", "else", "enum", "extern", "false", "final", "fn",
+    "for", "gen", "if", "impl", "in", "let", "loop", "macro", "match", "mod",
+    "move", "mut", "override", "priv", "pub", "ref", "return", "static",
+    "struct", "trait", "true", "try", "type", "typeof", "unsafe", "unsized",
+    "use", "virtual", "where", "while", "yield",
+];
+
+/// Type names commonly used in diagrams, and the Rust type they stand for.
+/// A bare `str` also stands for `String`, but `str` behind a reference is
+/// kept as it is.
+const TYPES: [(&str, &str); 11] = [
+    ("int", "i32"),
+    ("integer", "i32"),
+    ("long", "i64"),
+    ("short", "i16"),
+    ("byte", "u8"),
+    ("float", "f32"),
+    ("double", "f64"),
+    ("boolean", "bool"),
+    ("string", "String"),
+    ("List", "Vec"),
+    ("Set", "HashSet"),
+];
+
+/// Generates skeleton Rust code from the classes of a document:
+///
+/// - classes become `struct`s with their attributes as fields;
+/// - `«interface»` classes become `trait`s;
+/// - `«enumeration»` classes become `enum`s with their attributes as variants;
+/// - operations become methods with `todo!()` bodies in `impl` blocks,
+///   including an `impl Trait for Type` block for every realization.
+pub fn export(document: &Document) -> String {
+    let classes = document
+        .elements()
+        .iter()
+        .filter_map(|el| el.as_class().map(|class| (el.id(), class)))
+        .collect::<HashMap<Id, &Class>>();
+    let names = class_names(classes.values().copied());
+
+    let mut realizations: HashMap<Id, Vec<Id>> = HashMap::new();
+
+    for relationship in document
+        .elements()
+        .iter()
+        .filter_map(|el| el.as_relationship())
+    {
+        if relationship.kind() == RelationshipKind::Realization {
+            realizations
+                .entry(relationship.source())
+                .or_default()
+                .push(relationship.target());
+        }
+    }
+
+    let mut output = String::new();
+
+    for el in document.elements() {
+        let Some(class) = el.as_class() else {
+            continue;
+        };
+
+        if !output.is_empty() {
+            output += "\n";
+        }
+
+        match class.stereotype() {
+            Some("interface") => output += &write_trait(class, &names),
+            Some("enumeration") => output += &write_enum(class, &names),
+            _ => output += &write_struct(class, &names),
+        }
+
+        let operations =
+            class.operations().map(Member::parse).collect::<Vec<_>>();
+
+        if !operations.is_empty() && class.stereotype() != Some("interface") {
+            let generics = generics(class, &names);
+            output += &format!(
+                "\nimpl{} {} {{\n",
+                declare(&generics),
+                declared_type(class, &generics, &names)
+            );
+            output += &write_methods(&operations, true, &generics, &names);
+            output += "}\n";
+        }
+
+        for interface in realizations.get(&el.id()).into_iter().flatten() {
+            let Some(interface) = classes.get(interface) else {
+                continue;
+            };
+
+            let operations = interface
+                .operations()
+                .map(Member::parse)
+                .filter(|m| m.is_abstract())
+                .collect::<Vec<_>>();
+
+            // The methods declare the lifetimes of the trait themselves, so
+            // the ones of the type are left anonymous.
+            let generics = generics(class, &names)
+                .into_iter()
+                .filter(|g| !g.starts_with('\''))
+                .collect::<Vec<_>>();
+            output += &format!(
+                "\nimpl{} {} for {} {{\n",
+                declare(&generics),
+                type_name(interface.name(), &names),
+                declared_type(class, &generics, &names)
+            );
+            output += &write_methods(&operations, false, &[], &names);
+            output += "}\n";
+        }
+    }
+
+    output
+}
+
+/// The Rust name of every class, keyed by the ways members refer to it: its
+/// name with the spaces replaced by underscores, as in a Mermaid class id,
+/// and its Rust name itself.
+fn class_names<'a>(
+    classes: impl IntoIterator<Item = &'a Class>,
+) -> HashMap<String, String> {
+    let mut names = HashMap::new();
+
+    for class in classes {
+        let base = class.name().split('<').next().unwrap_or_default();
+        let rust = type_name(base, &HashMap::new());
+        let reference = base
+            .split(|c: char| !c.is_alphanumeric() && c != '_')
+            .filter(|w| !w.is_empty())
+            .collect::<Vec<_>>()
+            .join("_");
+
+        names.insert(reference, rust.clone());
+        names.insert(rust.clone(), rust);
+    }
+
+    names
+}
+
+fn write_struct(class: &Class, names: &HashMap<String, String>) -> String {
+    let attributes = class.attributes().map(Member::parse).collect::<Vec<_>>();
+    let name = declared_type(class, &generics(class, names), names);
+
+    if attributes.is_empty() {
+        return format!("pub struct {name};\n");
+    }
+
+    if attributes.iter().all(|a| a.name().parse::<usize>().is_ok()) {
+        let fields = attributes
+            .iter()
+            .map(|a| format!("{}{}", visibility(a), field_type(a.ty(), names)))
+            .collect::<Vec<_>>()
+            .join(", ");
+        return format!("pub struct {name}({fields});\n");
+    }
+
+    let mut output = format!("pub struct {name} {{\n");
+
+    for attribute in attributes {
+        output += &format!(
+            "{INDENT}{}{}: {},\n",
+            visibility(&attribute),
+            identifier(attribute.name()),
+            field_type(attribute.ty(), names)
+        );
+    }
+
+    output + "}\n"
+}
+
+fn write_enum(class: &Class, names: &HashMap<String, String>) -> String {
+    let mut output = format!(
+        "pub enum {} {{\n",
+        declared_type(class, &generics(class, names), names)
+    );
+
+    for variant in class.attributes().map(Member::parse) {
+        let name = type_name(variant.name(), names);
+
+        match variant.ty() {
+            None => output += &format!("{INDENT}{name},\n"),
+            Some(ty) if ty.starts_with('(') || ty.starts_with('{') => {
+                let separator = if ty.starts_with('{') { " " } else { "" };
+                output += &format!(
+                    "{INDENT}{name}{separator}{},\n",
+                    rust_type(ty, names)
+                );
+            }
+            Some(ty) => {
+                output +=
+                    &format!("{INDENT}{name}({}),\n", rust_type(ty, names));
+            }
+        }
+    }
+
+    output + "}\n"
+}
+
+fn write_trait(class: &Class, names: &HashMap<String, String>) -> String {
+    let mut output =
+        format!("pub trait {} {{\n", type_name(class.name(), names));
+
+    for operation in class.operations().map(Member::parse) {
+        let signature = signature(&operation, &[], names);
+
+        if operation.is_abstract() {
+            output += &format!("{INDENT}{signature};\n");
+        } else {
+            output += &format!(
+                "{INDENT}{signature} {{\n{INDENT}{INDENT}todo!()\n{INDENT}}}\n"
+            );
+        }
+    }
+
+    output + "}\n"
+}
+
+/// Writes the methods of an `impl` block, which declares `generics`.
+fn write_methods(
+    operations: &[Member],
+    with_visibility: bool,
+    generics: &[String],
+    names: &HashMap<String, String>,
+) -> String {
+    operations
+        .iter()
+        .map(|operation| {
+            let visibility = match with_visibility {
+                true => visibility(operation),
+                false => "",
+            };
+
+            format!(
+                "{INDENT}{visibility}{} {{\n{INDENT}{INDENT}todo!()\n{INDENT}}}\n",
+                signature(operation, generics, names)
+            )
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+/// Writes the signature of a method, declaring the lifetimes it uses that
+/// its `impl` block, which declares `generics`, does not.
+fn signature(
+    operation: &Member,
+    generics: &[String],
+    names: &HashMap<String, String>,
+) -> String {
+    let receiver = (!operation.is_static()).then(|| String::from("&self"));
+    let parameters = operation
+        .parameters()
+        .unwrap_or_default()
+        .iter()
+        .map(|p| {
+            let name = p.name().trim_start_matches("mut ");
+            (identifier(name), field_type(p.ty(), names))
+        })
+        .collect::<Vec<_>>();
+
+    let returns = operation
+        .ty()
+        .map(|ty| rust_type(ty, names))
+        .filter(|ty| ty != "()" && ty != "void");
+
+    let lifetimes = lifetimes(
+        parameters
+            .iter()
+            .map(|(_, ty)| ty.as_str())
+            .chain(returns.as_deref()),
+    )
+    .into_iter()
+    .filter(|lifetime| !generics.contains(lifetime))
+    .collect::<Vec<_>>();
+
+    let parameters = receiver
+        .into_iter()
+        .chain(
+            parameters
+                .into_iter()
+                .map(|(name, ty)| format!("{name}: {ty}")),
+        )
+        .collect::<Vec<_>>()
+        .join(", ");
+    let returns = returns.map(|ty| format!(" -> {ty}")).unwrap_or_default();
+
+    format!(
+        "fn {}{}({parameters}){returns}",
+        identifier(operation.name()),
+        declare(&lifetimes)
+    )
+}
+
+fn visibility(member: &Member) -> &'static str {
+    match member.visibility() {
+        Visibility::Public => "pub ",
+        Visibility::Protected | Visibility::Package => "pub(crate) ",
+        Visibility::Private | Visibility::Unspecified => "",
+    }
+}
+
+fn field_type(ty: Option<&str>, names: &HashMap<String, String>) -> String {
+    ty.map(|ty| rust_type(ty, names))
+        .unwrap_or_else(|| String::from("()"))
+}
+
+/// Replaces the type names of other languages within a type with their Rust
+/// equivalent, e.g. `List<int>` becomes `Vec<i32>`, and references to classes
+/// with the name of their Rust type, e.g. `Shopping_cart` with `ShoppingCart`.
+fn rust_type(ty: &str, names: &HashMap<String, String>) -> String {
+    let mut output = String::new();
+    let mut word = String::new();
+
+    let flush = |word: &mut String, output: &mut String| {
+        let mapped = TYPES
+            .iter()
+            .find(|(from, _)| from == word)
+            .map(|(_, to)| *to)
+            .or_else(|| names.get(word.as_str()).map(String::as_str))
+            .or_else(|| {
+                (word == "str" && !is_borrowed(output)).then_some("String")
+            })
+            .unwrap_or(word);
+        *output += mapped;
+        word.clear();
+    };
+
+    for c in ty.chars() {
+        if c.is_alphanumeric() || c == '_' {
+            word.push(c);
+        } else {
+            flush(&mut word, &mut output);
+            output.push(c);
+        }
+    }
+
+    flush(&mut word, &mut output);
+    output
+}
+
+/// Whether the type written so far ends in a reference, e.g. `&`, `&'a` or
+/// `&mut`, so that what comes next is borrowed.
+fn is_borrowed(output: &str) -> bool {
+    let rest = output.trim_end();
+    let rest = rest.strip_suffix("mut").unwrap_or(rest).trim_end();
+    let rest = rest.trim_end_matches(|c: char| c.is_alphanumeric() || c == '_');
+    let rest = rest.strip_suffix('\'').unwrap_or(rest).trim_end();
+    rest.ends_with('&')
+}
+
+/// The named lifetimes that `types` refer to, e.g. `'a` in `&'a str`.
+fn lifetimes<'a>(types: impl IntoIterator<Item = &'a str>) -> Vec<String> {
+    let mut lifetimes = vec![];
+
+    for ty in types {
+        for (start, _) in ty.match_indices('\'') {
+            let lifetime = ty[start + 1..]
+                .split(|c: char| !c.is_alphanumeric() && c != '_')
+                .next()
+                .unwrap_or_default();
+            let lifetime = format!("'{lifetime}");
+
+            if !["'", "'_", "'static"].contains(&lifetime.as_str())
+                && !lifetimes.contains(&lifetime)
+            {
+                lifetimes.push(lifetime);
+            }
+        }
+    }
+
+    lifetimes
+}
+
+/// The generic parameters of a class: the lifetimes its fields or variants
+/// borrow for, followed by the type parameters in its name.
+fn generics(class: &Class, names: &HashMap<String, String>) -> Vec<String> {
+    let types = class
+        .attributes()
+        .map(Member::parse)
+        .filter_map(|a| a.ty().map(|ty| rust_type(ty, names)))
+        .collect::<Vec<_>>();
+    let name = type_name(class.name(), names);
+    let parameters = name
+        .split_once('<')
+        .and_then(|(_, parameters)| parameters.strip_suffix('>'))
+        .into_iter()
+        .flat_map(|parameters| parameters.split(','))
+        .map(|parameter| parameter.trim().to_string());
+
+    lifetimes(types.iter().map(String::as_str))
+        .into_iter()
+        .chain(parameters)
+        .collect()
+}
+
+/// Declares `generics`, e.g. `<'a, T>`, or nothing if there are none.
+fn declare(generics: &[String]) -> String {
+    match generics.is_empty() {
+        true => String::new(),
+        false => format!("<{}>", generics.join(", ")),
+    }
+}
+
+/// The type of a class with the generic parameters of an item that declares
+/// `declared`. Lifetimes it does not declare are left anonymous.
+fn declared_type(
+    class: &Class,
+    declared: &[String],
+    names: &HashMap<String, String>,
+) -> String {
+    let name = type_name(class.name(), names);
+    let base = name.split('<').next().unwrap_or_default();
+    let arguments = generics(class, names)
+        .into_iter()
+        .map(|g| match g.starts_with('\'') && !declared.contains(&g) {
+            true => String::from("'_"),
+            false => g,
+        })
+        .collect::<Vec<_>>();
+
+    format!("{base}{}", declare(&arguments))
+}
+
+/// Turns a member name into a Rust identifier, e.g. `firstName` into
+/// `first_name` and `type` into `r#type`.
+fn identifier(name: &str) -> String {
+    let name = snake_case(name.strip_prefix("r#").unwrap_or(name));
+
+    match name.as_str() {
+        // These cannot be raw identifiers.
+        "self" | "super" | "crate" => format!("{name}_"),
+        name if KEYWORDS.contains(&name) => format!("r#{name}"),
+        _ => name,
+    }
+}
+
+/// Turns a class name such as `Shopping cart<T>` into `ShoppingCart<T>`.
+fn type_name(name: &str, names: &HashMap<String, String>) -> String {
+    let (base, generics) = match name.split_once('<') {
+        Some((base, generics)) => (base, format!("<{generics}")),
+        None => (name, String::new()),
+    };
+
+    let base = base
+        .split(|c: char| !c.is_alphanumeric() && c != '_')
+        .filter(|w| !w.is_empty())
+        .map(|w| {
+            let mut chars = w.chars();
+            chars
+                .next()
+                .map(|c| c.to_uppercase().chain(chars).collect::<String>())
+                .unwrap_or_default()
+        })
+        .collect::<String>();
+
+    format!("{base}{}", rust_type(&generics, names))
+}
+
+/// Turns a member name such as `firstName` or `first name` into `first_name`.
+fn snake_case(name: &str) -> String {
+    let mut output = String::new();
+
+    for c in name.chars() {
+        if c.is_uppercase() {
+            if !output.is_empty() && !output.ends_with('_') {
+                output.push('_');
+            }
+
+            output.extend(c.to_lowercase());
+        } else if c.is_alphanumeric() {
+            output.push(c);
+        } else if !output.is_empty() && !output.ends_with('_') {
+            output.push('_');
+        }
+    }
+
+    output.trim_end_matches('_').to_string()
+}
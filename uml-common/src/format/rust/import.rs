@@ -0,0 +1,549 @@
+//! Reverse engineering of Rust source code into class diagrams.
+
+use std::collections::HashMap;
+
+use quote::ToTokens;
+use syn::{
+    Fields, FnArg, GenericArgument, ImplItem, Item, PathArguments, ReturnType,
+    Signature, TraitItem, Type, Visibility as RustVisibility,
+};
+
+use crate::{
+    document::Document,
+    elements::{Class, Relationship, RelationshipKind},
+    format::ParseError,
+    id::Id,
+    layout,
+    member::{Member, Parameter, Visibility},
+};
+
+/// Wrappers that are looked through when following field types. The
+/// multiplicity is what the wrapper says about the number of values.
+const WRAPPERS: [(&str, Option<&str>); 13] = [
+    ("Box", None),
+    ("Rc", None),
+    ("Arc", None),
+    ("RefCell", None),
+    ("Cell", None),
+    ("Mutex", None),
+    ("RwLock", None),
+    ("Option", Some("0..1")),
+    ("Vec", Some("*")),
+    ("VecDeque", Some("*")),
+    ("HashSet", Some("*")),
+    ("BTreeSet", Some("*")),
+    ("LinkedList", Some("*")),
+];
+
+struct Association {
+    source: String,
+    target: String,
+    label: String,
+    multiplicity: Option<String>,
+}
+
+#[derive(Default)]
+struct Model {
+    classes: Vec<(String, Class)>,
+    indices: HashMap<String, usize>,
+    realizations: Vec<(String, String)>,
+    fields: Vec<(String, String, Type)>,
+}
+
+impl Model {
+    fn add_class(&mut self, ident: String, class: Class) {
+        if self.indices.contains_key(&ident) {
+            log::debug!("Type {ident} is declared more than once, skipping.");
+            return;
+        }
+
+        self.indices.insert(ident.clone(), self.classes.len());
+        self.classes.push((ident, class));
+    }
+
+    fn add_items(&mut self, items: &[Item]) {
+        for item in items {
+            match item {
+                Item::Struct(item) => {
+                    let ident = item.ident.to_string();
+                    let mut class =
+                        new_class(type_name(&ident, &item.generics));
+
+                    for (index, field) in item.fields.iter().enumerate() {
+                        let name = field
+                            .ident
+                            .as_ref()
+                            .map(|i| i.to_string())
+                            .unwrap_or_else(|| index.to_string());
+                        let member = Member::attribute(
+                            &name,
+                            Some(print_type(&field.ty)),
+                        )
+                        .with_visibility(visibility(&field.vis));
+
+                        class.add_attribute(member.to_string());
+                        self.fields.push((
+                            ident.clone(),
+                            name,
+                            field.ty.clone(),
+                        ));
+                    }
+
+                    self.add_class(ident, class);
+                }
+                Item::Enum(item) => {
+                    let ident = item.ident.to_string();
+                    let mut class =
+                        new_class(type_name(&ident, &item.generics));
+                    class.set_stereotype(Some(String::from("enumeration")));
+
+                    for variant in &item.variants {
+                        let name = variant.ident.to_string();
+                        let ty = match &variant.fields {
+                            Fields::Unit => None,
+                            Fields::Unnamed(fields) => Some(format!(
+                                "({})",
+                                fields
+                                    .unnamed
+                                    .iter()
+                                    .map(|f| print_type(&f.ty))
+                                    .collect::<Vec<_>>()
+                                    .join(", ")
+                            )),
+                            Fields::Named(fields) => Some(format!(
+                                "{{ {} }}",
+                                fields
+                                    .named
+                                    .iter()
+                                    .map(|f| format!(
+                                        "{}: {}",
+                                        f.ident.as_ref().expect("named field"),
+                                        print_type(&f.ty)
+                                    ))
+                                    .collect::<Vec<_>>()
+                                    .join(", ")
+                            )),
+                        };
+
+                        for field in &variant.fields {
+                            self.fields.push((
+                                ident.clone(),
+                                name.clone(),
+                                field.ty.clone(),
+                            ));
+                        }
+
+                        class.add_attribute(
+                            Member::attribute(name, ty).to_string(),
+                        );
+                    }
+
+                    self.add_class(ident, class);
+                }
+                Item::Trait(item) => {
+                    let ident = item.ident.to_string();
+                    let mut class =
+                        new_class(type_name(&ident, &item.generics));
+                    class.set_stereotype(Some(String::from("interface")));
+
+                    for item in &item.items {
+                        if let TraitItem::Fn(f) = item {
+                            let member = operation(&f.sig)
+                                .with_visibility(Visibility::Public)
+                                .with_abstract(f.default.is_none());
+                            class.add_operation(member.to_string());
+                        }
+                    }
+
+                    self.add_class(ident, class);
+                }
+                Item::Mod(item) => {
+                    if let Some((_, items)) = &item.content {
+                        self.add_items(items);
+                    }
+                }
+                _ => (),
+            }
+        }
+    }
+
+    /// Adds the methods of inherent `impl` blocks and records trait
+    /// implementations. Runs after every type is known, since `impl` blocks
+    /// may come before the type they implement.
+    fn add_impls(&mut self, items: &[Item]) {
+        for item in items {
+            match item {
+                Item::Impl(item) => {
+                    let Some(ident) = last_ident(&item.self_ty) else {
+                        continue;
+                    };
+
+                    if let Some((_, path, _)) = &item.trait_ {
+                        let Some(segment) = path.segments.last() else {
+                            continue;
+                        };
+
+                        self.realizations
+                            .push((ident, segment.ident.to_string()));
+                        continue;
+                    }
+
+                    let Some(index) = self.indices.get(&ident) else {
+                        continue;
+                    };
+
+                    let class = &mut self.classes[*index].1;
+
+                    for item in &item.items {
+                        if let ImplItem::Fn(f) = item {
+                            let member = operation(&f.sig)
+                                .with_visibility(visibility(&f.vis));
+                            class.add_operation(member.to_string());
+                        }
+                    }
+                }
+                Item::Mod(item) => {
+                    if let Some((_, items)) = &item.content {
+                        self.add_impls(items);
+                    }
+                }
+                _ => (),
+            }
+        }
+    }
+
+    fn associations(&self) -> Vec<Association> {
+        let mut associations = vec![];
+
+        for (source, label, ty) in &self.fields {
+            let (multiplicity, referenced) = references(ty);
+
+            for target in referenced {
+                let duplicate = associations.iter().any(|a: &Association| {
+                    a.source == *source
+                        && a.target == target
+                        && a.label == *label
+                });
+
+                if duplicate || !self.indices.contains_key(&target) {
+                    continue;
+                }
+
+                associations.push(Association {
+                    source: source.clone(),
+                    target,
+                    label: label.clone(),
+                    multiplicity: multiplicity.clone(),
+                });
+            }
+        }
+
+        associations
+    }
+
+    fn into_document(self) -> Document {
+        let associations = self.associations();
+        let mut document = Document::default();
+        let mut ids: HashMap<String, Id> = HashMap::new();
+
+        for (ident, class) in self.classes {
+            ids.insert(ident, document.add_element(class));
+        }
+
+        for (source, target) in self.realizations {
+            let (Some(source), Some(target)) =
+                (ids.get(&source), ids.get(&target))
+            else {
+                continue;
+            };
+
+            document.add_element(Relationship::new(
+                *source,
+                *target,
+                RelationshipKind::Realization,
+            ));
+        }
+
+        for association in associations {
+            let mut relationship = Relationship::new(
+                ids[&association.source],
+                ids[&association.target],
+                RelationshipKind::Association,
+            );
+            relationship.set_label(Some(association.label));
+            relationship.set_multiplicities(None, association.multiplicity);
+            document.add_element(relationship);
+        }
+
+        layout::layered(&mut document);
+        document
+    }
+}
+
+/// Reverse engineers a single Rust source file.
+pub fn import(source: &str) -> Result<Document, ParseError> {
+    import_sources([source])
+}
+
+/// Reverse engineers several Rust source files as one crate, so types can
+/// refer to each other across files.
+pub fn import_sources<'a>(
+    sources: impl IntoIterator<Item = &'a str>,
+) -> Result<Document, ParseError> {
+    let files = sources
+        .into_iter()
+        .map(|source| {
+            syn::parse_file(source).map_err(|e| {
+                ParseError::new(e.span().start().line, e.to_string())
+            })
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+
+    let mut model = Model::default();
+
+    for file in &files {
+        model.add_items(&file.items);
+    }
+
+    for file in &files {
+        model.add_impls(&file.items);
+    }
+
+    Ok(model.into_document())
+}
+
+/// Reverse engineers every `.rs` file below `path`.
+pub fn import_dir(
+    path: impl AsRef<std::path::Path>,
+) -> Result<Document, ParseError> {
+    let mut sources = vec![];
+    let mut directories = vec![path.as_ref().to_path_buf()];
+
+    while let Some(directory) = directories.pop() {
+        let entries = std::fs::read_dir(&directory).map_err(|e| {
+            ParseError::new(None, format!("{}: {e}", directory.display()))
+        })?;
+
+        for entry in entries.flatten() {
+            let path = entry.path();
+
+            if path.is_dir() {
+                directories.push(path);
+            } else if path.extension().is_some_and(|e| e == "rs") {
+                let source = std::fs::read_to_string(&path).map_err(|e| {
+                    ParseError::new(None, format!("{}: {e}", path.display()))
+                })?;
+                sources.push(source);
+            }
+        }
+    }
+
+    import_sources(sources.iter().map(String::as_str))
+}
+
+fn new_class(name: String) -> Class {
+    let mut class = Class::new(0.0, 0.0, name, None, None, None);
+    class.set_attributes(Vec::<String>::new());
+    class
+}
+
+fn type_name(ident: &str, generics: &syn::Generics) -> String {
+    let parameters = generics
+        .type_params()
+        .map(|p| p.ident.to_string())
+        .collect::<Vec<_>>();
+
+    if parameters.is_empty() {
+        ident.to_string()
+    } else {
+        format!("{ident}<{}>", parameters.join(", "))
+    }
+}
+
+fn visibility(vis: &RustVisibility) -> Visibility {
+    match vis {
+        RustVisibility::Public(_) => Visibility::Public,
+        RustVisibility::Restricted(_) => Visibility::Package,
+        RustVisibility::Inherited => Visibility::Private,
+    }
+}
+
+fn operation(sig: &Signature) -> Member {
+    let parameters = sig
+        .inputs
+        .iter()
+        .filter_map(|input| match input {
+            FnArg::Receiver(_) => None,
+            FnArg::Typed(arg) => Some(Parameter::new(
+                arg.pat.to_token_stream().to_string(),
+                Some(print_type(&arg.ty)),
+            )),
+        })
+        .collect();
+
+    let returns = match &sig.output {
+        ReturnType::Default => None,
+        ReturnType::Type(_, ty) => Some(print_type(ty)),
+    };
+
+    Member::operation(sig.ident.to_string(), parameters, returns)
+        .with_static(sig.receiver().is_none())
+}
+
+/// Prints a type the way it would be written by hand, rather than with the
+/// spacing of a token stream (`Vec < T >`).
+fn print_type(ty: &Type) -> String {
+    match ty {
+        Type::Path(path) => path
+            .path
+            .segments
+            .iter()
+            .map(|segment| {
+                let PathArguments::AngleBracketed(arguments) =
+                    &segment.arguments
+                else {
+                    return segment.ident.to_string();
+                };
+
+                let arguments = arguments
+                    .args
+                    .iter()
+                    .map(|arg| match arg {
+                        GenericArgument::Type(ty) => print_type(ty),
+                        arg => arg.to_token_stream().to_string(),
+                    })
+                    .collect::<Vec<_>>()
+                    .join(", ");
+                format!("{}<{arguments}>", segment.ident)
+            })
+            .collect::<Vec<_>>()
+            .join("::"),
+        Type::Reference(reference) => {
+            let lifetime = reference
+                .lifetime
+                .as_ref()
+                .map(|l| format!("{l} "))
+                .unwrap_or_default();
+            let mutability = match reference.mutability {
+                Some(_) => "mut ",
+                None => "",
+            };
+            format!("&{lifetime}{mutability}{}", print_type(&reference.elem))
+        }
+        Type::Slice(slice) => format!("[{}]", print_type(&slice.elem)),
+        Type::Array(array) => format!(
+            "[{}; {}]",
+            print_type(&array.elem),
+            array.len.to_token_stream()
+        ),
+        Type::Tuple(tuple) => format!(
+            "({})",
+            tuple
+                .elems
+                .iter()
+                .map(print_type)
+                .collect::<Vec<_>>()
+                .join(", ")
+        ),
+        Type::Paren(paren) => print_type(&paren.elem),
+        ty => ty.to_token_stream().to_string(),
+    }
+}
+
+fn last_ident(ty: &Type) -> Option<String> {
+    match ty {
+        Type::Path(path) => {
+            path.path.segments.last().map(|s| s.ident.to_string())
+        }
+        Type::Reference(reference) => last_ident(&reference.elem),
+        Type::Paren(paren) => last_ident(&paren.elem),
+        _ => None,
+    }
+}
+
+/// Returns the multiplicity implied by the outermost wrappers of a field type
+/// together with every type name the field refers to.
+fn references(ty: &Type) -> (Option<String>, Vec<String>) {
+    let mut multiplicity = None;
+    let mut current = ty;
+
+    loop {
+        match current {
+            Type::Reference(reference) => current = &reference.elem,
+            Type::Paren(paren) => current = &paren.elem,
+            Type::Slice(slice) => {
+                multiplicity = Some(String::from("*"));
+                current = &slice.elem;
+            }
+            Type::Array(array) => {
+                multiplicity = Some(String::from("*"));
+                current = &array.elem;
+            }
+            Type::Path(path) => {
+                let Some(segment) = path.path.segments.last() else {
+                    break;
+                };
+
+                let Some((_, wrapper)) =
+                    WRAPPERS.iter().find(|(name, _)| segment.ident == name)
+                else {
+                    break;
+                };
+
+                let PathArguments::AngleBracketed(arguments) =
+                    &segment.arguments
+                else {
+                    break;
+                };
+
+                let Some(GenericArgument::Type(inner)) = arguments.args.first()
+                else {
+                    break;
+                };
+
+                if multiplicity.is_none() {
+                    multiplicity = wrapper.map(String::from);
+                }
+
+                current = inner;
+            }
+            _ => break,
+        }
+    }
+
+    let mut names = vec![];
+    collect_names(current, &mut names);
+    (multiplicity, names)
+}
+
+fn collect_names(ty: &Type, names: &mut Vec<String>) {
+    match ty {
+        Type::Path(path) => {
+            for segment in &path.path.segments {
+                if !names.contains(&segment.ident.to_string()) {
+                    names.push(segment.ident.to_string());
+                }
+
+                if let PathArguments::AngleBracketed(arguments) =
+                    &segment.arguments
+                {
+                    for arg in &arguments.args {
+                        if let GenericArgument::Type(ty) = arg {
+                            collect_names(ty, names);
+                        }
+                    }
+                }
+            }
+        }
+        Type::Reference(reference) => collect_names(&reference.elem, names),
+        Type::Paren(paren) => collect_names(&paren.elem, names),
+        Type::Slice(slice) => collect_names(&slice.elem, names),
+        Type::Array(array) => collect_names(&array.elem, names),
+        Type::Tuple(tuple) => {
+            for ty in &tuple.elems {
+                collect_names(ty, names);
+            }
+        }
+        _ => (),
+    }
+}
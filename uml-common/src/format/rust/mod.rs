@@ -0,0 +1,10 @@
+//! Conversion between documents and Rust source code. Reverse engineering
+//! requires the `rust` feature, as it depends on a Rust parser.
+
+mod export;
+#[cfg(feature = "rust")]
+mod import;
+
+pub use export::*;
+#[cfg(feature = "rust")]
+pub use import::*;
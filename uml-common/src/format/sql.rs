@@ -0,0 +1,701 @@
+//! Import of relational schemas from SQL DDL, such as PostgreSQL migrations.
+//! `CREATE TABLE` and `ALTER TABLE ... ADD` statements are read; every other
+//! statement is skipped.
+
+use std::collections::HashMap;
+
+use crate::{
+    document::Document,
+    elements::{Class, Relationship, RelationshipKind},
+    layout,
+};
+
+use super::ParseError;
+
+/// Keywords that end the type of a column and start its constraints.
+const CONSTRAINT_KEYWORDS: [&str; 12] = [
+    "constraint",
+    "not",
+    "null",
+    "default",
+    "primary",
+    "references",
+    "unique",
+    "check",
+    "generated",
+    "collate",
+    "identity",
+    "auto_increment",
+];
+
+#[derive(Debug, Clone, PartialEq)]
+enum Token {
+    Word(String),
+    /// A quoted identifier, e.g. `"order"`.
+    Identifier(String),
+    String(String),
+    Symbol(char),
+}
+
+#[derive(Debug, Clone)]
+struct Spanned {
+    token: Token,
+    line: usize,
+}
+
+fn tokenize(source: &str) -> Result<Vec<Spanned>, ParseError> {
+    let mut tokens = vec![];
+    let mut chars = source.chars().peekable();
+    let mut line = 1;
+
+    while let Some(c) = chars.next() {
+        let start = line;
+
+        match c {
+            '\n' => line += 1,
+            c if c.is_whitespace() => (),
+            '-' if chars.peek() == Some(&'-') => {
+                while chars.next_if(|c| *c != '\n').is_some() {}
+            }
+            '/' if chars.peek() == Some(&'*') => {
+                chars.next();
+                let mut previous = ' ';
+
+                loop {
+                    match chars.next() {
+                        Some('/') if previous == '*' => break,
+                        Some(c) => {
+                            line += (c == '\n') as usize;
+                            previous = c;
+                        }
+                        None => {
+                            return Err(ParseError::new(
+                                start,
+                                "unterminated comment",
+                            ));
+                        }
+                    }
+                }
+            }
+            '"' | '`' | '\'' => {
+                let close = c;
+                let mut text = String::new();
+
+                loop {
+                    match chars.next() {
+                        // A doubled quote stands for the quote itself.
+                        Some(q)
+                            if q == close && chars.peek() == Some(&close) =>
+                        {
+                            chars.next();
+                            text.push(q);
+                        }
+                        Some(q) if q == close => break,
+                        Some(c) => {
+                            line += (c == '\n') as usize;
+                            text.push(c);
+                        }
+                        None => {
+                            return Err(ParseError::new(
+                                start,
+                                "unterminated quote",
+                            ));
+                        }
+                    }
+                }
+
+                tokens.push(Spanned {
+                    token: match c {
+                        '\'' => Token::String(text),
+                        _ => Token::Identifier(text),
+                    },
+                    line: start,
+                });
+            }
+            // Dollar quoted strings, e.g. `$$ ... $$` or `$body$ ... $body$`,
+            // which usually hold function bodies.
+            '$' if chars
+                .clone()
+                .take_while(|c| *c != '$')
+                .all(|c| c.is_alphanumeric() || c == '_')
+                && chars.clone().any(|c| c == '$') =>
+            {
+                let tag = chars
+                    .by_ref()
+                    .take_while(|c| *c != '$')
+                    .collect::<String>();
+                let delimiter = format!("${tag}$");
+                let mut text = String::new();
+
+                while !text.ends_with(&delimiter) {
+                    let Some(c) = chars.next() else {
+                        return Err(ParseError::new(
+                            start,
+                            "unterminated dollar quote",
+                        ));
+                    };
+
+                    line += (c == '\n') as usize;
+                    text.push(c);
+                }
+
+                text.truncate(text.len() - delimiter.len());
+                tokens.push(Spanned {
+                    token: Token::String(text),
+                    line: start,
+                });
+            }
+            c if c.is_alphanumeric() || c == '_' => {
+                let mut word = String::from(c);
+
+                while let Some(c) = chars
+                    .next_if(|c| c.is_alphanumeric() || matches!(c, '_' | '$'))
+                {
+                    word.push(c);
+                }
+
+                tokens.push(Spanned {
+                    token: Token::Word(word),
+                    line,
+                });
+            }
+            c => tokens.push(Spanned {
+                token: Token::Symbol(c),
+                line,
+            }),
+        }
+    }
+
+    Ok(tokens)
+}
+
+/// Writes tokens back as SQL, e.g. the type `numeric ( 10 , 2 )` becomes
+/// `numeric(10, 2)`.
+fn render(tokens: &[Spanned]) -> String {
+    let mut output = String::new();
+
+    for (index, spanned) in tokens.iter().enumerate() {
+        let text = match &spanned.token {
+            Token::Word(word) | Token::Identifier(word) => word.clone(),
+            Token::String(text) => format!("'{text}'"),
+            Token::Symbol(c) => c.to_string(),
+        };
+
+        let joined = matches!(
+            spanned.token,
+            Token::Symbol('(' | ')' | ',' | '[' | ']' | '.' | ':')
+        ) || matches!(
+            tokens.get(index.wrapping_sub(1)).map(|t| &t.token),
+            Some(Token::Symbol('(' | '[' | '.' | ':'))
+        );
+
+        if index > 0 && !joined {
+            output.push(' ');
+        }
+
+        output += &text;
+    }
+
+    output
+}
+
+struct Column {
+    name: String,
+    ty: String,
+    not_null: bool,
+    primary_key: bool,
+    unique: bool,
+}
+
+struct ForeignKey {
+    table: String,
+    columns: Vec<String>,
+    target: String,
+}
+
+#[derive(Default)]
+struct Table {
+    name: String,
+    columns: Vec<Column>,
+}
+
+#[derive(Default)]
+struct Schema {
+    tables: Vec<Table>,
+    foreign_keys: Vec<ForeignKey>,
+}
+
+impl Schema {
+    fn table_mut(&mut self, name: &str) -> Option<&mut Table> {
+        self.tables
+            .iter_mut()
+            .find(|t| t.name.eq_ignore_ascii_case(name))
+    }
+
+    fn set_primary_key(&mut self, table: &str, columns: &[String]) {
+        let Some(table) = self.table_mut(table) else {
+            return;
+        };
+
+        for column in &mut table.columns {
+            if columns.iter().any(|c| c.eq_ignore_ascii_case(&column.name)) {
+                column.primary_key = true;
+                column.not_null = true;
+            }
+        }
+    }
+
+    fn set_unique(&mut self, table: &str, columns: &[String]) {
+        // Only a constraint on a single column makes that column unique.
+        let [column] = columns else {
+            return;
+        };
+
+        if let Some(column) = self.table_mut(table).and_then(|t| {
+            t.columns
+                .iter_mut()
+                .find(|c| c.name.eq_ignore_ascii_case(column))
+        }) {
+            column.unique = true;
+        }
+    }
+}
+
+/// A cursor over the tokens of a single statement.
+struct Statement<'a> {
+    tokens: &'a [Spanned],
+    position: usize,
+}
+
+impl<'a> Statement<'a> {
+    fn peek(&self) -> Option<&'a Token> {
+        self.tokens.get(self.position).map(|t| &t.token)
+    }
+
+    fn line(&self) -> usize {
+        self.tokens
+            .get(self.position)
+            .or(self.tokens.last())
+            .map_or(1, |t| t.line)
+    }
+
+    fn is_keyword(&self, keyword: &str) -> bool {
+        matches!(self.peek(), Some(Token::Word(w)) if w.eq_ignore_ascii_case(keyword))
+    }
+
+    /// Consumes the given keywords if they come next, all of them or none.
+    fn keywords(&mut self, keywords: &[&str]) -> bool {
+        let matches = keywords.iter().enumerate().all(|(i, keyword)| {
+            matches!(
+                self.tokens.get(self.position + i).map(|t| &t.token),
+                Some(Token::Word(w)) if w.eq_ignore_ascii_case(keyword)
+            )
+        });
+
+        if matches {
+            self.position += keywords.len();
+        }
+
+        matches
+    }
+
+    fn symbol(&mut self, symbol: char) -> bool {
+        let matches = self.peek() == Some(&Token::Symbol(symbol));
+        self.position += matches as usize;
+        matches
+    }
+
+    fn expect_symbol(&mut self, symbol: char) -> Result<(), ParseError> {
+        match self.symbol(symbol) {
+            true => Ok(()),
+            false => Err(ParseError::new(
+                self.line(),
+                format!("expected `{symbol}`"),
+            )),
+        }
+    }
+
+    fn identifier(&mut self) -> Result<String, ParseError> {
+        match self.peek() {
+            Some(Token::Word(name) | Token::Identifier(name)) => {
+                self.position += 1;
+                Ok(name.clone())
+            }
+            _ => Err(ParseError::new(self.line(), "expected a name")),
+        }
+    }
+
+    /// A possibly schema qualified name, of which only the last part is kept.
+    fn qualified_name(&mut self) -> Result<String, ParseError> {
+        let mut name = self.identifier()?;
+
+        while self.symbol('.') {
+            name = self.identifier()?;
+        }
+
+        Ok(name)
+    }
+
+    /// A parenthesized, comma separated list of column names.
+    fn column_list(&mut self) -> Result<Vec<String>, ParseError> {
+        self.expect_symbol('(')?;
+        let mut columns = vec![self.identifier()?];
+
+        while self.symbol(',') {
+            columns.push(self.identifier()?);
+        }
+
+        self.expect_symbol(')')?;
+        Ok(columns)
+    }
+
+    /// Skips to the end of the current table element, including anything
+    /// nested in parentheses.
+    fn skip_element(&mut self) {
+        let mut depth = 0;
+
+        while let Some(token) = self.peek() {
+            match token {
+                Token::Symbol('(') => depth += 1,
+                Token::Symbol(')') if depth == 0 => return,
+                Token::Symbol(')') => depth -= 1,
+                Token::Symbol(',') if depth == 0 => return,
+                _ => (),
+            }
+
+            self.position += 1;
+        }
+    }
+
+    /// Skips to after the parenthesis closing the one just consumed.
+    fn skip_group(&mut self) -> Result<(), ParseError> {
+        let mut depth = 1;
+
+        while depth > 0 {
+            match self.peek() {
+                Some(Token::Symbol('(')) => depth += 1,
+                Some(Token::Symbol(')')) => depth -= 1,
+                Some(_) => (),
+                None => {
+                    return Err(ParseError::new(self.line(), "expected `)`"));
+                }
+            }
+
+            self.position += 1;
+        }
+
+        Ok(())
+    }
+
+    /// Reads `REFERENCES table [(columns)]` and any actions that follow it.
+    fn references(&mut self) -> Result<String, ParseError> {
+        let table = self.qualified_name()?;
+
+        if self.peek() == Some(&Token::Symbol('(')) {
+            self.column_list()?;
+        }
+
+        Ok(table)
+    }
+}
+
+fn parse_create_table(
+    statement: &mut Statement,
+    schema: &mut Schema,
+) -> Result<(), ParseError> {
+    statement.keywords(&["if", "not", "exists"]);
+    let name = statement.qualified_name()?;
+
+    // `CREATE TABLE ... AS SELECT` and `PARTITION OF` have no column list.
+    if !statement.symbol('(') {
+        return Ok(());
+    }
+
+    schema.tables.push(Table {
+        name: name.clone(),
+        ..Default::default()
+    });
+
+    loop {
+        if statement.symbol(')') {
+            break;
+        }
+
+        parse_table_element(statement, schema, &name)?;
+
+        if !statement.symbol(',') {
+            statement.expect_symbol(')')?;
+            break;
+        }
+    }
+
+    Ok(())
+}
+
+fn parse_table_element(
+    statement: &mut Statement,
+    schema: &mut Schema,
+    table: &str,
+) -> Result<(), ParseError> {
+    if statement.keywords(&["constraint"]) {
+        statement.identifier()?;
+    }
+
+    if parse_table_constraint(statement, schema, table)? {
+        return Ok(());
+    }
+
+    if statement.keywords(&["like"]) || statement.keywords(&["exclude"]) {
+        statement.skip_element();
+        return Ok(());
+    }
+
+    let name = statement.identifier()?;
+    let start = statement.position;
+
+    while statement.peek().is_some_and(|token| match token {
+        Token::Word(w) => !CONSTRAINT_KEYWORDS
+            .iter()
+            .any(|k| w.eq_ignore_ascii_case(k)),
+        Token::Symbol(',' | ')') => false,
+        _ => true,
+    }) {
+        // Skip over type arguments such as `numeric(10, 2)`.
+        if statement.symbol('(') {
+            statement.skip_group()?;
+        } else {
+            statement.position += 1;
+        }
+    }
+
+    let ty = render(&statement.tokens[start..statement.position]);
+    let mut column = Column {
+        name,
+        ty,
+        not_null: false,
+        primary_key: false,
+        unique: false,
+    };
+
+    while !matches!(statement.peek(), None | Some(Token::Symbol(',' | ')'))) {
+        if statement.keywords(&["constraint"]) {
+            statement.identifier()?;
+        } else if statement.keywords(&["not", "null"]) {
+            column.not_null = true;
+        } else if statement.keywords(&["primary", "key"]) {
+            column.primary_key = true;
+            column.not_null = true;
+        } else if statement.keywords(&["unique"]) {
+            column.unique = true;
+        } else if statement.keywords(&["references"]) {
+            let target = statement.references()?;
+            schema.foreign_keys.push(ForeignKey {
+                table: table.to_string(),
+                columns: vec![column.name.clone()],
+                target,
+            });
+        } else if statement.symbol('(') {
+            statement.skip_group()?;
+        } else {
+            statement.position += 1;
+        }
+    }
+
+    if let Some(table) = schema.table_mut(table) {
+        table.columns.push(column);
+    }
+
+    Ok(())
+}
+
+/// Reads a table constraint (`PRIMARY KEY`, `FOREIGN KEY`, `UNIQUE` or
+/// `CHECK`), returning whether there was one.
+fn parse_table_constraint(
+    statement: &mut Statement,
+    schema: &mut Schema,
+    table: &str,
+) -> Result<bool, ParseError> {
+    if statement.keywords(&["primary", "key"]) {
+        let columns = statement.column_list()?;
+        schema.set_primary_key(table, &columns);
+    } else if statement.keywords(&["foreign", "key"]) {
+        let columns = statement.column_list()?;
+
+        if !statement.keywords(&["references"]) {
+            return Err(ParseError::new(
+                statement.line(),
+                "expected REFERENCES",
+            ));
+        }
+
+        let target = statement.references()?;
+        schema.foreign_keys.push(ForeignKey {
+            table: table.to_string(),
+            columns,
+            target,
+        });
+    } else if statement.keywords(&["unique"]) {
+        statement.keywords(&["nulls", "not", "distinct"]);
+        let columns = statement.column_list()?;
+        schema.set_unique(table, &columns);
+    } else if statement.is_keyword("check") {
+        statement.position += 1;
+    } else {
+        return Ok(false);
+    }
+
+    statement.skip_element();
+    Ok(true)
+}
+
+fn parse_alter_table(
+    statement: &mut Statement,
+    schema: &mut Schema,
+) -> Result<(), ParseError> {
+    statement.keywords(&["if", "exists"]);
+    statement.keywords(&["only"]);
+    let table = statement.qualified_name()?;
+
+    loop {
+        if statement.keywords(&["add"]) {
+            if statement.keywords(&["constraint"]) {
+                statement.identifier()?;
+            }
+
+            if !parse_table_constraint(statement, schema, &table)? {
+                statement.skip_element();
+            }
+        } else {
+            statement.skip_element();
+        }
+
+        if !statement.symbol(',') {
+            return Ok(());
+        }
+    }
+}
+
+/// Parses the tables of a schema. Tables become classes with a typed
+/// attribute per column; primary and foreign key columns are marked with
+/// `«PK»` and `«FK»`, and nullable columns get a `[0..1]` multiplicity.
+/// Foreign keys become associations from the referencing table.
+pub fn import(source: &str) -> Result<Document, ParseError> {
+    let tokens = tokenize(source)?;
+    let mut schema = Schema::default();
+
+    for tokens in tokens.split(|t| t.token == Token::Symbol(';')) {
+        let mut statement = Statement {
+            tokens,
+            position: 0,
+        };
+
+        if statement.keywords(&["create"]) {
+            statement.keywords(&["or", "replace"]);
+
+            while ["global", "local", "temp", "temporary", "unlogged"]
+                .iter()
+                .any(|k| statement.is_keyword(k))
+            {
+                statement.position += 1;
+            }
+
+            if statement.keywords(&["table"]) {
+                parse_create_table(&mut statement, &mut schema)?;
+            }
+        } else if statement.keywords(&["alter", "table"]) {
+            parse_alter_table(&mut statement, &mut schema)?;
+        }
+    }
+
+    if schema.tables.is_empty() {
+        return Err(ParseError::new(None, "no CREATE TABLE statements found"));
+    }
+
+    let mut document = Document::default();
+    let mut ids = HashMap::new();
+
+    for table in &schema.tables {
+        let foreign = |column: &Column| {
+            schema.foreign_keys.iter().any(|fk| {
+                fk.table.eq_ignore_ascii_case(&table.name)
+                    && fk
+                        .columns
+                        .iter()
+                        .any(|c| c.eq_ignore_ascii_case(&column.name))
+            })
+        };
+
+        let attributes = table
+            .columns
+            .iter()
+            .map(|column| {
+                let keys = match (column.primary_key, foreign(column)) {
+                    (true, true) => "«PK, FK» ",
+                    (true, false) => "«PK» ",
+                    (false, true) => "«FK» ",
+                    (false, false) => "",
+                };
+                let multiplicity = match column.not_null {
+                    true => "",
+                    false => " [0..1]",
+                };
+
+                format!("{keys}{}: {}{multiplicity}", column.name, column.ty)
+            })
+            .collect::<Vec<_>>();
+
+        let mut class =
+            Class::new(0.0, 0.0, table.name.clone(), None, None, None);
+        class.set_attributes(attributes);
+        ids.insert(table.name.to_lowercase(), document.add_element(class));
+    }
+
+    for fk in &schema.foreign_keys {
+        let (Some(source), Some(target)) = (
+            ids.get(&fk.table.to_lowercase()),
+            ids.get(&fk.target.to_lowercase()),
+        ) else {
+            continue;
+        };
+
+        let columns = schema
+            .tables
+            .iter()
+            .find(|t| t.name.eq_ignore_ascii_case(&fk.table))
+            .map(|t| {
+                t.columns
+                    .iter()
+                    .filter(|c| {
+                        fk.columns
+                            .iter()
+                            .any(|n| n.eq_ignore_ascii_case(&c.name))
+                    })
+                    .collect::<Vec<_>>()
+            })
+            .unwrap_or_default();
+
+        // A row references at most one row of the target, which is required
+        // unless the key may be null. A target row is referenced by any
+        // number of rows, unless the key is unique.
+        let target_multiplicity = match columns.iter().all(|c| c.not_null) {
+            true => "1",
+            false => "0..1",
+        };
+        let source_multiplicity = match columns.as_slice() {
+            [column] if column.unique || column.primary_key => "0..1",
+            _ => "*",
+        };
+
+        let mut relationship =
+            Relationship::new(*source, *target, RelationshipKind::Association);
+        relationship.set_label(Some(fk.columns.join(", ")));
+        relationship.set_multiplicities(
+            Some(source_multiplicity.to_string()),
+            Some(target_multiplicity.to_string()),
+        );
+        document.add_element(relationship);
+    }
+
+    layout::layered(&mut document);
+    Ok(document)
+}
@@ -0,0 +1,758 @@
+//! Conversion between documents and XMI 2.x, limited to the class diagram
+//! subset of UML. Positions are written as UML diagram interchange shapes and
+//! read from either UML diagram interchange or Enterprise Architect diagrams.
+
+use std::collections::HashMap;
+
+use roxmltree::Node;
+
+use crate::{
+    document::Document,
+    drawable::Drawable,
+    elements::{Class, Relationship, RelationshipKind},
+    id::Id,
+    interaction::Interactive,
+    layout,
+    member::{Member, Parameter, Visibility},
+};
+
+use super::{ParseError, Writer, escape_xml};
+
+const HEADER: &str = r#"<?xml version="1.0" encoding="UTF-8"?>
+<xmi:XMI xmi:version="2.1" xmlns:xmi="http://schema.omg.org/spec/XMI/2.1" xmlns:uml="http://www.omg.org/spec/UML/20090901" xmlns:umldi="http://www.omg.org/spec/UML/20131001/UMLDI" xmlns:dc="http://www.omg.org/spec/DD/20131001/DC">
+"#;
+
+/// Writes the classes and relationships of a document as an XMI model,
+/// followed by a class diagram holding the position of every class.
+pub fn export(document: &Document) -> String {
+    let classes = document
+        .elements()
+        .iter()
+        .filter_map(|el| el.as_class().map(|class| (el.id(), class)))
+        .collect::<Vec<_>>();
+    let relationships = document
+        .elements()
+        .iter()
+        .filter_map(|el| el.as_relationship().map(|r| (el.id(), r)))
+        .filter(|(_, r)| {
+            classes.iter().any(|(id, _)| *id == r.source())
+                && classes.iter().any(|(id, _)| *id == r.target())
+        })
+        .collect::<Vec<_>>();
+
+    let names = classes
+        .iter()
+        .map(|(id, class)| (class.name().to_string(), xmi_id(*id)))
+        .collect::<HashMap<_, _>>();
+    let mut types: Vec<String> = vec![];
+    let mut type_ref = |ty: &str| match names.get(ty) {
+        Some(id) => id.clone(),
+        None => {
+            if !types.iter().any(|t| t == ty) {
+                types.push(ty.to_string());
+            }
+
+            type_id(ty)
+        }
+    };
+
+    let mut w = Writer::new(HEADER, 1);
+    w.open(r#"<uml:Model xmi:type="uml:Model" xmi:id="model" name="Model">"#);
+
+    for (id, class) in &classes {
+        let (kind, is_abstract) = match class.stereotype() {
+            Some("interface") => ("Interface", false),
+            Some("enumeration") => ("Enumeration", false),
+            Some("abstract") => ("Class", true),
+            _ => ("Class", false),
+        };
+
+        w.open(&format!(
+            r#"<packagedElement xmi:type="uml:{kind}" xmi:id="{}" name="{}" isAbstract="{is_abstract}">"#,
+            xmi_id(*id),
+            escape_xml(class.name())
+        ));
+
+        for (index, text) in class.attributes().enumerate() {
+            let attribute = Member::parse(text);
+
+            if kind == "Enumeration" {
+                w.line(&format!(
+                    r#"<ownedLiteral xmi:type="uml:EnumerationLiteral" xmi:id="{}_l{index}" name="{}"/>"#,
+                    xmi_id(*id),
+                    escape_xml(attribute.name())
+                ));
+                continue;
+            }
+
+            let ty = attribute
+                .ty()
+                .map(|ty| format!(r#" type="{}""#, type_ref(ty)))
+                .unwrap_or_default();
+            w.line(&format!(
+                r#"<ownedAttribute xmi:type="uml:Property" xmi:id="{}_a{index}" name="{}"{}{ty} isStatic="{}"/>"#,
+                xmi_id(*id),
+                escape_xml(attribute.name()),
+                visibility_attribute(attribute.visibility()),
+                attribute.is_static()
+            ));
+        }
+
+        for (index, text) in class.operations().enumerate() {
+            let operation = Member::parse(text);
+            let operation_id = format!("{}_o{index}", xmi_id(*id));
+
+            w.open(&format!(
+                r#"<ownedOperation xmi:type="uml:Operation" xmi:id="{operation_id}" name="{}"{} isStatic="{}" isAbstract="{}">"#,
+                escape_xml(operation.name()),
+                visibility_attribute(operation.visibility()),
+                operation.is_static(),
+                operation.is_abstract()
+            ));
+
+            let parameters = operation.parameters().unwrap_or_default();
+
+            for (index, parameter) in parameters.iter().enumerate() {
+                let ty = parameter
+                    .ty()
+                    .map(|ty| format!(r#" type="{}""#, type_ref(ty)))
+                    .unwrap_or_default();
+                w.line(&format!(
+                    r#"<ownedParameter xmi:type="uml:Parameter" xmi:id="{operation_id}_p{index}" name="{}" direction="in"{ty}/>"#,
+                    escape_xml(parameter.name())
+                ));
+            }
+
+            if let Some(ty) = operation.ty() {
+                w.line(&format!(
+                    r#"<ownedParameter xmi:type="uml:Parameter" xmi:id="{operation_id}_r" direction="return" type="{}"/>"#,
+                    type_ref(ty)
+                ));
+            }
+
+            w.close("</ownedOperation>");
+        }
+
+        for (relationship_id, relationship) in &relationships {
+            if relationship.source() != *id {
+                continue;
+            }
+
+            match relationship.kind() {
+                RelationshipKind::Generalization => w.line(&format!(
+                    r#"<generalization xmi:type="uml:Generalization" xmi:id="{}" general="{}"/>"#,
+                    xmi_id(*relationship_id),
+                    xmi_id(relationship.target())
+                )),
+                RelationshipKind::Realization => w.line(&format!(
+                    r#"<interfaceRealization xmi:type="uml:InterfaceRealization" xmi:id="{}" client="{}" supplier="{2}" contract="{2}"/>"#,
+                    xmi_id(*relationship_id),
+                    xmi_id(*id),
+                    xmi_id(relationship.target())
+                )),
+                _ => (),
+            }
+        }
+
+        w.close("</packagedElement>");
+    }
+
+    for (id, relationship) in &relationships {
+        write_relationship(&mut w, *id, relationship);
+    }
+
+    for ty in &types {
+        w.line(&format!(
+            r#"<packagedElement xmi:type="uml:PrimitiveType" xmi:id="{}" name="{}"/>"#,
+            type_id(ty),
+            escape_xml(ty)
+        ));
+    }
+
+    w.close("</uml:Model>");
+    w.open(
+        r#"<umldi:UMLDiagram xmi:type="umldi:UMLClassDiagram" xmi:id="diagram" name="Class diagram" modelElement="model">"#,
+    );
+
+    for el in document.elements() {
+        if el.as_class().is_none() {
+            continue;
+        }
+
+        let (l, t, r, b) = el.bounds().edges();
+        let (width, height) = match (r - l, b - t) {
+            (0.0, _) | (_, 0.0) => layout::estimated_size(el),
+            (width, height) => (width, height),
+        };
+
+        w.open(&format!(
+            r#"<ownedElement xmi:type="umldi:UMLShape" xmi:id="{}_shape" modelElement="{0}">"#,
+            xmi_id(el.id())
+        ));
+        w.line(&format!(
+            r#"<bounds xmi:type="dc:Bounds" x="{}" y="{}" width="{width}" height="{height}"/>"#,
+            el.x(),
+            el.y()
+        ));
+        w.close("</ownedElement>");
+    }
+
+    w.close("</umldi:UMLDiagram>");
+    w.finish("</xmi:XMI>\n")
+}
+
+fn write_relationship(w: &mut Writer, id: Id, relationship: &Relationship) {
+    let kind = relationship.kind();
+    let name = relationship
+        .label()
+        .map(|l| format!(r#" name="{}""#, escape_xml(l)))
+        .unwrap_or_default();
+
+    match kind {
+        RelationshipKind::Generalization | RelationshipKind::Realization => {}
+        RelationshipKind::Dependency
+        | RelationshipKind::DashedLink
+        | RelationshipKind::Include
+        | RelationshipKind::Extend
+        | RelationshipKind::Import
+        | RelationshipKind::Merge
+        | RelationshipKind::Uses
+        | RelationshipKind::Transition
+        | RelationshipKind::ControlFlow => {
+            w.line(&format!(
+                r#"<packagedElement xmi:type="uml:Dependency" xmi:id="{}"{name} client="{}" supplier="{}"/>"#,
+                xmi_id(id),
+                xmi_id(relationship.source()),
+                xmi_id(relationship.target())
+            ));
+        }
+        RelationshipKind::Association
+        | RelationshipKind::Link
+        | RelationshipKind::CommunicationPath
+        | RelationshipKind::EntityRelationship
+        | RelationshipKind::Aggregation
+        | RelationshipKind::Composition => {
+            let id = xmi_id(id);
+            let navigable = match kind {
+                RelationshipKind::Link
+                | RelationshipKind::CommunicationPath
+                | RelationshipKind::EntityRelationship => String::new(),
+                _ => format!(r#" navigableOwnedEnd="{id}_target""#),
+            };
+
+            w.open(&format!(
+                r#"<packagedElement xmi:type="uml:Association" xmi:id="{id}"{name} memberEnd="{id}_source {id}_target"{navigable}>"#
+            ));
+
+            // The aggregation kind belongs to the end typed by the part, which
+            // is the source of the relationship.
+            let aggregation = match kind {
+                RelationshipKind::Aggregation => r#" aggregation="shared""#,
+                RelationshipKind::Composition => r#" aggregation="composite""#,
+                _ => "",
+            };
+
+            for (end, ty, multiplicity, aggregation) in [
+                (
+                    "source",
+                    relationship.source(),
+                    relationship.source_multiplicity(),
+                    aggregation,
+                ),
+                (
+                    "target",
+                    relationship.target(),
+                    relationship.target_multiplicity(),
+                    "",
+                ),
+            ] {
+                let Some(multiplicity) = multiplicity else {
+                    w.line(&format!(
+                        r#"<ownedEnd xmi:type="uml:Property" xmi:id="{id}_{end}" type="{}" association="{id}"{aggregation}/>"#,
+                        xmi_id(ty)
+                    ));
+                    continue;
+                };
+
+                w.open(&format!(
+                    r#"<ownedEnd xmi:type="uml:Property" xmi:id="{id}_{end}" type="{}" association="{id}"{aggregation}>"#,
+                    xmi_id(ty)
+                ));
+
+                let (lower, upper) = match multiplicity.split_once("..") {
+                    Some((lower, upper)) => (Some(lower), upper),
+                    None => match multiplicity {
+                        "*" => (None, "*"),
+                        m => (Some(m), m),
+                    },
+                };
+
+                if let Some(lower) = lower {
+                    w.line(&format!(
+                        r#"<lowerValue xmi:type="uml:LiteralInteger" xmi:id="{id}_{end}_lower" value="{}"/>"#,
+                        escape_xml(lower)
+                    ));
+                }
+
+                w.line(&format!(
+                    r#"<upperValue xmi:type="uml:LiteralUnlimitedNatural" xmi:id="{id}_{end}_upper" value="{}"/>"#,
+                    escape_xml(upper)
+                ));
+                w.close("</ownedEnd>");
+            }
+
+            w.close("</packagedElement>");
+        }
+    }
+}
+
+fn xmi_id(id: Id) -> String {
+    format!("id{id}")
+}
+
+fn type_id(ty: &str) -> String {
+    let ty = ty
+        .chars()
+        .map(|c| if c.is_alphanumeric() { c } else { '_' })
+        .collect::<String>();
+    format!("type_{ty}")
+}
+
+fn visibility_attribute(visibility: Visibility) -> &'static str {
+    match visibility {
+        Visibility::Public => r#" visibility="public""#,
+        Visibility::Private => r#" visibility="private""#,
+        Visibility::Protected => r#" visibility="protected""#,
+        Visibility::Package => r#" visibility="package""#,
+        Visibility::Unspecified => "",
+    }
+}
+
+/// Returns an attribute from the XMI namespace (e.g. `xmi:id`), whichever
+/// version of XMI the document uses.
+fn xmi_attribute<'a>(node: Node<'a, '_>, name: &str) -> Option<&'a str> {
+    node.attributes()
+        .find(|a| {
+            a.name() == name
+                && a.namespace()
+                    .is_some_and(|ns| ns.to_lowercase().contains("xmi"))
+        })
+        .map(|a| a.value())
+}
+
+/// The `xmi:type` of a node without its namespace prefix (e.g. `Class`).
+fn xmi_type<'a>(node: Node<'a, '_>) -> Option<&'a str> {
+    xmi_attribute(node, "type").map(|t| t.rsplit(':').next().unwrap_or(t))
+}
+
+fn children<'a, 'input>(
+    node: Node<'a, 'input>,
+    name: &'a str,
+) -> impl Iterator<Item = Node<'a, 'input>> {
+    node.children()
+        .filter(move |c| c.is_element() && c.tag_name().name() == name)
+}
+
+/// Resolves a reference to an element, given either as an attribute
+/// (`type="id"`) or as a child (`<type xmi:idref="id"/>` or
+/// `<type href="file#id"/>`).
+fn reference<'a>(node: Node<'a, '_>, name: &str) -> Option<&'a str> {
+    // `Node::attribute` ignores namespaces, which would confuse `type` with
+    // `xmi:type`.
+    let value = node
+        .attributes()
+        .find(|a| a.name() == name && a.namespace().is_none());
+
+    if let Some(value) = value {
+        return Some(value.value());
+    }
+
+    let child = node
+        .children()
+        .find(|c| c.is_element() && c.tag_name().name() == name)?;
+
+    xmi_attribute(child, "idref").or_else(|| {
+        child
+            .attribute("href")
+            .map(|href| href.rsplit('#').next().unwrap_or(href))
+    })
+}
+
+/// A relationship between two classes of the model, by their XMI ids. It is
+/// added to the document once every class is known.
+struct PendingRelationship<'a> {
+    kind: RelationshipKind,
+    source: &'a str,
+    target: &'a str,
+    name: Option<String>,
+    multiplicities: (Option<String>, Option<String>),
+}
+
+impl<'a> PendingRelationship<'a> {
+    fn new(kind: RelationshipKind, source: &'a str, target: &'a str) -> Self {
+        Self {
+            kind,
+            source,
+            target,
+            name: None,
+            multiplicities: (None, None),
+        }
+    }
+}
+
+#[derive(Default)]
+struct Importer<'a> {
+    names: HashMap<&'a str, String>,
+    classes: Vec<(&'a str, Class)>,
+    relationships: Vec<PendingRelationship<'a>>,
+    positions: HashMap<&'a str, (f64, f64)>,
+}
+
+impl<'a> Importer<'a> {
+    fn type_name(&self, node: Node<'a, '_>) -> Option<String> {
+        let reference = reference(node, "type")?;
+
+        let name = self.names.get(reference).cloned().unwrap_or_else(|| {
+            // Enterprise Architect refers to types it does not define by
+            // prefixed identifiers like `EAJava_int`.
+            match reference.strip_prefix("EA") {
+                Some(rest) => rest.split_once('_').map_or(rest, |(_, t)| t),
+                None => reference,
+            }
+            .to_string()
+        });
+
+        Some(name)
+    }
+
+    fn collect_names(&mut self, node: Node<'a, '_>) {
+        for node in node.descendants() {
+            if let (Some(id), Some(name)) =
+                (xmi_attribute(node, "id"), node.attribute("name"))
+            {
+                self.names.insert(id, name.to_string());
+            }
+        }
+    }
+
+    fn add_classifier(&mut self, node: Node<'a, '_>, kind: &str) {
+        let (Some(id), Some(name)) =
+            (xmi_attribute(node, "id"), node.attribute("name"))
+        else {
+            return;
+        };
+
+        let mut class =
+            Class::new(0.0, 0.0, name.to_string(), None, None, None);
+        class.set_attributes(Vec::<String>::new());
+
+        match kind {
+            "Interface" => {
+                class.set_stereotype(Some(String::from("interface")))
+            }
+            "Enumeration" => {
+                class.set_stereotype(Some(String::from("enumeration")))
+            }
+            _ if node.attribute("isAbstract") == Some("true") => {
+                class.set_stereotype(Some(String::from("abstract")))
+            }
+            _ => (),
+        }
+
+        for literal in children(node, "ownedLiteral") {
+            if let Some(name) = literal.attribute("name") {
+                class.add_attribute(name);
+            }
+        }
+
+        for attribute in children(node, "ownedAttribute") {
+            // Association ends owned by the class are drawn as relationships.
+            let Some(name) = attribute.attribute("name") else {
+                continue;
+            };
+
+            if attribute.attribute("association").is_some() {
+                continue;
+            }
+
+            let member = Member::attribute(name, self.type_name(attribute))
+                .with_visibility(visibility(attribute))
+                .with_static(attribute.attribute("isStatic") == Some("true"));
+            class.add_attribute(member.to_string());
+        }
+
+        for operation in children(node, "ownedOperation") {
+            let Some(name) = operation.attribute("name") else {
+                continue;
+            };
+
+            let mut parameters = vec![];
+            let mut returns = None;
+
+            for parameter in children(operation, "ownedParameter") {
+                let ty = self.type_name(parameter);
+
+                if parameter.attribute("direction") == Some("return") {
+                    returns = ty;
+                } else {
+                    let name = parameter.attribute("name").unwrap_or_default();
+                    parameters.push(Parameter::new(name, ty));
+                }
+            }
+
+            let member = Member::operation(name, parameters, returns)
+                .with_visibility(visibility(operation))
+                .with_static(operation.attribute("isStatic") == Some("true"))
+                .with_abstract(
+                    operation.attribute("isAbstract") == Some("true"),
+                );
+            class.add_operation(member.to_string());
+        }
+
+        for generalization in children(node, "generalization") {
+            if let Some(general) = reference(generalization, "general") {
+                self.relationships.push(PendingRelationship::new(
+                    RelationshipKind::Generalization,
+                    id,
+                    general,
+                ));
+            }
+        }
+
+        for realization in children(node, "interfaceRealization") {
+            if let Some(contract) = reference(realization, "contract")
+                .or_else(|| reference(realization, "supplier"))
+            {
+                self.relationships.push(PendingRelationship::new(
+                    RelationshipKind::Realization,
+                    id,
+                    contract,
+                ));
+            }
+        }
+
+        self.classes.push((id, class));
+    }
+
+    fn add_association(&mut self, node: Node<'a, '_>) {
+        let ends = node
+            .attribute("memberEnd")
+            .map(|ends| ends.split_whitespace().collect::<Vec<_>>())
+            .unwrap_or_default();
+
+        // Ends are either owned by the association or by the classes, in
+        // which case they are found by their identifier.
+        let document = node.document();
+        let ends = ends
+            .iter()
+            .filter_map(|end| {
+                document
+                    .descendants()
+                    .find(|n| xmi_attribute(*n, "id") == Some(*end))
+            })
+            .collect::<Vec<_>>();
+
+        let [first, second] = ends[..] else {
+            return;
+        };
+
+        let navigable = node
+            .attribute("navigableOwnedEnd")
+            .map(|ends| ends.split_whitespace().collect::<Vec<_>>())
+            .unwrap_or_default();
+        let is_navigable = |end: Node| {
+            end.attribute("isNavigable") == Some("true")
+                || xmi_attribute(end, "id")
+                    .is_some_and(|id| navigable.contains(&id))
+                || end.parent().is_some_and(|p| p != node)
+        };
+
+        let aggregation = |end: Node| match end.attribute("aggregation") {
+            Some("composite") => Some(RelationshipKind::Composition),
+            Some("shared") => Some(RelationshipKind::Aggregation),
+            _ => None,
+        };
+
+        // The end marked with an aggregation kind is typed by the part, which
+        // becomes the source. Otherwise the navigable end is the target.
+        let (source, target, kind) =
+            match (aggregation(first), aggregation(second)) {
+                (Some(kind), _) => (first, second, kind),
+                (None, Some(kind)) => (second, first, kind),
+                (None, None) => {
+                    match (is_navigable(first), is_navigable(second)) {
+                        (true, false) => {
+                            (second, first, RelationshipKind::Association)
+                        }
+                        (false, true) => {
+                            (first, second, RelationshipKind::Association)
+                        }
+                        _ => (first, second, RelationshipKind::Link),
+                    }
+                }
+            };
+
+        let (Some(source_type), Some(target_type)) =
+            (reference(source, "type"), reference(target, "type"))
+        else {
+            return;
+        };
+
+        self.relationships.push(PendingRelationship {
+            name: node.attribute("name").map(String::from),
+            multiplicities: (multiplicity(source), multiplicity(target)),
+            ..PendingRelationship::new(kind, source_type, target_type)
+        });
+    }
+
+    fn add_dependency(&mut self, node: Node<'a, '_>) {
+        if let (Some(client), Some(supplier)) =
+            (reference(node, "client"), reference(node, "supplier"))
+        {
+            self.relationships.push(PendingRelationship {
+                name: node.attribute("name").map(String::from),
+                ..PendingRelationship::new(
+                    RelationshipKind::Dependency,
+                    client,
+                    supplier,
+                )
+            });
+        }
+    }
+
+    fn add_position(&mut self, node: Node<'a, '_>) {
+        // UML diagram interchange: a shape with a bounds child.
+        if let Some(element) = node.attribute("modelElement") {
+            let bounds = node
+                .children()
+                .find(|c| c.tag_name().name().eq_ignore_ascii_case("bounds"));
+
+            if let Some(bounds) = bounds {
+                let coordinate = |name| {
+                    bounds
+                        .attribute(name)
+                        .and_then(|v: &str| v.parse::<f64>().ok())
+                        .unwrap_or(0.0)
+                };
+                self.positions
+                    .insert(element, (coordinate("x"), coordinate("y")));
+            }
+        }
+
+        // Enterprise Architect: `geometry="Left=10;Top=20;Right=...;"`.
+        if let (Some(element), Some(geometry)) =
+            (node.attribute("subject"), node.attribute("geometry"))
+        {
+            let value = |key: &str| {
+                geometry
+                    .split(';')
+                    .filter_map(|pair| pair.split_once('='))
+                    .find(|(k, _)| k.eq_ignore_ascii_case(key))
+                    .and_then(|(_, v)| v.parse::<f64>().ok())
+            };
+
+            if let (Some(left), Some(top)) = (value("Left"), value("Top")) {
+                self.positions.insert(element, (left, top));
+            }
+        }
+    }
+}
+
+/// Parses the classes and relationships of an XMI 2.x model. Classes without
+/// diagram interchange coordinates are laid out automatically.
+pub fn import(source: &str) -> Result<Document, ParseError> {
+    let xml = roxmltree::Document::parse(source)
+        .map_err(|e| ParseError::new(e.pos().row as usize, e.to_string()))?;
+
+    let mut importer = Importer::default();
+    importer.collect_names(xml.root());
+
+    for node in xml.descendants() {
+        match xmi_type(node) {
+            Some(kind @ ("Class" | "Interface" | "Enumeration")) => {
+                importer.add_classifier(node, kind)
+            }
+            Some("Association") => importer.add_association(node),
+            Some("Dependency" | "Usage" | "Abstraction") => {
+                importer.add_dependency(node)
+            }
+            _ => (),
+        }
+
+        importer.add_position(node);
+    }
+
+    if importer.classes.is_empty() {
+        return Err(ParseError::new(None, "the model contains no classes"));
+    }
+
+    let mut document = Document::default();
+    let mut ids = HashMap::new();
+    let positioned = importer
+        .classes
+        .iter()
+        .all(|(id, _)| importer.positions.contains_key(id));
+
+    for (xmi_id, class) in importer.classes {
+        ids.insert(xmi_id, document.add_element(class));
+    }
+
+    for pending in importer.relationships {
+        let (Some(source), Some(target)) =
+            (ids.get(pending.source), ids.get(pending.target))
+        else {
+            continue;
+        };
+
+        let mut relationship =
+            Relationship::new(*source, *target, pending.kind);
+        relationship.set_label(pending.name);
+        let (source_multiplicity, target_multiplicity) = pending.multiplicities;
+        relationship
+            .set_multiplicities(source_multiplicity, target_multiplicity);
+        document.add_element(relationship);
+    }
+
+    if !positioned {
+        layout::layered(&mut document);
+    }
+
+    for (xmi_id, (x, y)) in importer.positions {
+        let Some(id) = ids.get(xmi_id) else {
+            continue;
+        };
+
+        if let Some(el) = document.get_mut(*id) {
+            el.adjust_position(x - el.x(), y - el.y());
+        }
+    }
+
+    Ok(document)
+}
+
+fn visibility(node: Node) -> Visibility {
+    match node.attribute("visibility") {
+        Some("public") => Visibility::Public,
+        Some("private") => Visibility::Private,
+        Some("protected") => Visibility::Protected,
+        Some("package") => Visibility::Package,
+        _ => Visibility::Unspecified,
+    }
+}
+
+fn multiplicity(end: Node) -> Option<String> {
+    let value = |name| {
+        children(end, name)
+            .next()
+            .and_then(|v| v.attribute("value"))
+            .map(|v| if v == "-1" { "*" } else { v })
+    };
+
+    match (value("lowerValue"), value("upperValue")) {
+        (Some(lower), Some(upper)) if lower == upper => Some(lower.to_string()),
+        (Some(lower), Some(upper)) => Some(format!("{lower}..{upper}")),
+        (None, Some(upper)) => Some(upper.to_string()),
+        (Some(lower), None) => Some(lower.to_string()),
+        (None, None) => None,
+    }
+}
@@ -0,0 +1,406 @@
+use std::ops::{Add, Mul, Neg, Sub};
+
+/// A position in the document.
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct Point {
+    x: f64,
+    y: f64,
+}
+
+impl Point {
+    pub const fn new(x: f64, y: f64) -> Self {
+        Self { x, y }
+    }
+
+    pub fn x(&self) -> f64 {
+        self.x
+    }
+
+    pub fn y(&self) -> f64 {
+        self.y
+    }
+
+    /// Moves the point by `vector`.
+    pub fn offset(self, vector: Vector) -> Self {
+        Self::new(self.x + vector.x, self.y + vector.y)
+    }
+
+    pub fn distance_to(self, other: Point) -> f64 {
+        (other - self).length()
+    }
+
+    /// The distance to the closest point of the segment from `a` to `b`.
+    pub fn distance_to_segment(self, a: Point, b: Point) -> f64 {
+        let along = b - a;
+        let length_squared = along.dot(along);
+
+        let t = if length_squared == 0.0 {
+            0.0
+        } else {
+            ((self - a).dot(along) / length_squared).clamp(0.0, 1.0)
+        };
+
+        ((self - a) - along * t).length()
+    }
+}
+
+impl From<(f64, f64)> for Point {
+    fn from((x, y): (f64, f64)) -> Self {
+        Self::new(x, y)
+    }
+}
+
+impl From<Point> for (f64, f64) {
+    fn from(point: Point) -> Self {
+        (point.x, point.y)
+    }
+}
+
+impl Sub for Point {
+    type Output = Vector;
+
+    fn sub(self, other: Point) -> Vector {
+        Vector::new(self.x - other.x, self.y - other.y)
+    }
+}
+
+/// A direction and distance between two points.
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct Vector {
+    x: f64,
+    y: f64,
+}
+
+impl Vector {
+    pub const fn new(x: f64, y: f64) -> Self {
+        Self { x, y }
+    }
+
+    pub fn x(&self) -> f64 {
+        self.x
+    }
+
+    pub fn y(&self) -> f64 {
+        self.y
+    }
+
+    pub fn length(self) -> f64 {
+        self.x.hypot(self.y)
+    }
+
+    pub fn dot(self, other: Vector) -> f64 {
+        self.x * other.x + self.y * other.y
+    }
+
+    /// The vector of length 1 pointing the same way. The zero vector points
+    /// to the right.
+    pub fn normalized(self) -> Self {
+        match self.length() {
+            0.0 => Self::new(1.0, 0.0),
+            length => Self::new(self.x / length, self.y / length),
+        }
+    }
+
+    /// The vector turned by a quarter, clockwise on screen.
+    pub fn perpendicular(self) -> Self {
+        Self::new(-self.y, self.x)
+    }
+}
+
+impl Add for Vector {
+    type Output = Vector;
+
+    fn add(self, other: Vector) -> Vector {
+        Vector::new(self.x + other.x, self.y + other.y)
+    }
+}
+
+impl Sub for Vector {
+    type Output = Vector;
+
+    fn sub(self, other: Vector) -> Vector {
+        Vector::new(self.x - other.x, self.y - other.y)
+    }
+}
+
+impl Mul<f64> for Vector {
+    type Output = Vector;
+
+    fn mul(self, factor: f64) -> Vector {
+        Vector::new(self.x * factor, self.y * factor)
+    }
+}
+
+impl Neg for Vector {
+    type Output = Vector;
+
+    fn neg(self) -> Vector {
+        Vector::new(-self.x, -self.y)
+    }
+}
+
+/// An axis-aligned rectangle, given by its edges. The right and bottom edges
+/// are part of the rectangle.
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct Rect {
+    left: f64,
+    top: f64,
+    right: f64,
+    bottom: f64,
+}
+
+impl Rect {
+    pub fn new(x: f64, y: f64, width: f64, height: f64) -> Self {
+        Self::from_edges(x, y, x + width, y + height)
+    }
+
+    /// The rectangle between the given edges, in any order.
+    pub fn from_edges(left: f64, top: f64, right: f64, bottom: f64) -> Self {
+        Self {
+            left: left.min(right),
+            top: top.min(bottom),
+            right: left.max(right),
+            bottom: top.max(bottom),
+        }
+    }
+
+    /// The smallest rectangle holding every point, if there are any.
+    pub fn from_points(
+        points: impl IntoIterator<Item = impl Into<Point>>,
+    ) -> Option<Self> {
+        points
+            .into_iter()
+            .map(|point| {
+                let point = point.into();
+                Self::from_edges(point.x, point.y, point.x, point.y)
+            })
+            .reduce(Self::union)
+    }
+
+    pub fn left(&self) -> f64 {
+        self.left
+    }
+
+    pub fn top(&self) -> f64 {
+        self.top
+    }
+
+    pub fn right(&self) -> f64 {
+        self.right
+    }
+
+    pub fn bottom(&self) -> f64 {
+        self.bottom
+    }
+
+    pub fn width(&self) -> f64 {
+        self.right - self.left
+    }
+
+    pub fn height(&self) -> f64 {
+        self.bottom - self.top
+    }
+
+    /// The left, top, right and bottom edges.
+    pub fn edges(&self) -> (f64, f64, f64, f64) {
+        (self.left, self.top, self.right, self.bottom)
+    }
+
+    pub fn top_left(&self) -> Point {
+        Point::new(self.left, self.top)
+    }
+
+    pub fn center(&self) -> Point {
+        Point::new(
+            (self.left + self.right) / 2.0,
+            (self.top + self.bottom) / 2.0,
+        )
+    }
+
+    /// The smallest rectangle holding both rectangles.
+    pub fn union(self, other: Rect) -> Self {
+        Self {
+            left: self.left.min(other.left),
+            top: self.top.min(other.top),
+            right: self.right.max(other.right),
+            bottom: self.bottom.max(other.bottom),
+        }
+    }
+
+    /// The part both rectangles cover, if they touch at all.
+    pub fn intersection(self, other: Rect) -> Option<Self> {
+        self.intersects(other).then(|| Self {
+            left: self.left.max(other.left),
+            top: self.top.max(other.top),
+            right: self.right.min(other.right),
+            bottom: self.bottom.min(other.bottom),
+        })
+    }
+
+    pub fn intersects(&self, other: Rect) -> bool {
+        self.left <= other.right
+            && other.left <= self.right
+            && self.top <= other.bottom
+            && other.top <= self.bottom
+    }
+
+    pub fn contains(&self, point: Point) -> bool {
+        (self.left..=self.right).contains(&point.x)
+            && (self.top..=self.bottom).contains(&point.y)
+    }
+
+    /// The distance to the closest point of the border, from inside or
+    /// outside.
+    pub fn distance_to_border(&self, point: Point) -> f64 {
+        let (l, t, r, b) = self.edges();
+        let corners = [
+            Point::new(l, t),
+            Point::new(r, t),
+            Point::new(r, b),
+            Point::new(l, b),
+        ];
+
+        (0..4)
+            .map(|i| {
+                point.distance_to_segment(corners[i], corners[(i + 1) % 4])
+            })
+            .fold(f64::INFINITY, f64::min)
+    }
+
+    pub fn contains_rect(&self, other: Rect) -> bool {
+        other.left >= self.left
+            && other.top >= self.top
+            && other.right <= self.right
+            && other.bottom <= self.bottom
+    }
+
+    /// Grows the rectangle by `by` on every side, or shrinks it for negative
+    /// values, down to nothing around its center.
+    pub fn inflate(self, by: f64) -> Self {
+        let by_x = by.max(-self.width() / 2.0);
+        let by_y = by.max(-self.height() / 2.0);
+
+        Self {
+            left: self.left - by_x,
+            top: self.top - by_y,
+            right: self.right + by_x,
+            bottom: self.bottom + by_y,
+        }
+    }
+
+    pub fn translate(self, dx: f64, dy: f64) -> Self {
+        Self {
+            left: self.left + dx,
+            top: self.top + dy,
+            right: self.right + dx,
+            bottom: self.bottom + dy,
+        }
+    }
+}
+
+/// An affine transform, mapping (x, y) to
+/// (a * x + c * y + e, b * x + d * y + f), as on an HTML canvas.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Transform {
+    a: f64,
+    b: f64,
+    c: f64,
+    d: f64,
+    e: f64,
+    f: f64,
+}
+
+impl Transform {
+    pub const IDENTITY: Self = Self {
+        a: 1.0,
+        b: 0.0,
+        c: 0.0,
+        d: 1.0,
+        e: 0.0,
+        f: 0.0,
+    };
+
+    pub fn translation(dx: f64, dy: f64) -> Self {
+        Self {
+            e: dx,
+            f: dy,
+            ..Self::IDENTITY
+        }
+    }
+
+    pub fn scale(sx: f64, sy: f64) -> Self {
+        Self {
+            a: sx,
+            d: sy,
+            ..Self::IDENTITY
+        }
+    }
+
+    /// The transform applying `self` first and `next` after it.
+    pub fn then(self, next: Transform) -> Self {
+        Self {
+            a: next.a * self.a + next.c * self.b,
+            b: next.b * self.a + next.d * self.b,
+            c: next.a * self.c + next.c * self.d,
+            d: next.b * self.c + next.d * self.d,
+            e: next.a * self.e + next.c * self.f + next.e,
+            f: next.b * self.e + next.d * self.f + next.f,
+        }
+    }
+
+    /// The transform undoing this one, unless it flattens the plane.
+    pub fn inverse(&self) -> Option<Self> {
+        let determinant = self.a * self.d - self.b * self.c;
+
+        if determinant == 0.0 || !determinant.is_finite() {
+            return None;
+        }
+
+        Some(Self {
+            a: self.d / determinant,
+            b: -self.b / determinant,
+            c: -self.c / determinant,
+            d: self.a / determinant,
+            e: (self.c * self.f - self.d * self.e) / determinant,
+            f: (self.b * self.e - self.a * self.f) / determinant,
+        })
+    }
+
+    pub fn apply(&self, x: f64, y: f64) -> (f64, f64) {
+        (
+            self.a * x + self.c * y + self.e,
+            self.b * x + self.d * y + self.f,
+        )
+    }
+
+    pub fn apply_point(&self, point: Point) -> Point {
+        let (x, y) = self.apply(point.x, point.y);
+        Point::new(x, y)
+    }
+
+    /// The smallest rectangle holding the transformed corners of `rect`.
+    pub fn apply_rect(&self, rect: Rect) -> Rect {
+        let corners = [
+            (rect.left, rect.top),
+            (rect.right, rect.top),
+            (rect.left, rect.bottom),
+            (rect.right, rect.bottom),
+        ]
+        .map(|(x, y)| self.apply(x, y));
+
+        let xs = corners.map(|(x, _)| x);
+        let ys = corners.map(|(_, y)| y);
+        let min =
+            |values: [f64; 4]| values.into_iter().fold(f64::MAX, f64::min);
+        let max =
+            |values: [f64; 4]| values.into_iter().fold(f64::MIN, f64::max);
+
+        Rect::from_edges(min(xs), min(ys), max(xs), max(ys))
+    }
+}
+
+impl Default for Transform {
+    fn default() -> Self {
+        Self::IDENTITY
+    }
+}
@@ -0,0 +1,67 @@
+use crate::{color::Color, geometry::Rect};
+
+const DOT_COLOR: Color = const {
+    Color::Rgb {
+        red: 170,
+        green: 170,
+        blue: 170,
+    }
+};
+
+const DOT_SIZE: f64 = 2.0;
+const SPACING: f64 = 75.0;
+
+/// The dotted grid drawn behind a document. The dots sit on multiples of the
+/// spacing in document coordinates, so the grid moves along with the
+/// camera.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Grid {
+    background: Color,
+    dot_color: Color,
+    dot_size: f64,
+    spacing: f64,
+}
+
+impl Grid {
+    pub fn new(background: Color) -> Self {
+        Self {
+            background,
+            dot_color: DOT_COLOR,
+            dot_size: DOT_SIZE,
+            spacing: SPACING,
+        }
+    }
+
+    pub fn background(&self) -> Color {
+        self.background
+    }
+
+    pub fn dot_color(&self) -> Color {
+        self.dot_color
+    }
+
+    pub fn dot_size(&self) -> f64 {
+        self.dot_size
+    }
+
+    pub fn spacing(&self) -> f64 {
+        self.spacing
+    }
+
+    /// The top left corners of the dots within `viewport`.
+    pub fn dots(
+        &self,
+        viewport: Rect,
+    ) -> impl Iterator<Item = (f64, f64)> + use<> {
+        let (l, t, r, b) = viewport.edges();
+        let spacing = self.spacing.max(1.0);
+        let steps = move |from: f64, to: f64| {
+            let first = (from / spacing).ceil() as i64;
+            let last = (to / spacing).floor() as i64;
+            (first..=last).map(move |i| i as f64 * spacing)
+        };
+        let xs = steps(l, r);
+
+        steps(t, b).flat_map(move |y| xs.clone().map(move |x| (x, y)))
+    }
+}
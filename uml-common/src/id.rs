@@ -1,15 +1,39 @@
 use std::fmt::Display;
-use std::sync::atomic::AtomicUsize;
+use std::sync::atomic::AtomicU64;
 use std::sync::atomic::Ordering::Relaxed;
 
-static COUNTER: AtomicUsize = AtomicUsize::new(1);
+use serde::{Deserialize, Serialize};
 
-#[derive(PartialEq, Eq, Clone, Copy, Debug)]
-pub struct Id(usize);
+/// How many of the low bits of an id count the ids minted by one process.
+/// The bits above them hold the origin of that process.
+const SEQUENCE_BITS: u32 = 32;
+
+static ORIGIN: AtomicU64 = AtomicU64::new(0);
+static COUNTER: AtomicU64 = AtomicU64::new(1);
+
+/// Identifies an element within a document.
+///
+/// Ids are 64 bits wide on every target. The high bits hold the origin of
+/// the process that minted the id: the server is origin 0 and hands out
+/// another origin to every client as it connects. The low bits count up
+/// within that process, so clients never mint the same id, and ids received
+/// from others have no effect on the ones minted locally.
+#[derive(PartialEq, Eq, Hash, Clone, Copy, Debug, Serialize, Deserialize)]
+pub struct Id(u64);
+
+impl Id {
+    /// Mints ids under `origin` from now on. A client calls this once, with
+    /// the origin the server assigned to it.
+    pub fn set_origin(origin: Origin) {
+        ORIGIN.store(u64::from(origin.origin), Relaxed);
+    }
+}
 
 impl Default for Id {
     fn default() -> Self {
-        Self(COUNTER.fetch_add(1, Relaxed))
+        // A process would have to mint four billion ids to wrap around.
+        let sequence = COUNTER.fetch_add(1, Relaxed) & u64::from(u32::MAX);
+        Self(ORIGIN.load(Relaxed) << SEQUENCE_BITS | sequence)
     }
 }
 
@@ -18,3 +42,11 @@ impl Display for Id {
         f.write_fmt(format_args!("{}", self.0))
     }
 }
+
+/// Sent by the server to a client as it connects, before the document: the
+/// origin the client mints its ids under.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct Origin {
+    pub origin: u32,
+}
@@ -3,6 +3,7 @@ use crate::prompt::{Prompt, PromptResponse};
 #[derive(Debug, Clone, Copy, Default, PartialEq)]
 pub struct InteractionState {
     hover: bool,
+    selected: bool,
 }
 
 impl InteractionState {
@@ -13,6 +14,14 @@ impl InteractionState {
     pub fn is_hovered(&self) -> bool {
         self.hover
     }
+
+    pub fn set_selected(&mut self, value: bool) {
+        self.selected = value;
+    }
+
+    pub fn is_selected(&self) -> bool {
+        self.selected
+    }
 }
 
 #[allow(unused_variables)]
@@ -20,11 +29,11 @@ pub trait Interactive {
     fn get_interaction(&self) -> InteractionState;
     fn get_interaction_mut(&mut self) -> &mut InteractionState;
 
-    fn adjust_position(&mut self, delta_x: i32, delta_y: i32);
+    fn adjust_position(&mut self, delta_x: f64, delta_y: f64);
 
-    fn set_position(&mut self, x: i32, y: i32) {}
+    fn set_position(&mut self, x: f64, y: f64) {}
 
-    fn click(&mut self, x: i32, y: i32) -> Option<Prompt> {
+    fn click(&mut self, x: f64, y: f64) -> Option<Prompt> {
         None
     }
 
@@ -41,4 +50,14 @@ pub trait Interactive {
     fn is_hovered(&self) -> bool {
         self.get_interaction().is_hovered()
     }
+
+    fn is_selected(&self) -> bool {
+        self.get_interaction().is_selected()
+    }
+
+    /// Whether the element is drawn highlighted: while it is hovered or
+    /// selected.
+    fn is_highlighted(&self) -> bool {
+        self.is_hovered() || self.is_selected()
+    }
 }
@@ -0,0 +1,155 @@
+use std::collections::HashMap;
+
+use crate::{
+    document::Document, drawable::Drawable, elements::Element, id::Id,
+    interaction::Interactive,
+};
+
+const ORIGIN: f64 = 100.0;
+const GAP: f64 = 80.0;
+const CHAR_WIDTH: f64 = 11.0;
+const LINE_HEIGHT: f64 = 36.0;
+const PADDING: f64 = 40.0;
+const MAX_LAYER_WIDTH: usize = 8;
+
+/// Places every element of the document (except relationships, which follow
+/// the elements they connect) in rows of roughly equal length, in document
+/// order.
+pub fn grid(document: &mut Document) {
+    let count = document
+        .elements()
+        .iter()
+        .filter(|el| el.as_relationship().is_none())
+        .count();
+    let columns = (count as f64).sqrt().ceil().max(1.0) as usize;
+
+    let (mut x, mut y) = (ORIGIN, ORIGIN);
+    let mut row_height = 0.0;
+    let mut column = 0;
+
+    for el in document.elements_mut() {
+        if el.as_relationship().is_some() {
+            continue;
+        }
+
+        if column == columns {
+            column = 0;
+            x = ORIGIN;
+            y += row_height + GAP;
+            row_height = 0.0;
+        }
+
+        let (width, height) = estimated_size(el);
+        el.adjust_position(x - el.x(), y - el.y());
+
+        x += width + GAP;
+        row_height = row_height.max(height);
+        column += 1;
+    }
+}
+
+/// Places elements in horizontal layers so that relationships point upwards:
+/// the target of a relationship (e.g. a superclass or interface) ends up in a
+/// layer above its source. Elements within a layer keep document order.
+pub fn layered(document: &mut Document) {
+    let ids = document
+        .elements()
+        .iter()
+        .filter(|el| el.as_relationship().is_none())
+        .map(|el| el.id())
+        .collect::<Vec<_>>();
+
+    let edges = document
+        .elements()
+        .iter()
+        .filter_map(|el| el.as_relationship())
+        .map(|r| (r.source(), r.target()))
+        .filter(|(source, target)| source != target)
+        .collect::<Vec<_>>();
+
+    let mut layers: HashMap<Id, usize> =
+        ids.iter().map(|id| (*id, 0)).collect();
+
+    // Cycles would otherwise push their elements down forever, so stop once
+    // every element could have been placed below every other one.
+    for _ in 0..ids.len() {
+        let mut changed = false;
+
+        for (source, target) in &edges {
+            let (Some(s), Some(t)) = (layers.get(source), layers.get(target))
+            else {
+                continue;
+            };
+
+            if *s <= *t {
+                layers.insert(*source, t + 1);
+                changed = true;
+            }
+        }
+
+        if !changed {
+            break;
+        }
+    }
+
+    let mut rows: Vec<Vec<Id>> = vec![];
+
+    for id in ids {
+        let layer = layers[&id];
+        rows.resize(rows.len().max(layer + 1), vec![]);
+        rows[layer].push(id);
+    }
+
+    let mut y = ORIGIN;
+
+    for row in rows.iter().flat_map(|r| r.chunks(MAX_LAYER_WIDTH)) {
+        let mut x = ORIGIN;
+        let mut row_height = 0.0;
+
+        for id in row {
+            let Some(el) = document.get_mut(*id) else {
+                continue;
+            };
+
+            let (width, height) = estimated_size(el);
+            el.adjust_position(x - el.x(), y - el.y());
+
+            x += width + GAP;
+            row_height = f64::max(row_height, height);
+        }
+
+        y += row_height + GAP;
+    }
+}
+
+/// Estimates the size of an element from its text. Elements created by
+/// importers have not been measured by a canvas yet, so their actual size is
+/// unknown until the document is drawn.
+pub fn estimated_size(el: &Element) -> (f64, f64) {
+    if let Some(label) = el.as_label()
+        && label.width().is_none()
+    {
+        let longest = label.text().lines().map(|l| l.chars().count()).max();
+        let lines = label.text().lines().count().max(1) as f64;
+        return (
+            longest.unwrap_or(0) as f64 * CHAR_WIDTH,
+            lines * LINE_HEIGHT,
+        );
+    }
+
+    let Some(class) = el.as_class() else {
+        let (l, t, r, b) = el.bounds().edges();
+        return (r - l, b - t);
+    };
+
+    let lines = std::iter::once(class.name())
+        .chain(class.stereotype())
+        .chain(class.attributes())
+        .chain(class.operations())
+        .collect::<Vec<_>>();
+
+    let longest = lines.iter().map(|l| l.chars().count()).max().unwrap_or(0);
+    let width = longest as f64 * CHAR_WIDTH + PADDING;
+    let height = lines.len() as f64 * LINE_HEIGHT + PADDING;
+    (width, height)
+}
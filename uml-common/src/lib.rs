@@ -4,8 +4,14 @@ pub mod color;
 pub mod document;
 pub mod drawable;
 pub mod elements;
+pub mod format;
+pub mod geometry;
+pub mod grid;
 pub mod id;
 pub mod interaction;
+pub mod layout;
+pub mod member;
 pub mod prompt;
 pub mod size;
+pub mod spatial;
 pub mod stroke;
@@ -0,0 +1,265 @@
+use std::fmt::Display;
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum Visibility {
+    Public,
+    Private,
+    Protected,
+    Package,
+    #[default]
+    Unspecified,
+}
+
+impl Visibility {
+    pub fn from_symbol(symbol: char) -> Option<Self> {
+        match symbol {
+            '+' => Some(Visibility::Public),
+            '-' => Some(Visibility::Private),
+            '#' => Some(Visibility::Protected),
+            '~' => Some(Visibility::Package),
+            _ => None,
+        }
+    }
+
+    pub fn symbol(&self) -> Option<char> {
+        match self {
+            Visibility::Public => Some('+'),
+            Visibility::Private => Some('-'),
+            Visibility::Protected => Some('#'),
+            Visibility::Package => Some('~'),
+            Visibility::Unspecified => None,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Parameter {
+    name: String,
+    ty: Option<String>,
+}
+
+impl Parameter {
+    pub fn new(name: impl Into<String>, ty: Option<String>) -> Self {
+        Self {
+            name: name.into(),
+            ty,
+        }
+    }
+
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    pub fn ty(&self) -> Option<&str> {
+        self.ty.as_deref()
+    }
+}
+
+impl Display for Parameter {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match &self.ty {
+            Some(ty) => f.write_fmt(format_args!("{}: {ty}", self.name)),
+            None => f.write_str(&self.name),
+        }
+    }
+}
+
+/// An attribute or operation of a classifier, written in UML notation:
+/// `{static} +name: Type` or `{abstract} #name(param: Type): Return`.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Member {
+    visibility: Visibility,
+    name: String,
+    ty: Option<String>,
+    parameters: Option<Vec<Parameter>>,
+    is_static: bool,
+    is_abstract: bool,
+}
+
+impl Member {
+    pub fn attribute(name: impl Into<String>, ty: Option<String>) -> Self {
+        Self {
+            name: name.into(),
+            ty,
+            ..Default::default()
+        }
+    }
+
+    pub fn operation(
+        name: impl Into<String>,
+        parameters: Vec<Parameter>,
+        ty: Option<String>,
+    ) -> Self {
+        Self {
+            name: name.into(),
+            ty,
+            parameters: Some(parameters),
+            ..Default::default()
+        }
+    }
+
+    /// Parses a member written in UML notation. Text that does not follow the
+    /// notation is kept as the name of an untyped attribute.
+    pub fn parse(text: &str) -> Self {
+        let mut rest = text.trim();
+        let mut member = Member::default();
+
+        loop {
+            if let Some(r) = rest.strip_prefix("{static}") {
+                member.is_static = true;
+                rest = r.trim_start();
+            } else if let Some(r) = rest.strip_prefix("{abstract}") {
+                member.is_abstract = true;
+                rest = r.trim_start();
+            } else {
+                break;
+            }
+        }
+
+        if let Some(visibility) =
+            rest.chars().next().and_then(Visibility::from_symbol)
+        {
+            member.visibility = visibility;
+            rest = rest[1..].trim_start();
+        }
+
+        if let Some(open) = rest.find('(')
+            && let Some(close) = rest.rfind(')').filter(|c| *c > open)
+        {
+            member.name = rest[..open].trim().to_string();
+            member.parameters = Some(
+                split_top_level(&rest[open + 1..close], ',')
+                    .into_iter()
+                    .filter(|p| !p.is_empty())
+                    .map(|p| match p.split_once(':') {
+                        Some((name, ty)) => Parameter::new(
+                            name.trim(),
+                            Some(ty.trim().to_string()),
+                        ),
+                        None => Parameter::new(p, None),
+                    })
+                    .collect(),
+            );
+            member.ty = rest[close + 1..]
+                .trim()
+                .strip_prefix(':')
+                .map(|ty| ty.trim().to_string())
+                .filter(|ty| !ty.is_empty());
+            return member;
+        }
+
+        match rest.split_once(':') {
+            Some((name, ty)) => {
+                member.name = name.trim().to_string();
+                member.ty =
+                    Some(ty.trim().to_string()).filter(|t| !t.is_empty());
+            }
+            None => member.name = rest.to_string(),
+        }
+
+        member
+    }
+
+    pub fn visibility(&self) -> Visibility {
+        self.visibility
+    }
+
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// The type of an attribute, or the return type of an operation.
+    pub fn ty(&self) -> Option<&str> {
+        self.ty.as_deref()
+    }
+
+    /// The parameters of an operation, or `None` if this is an attribute.
+    pub fn parameters(&self) -> Option<&[Parameter]> {
+        self.parameters.as_deref()
+    }
+
+    pub fn is_operation(&self) -> bool {
+        self.parameters.is_some()
+    }
+
+    pub fn is_static(&self) -> bool {
+        self.is_static
+    }
+
+    pub fn is_abstract(&self) -> bool {
+        self.is_abstract
+    }
+
+    pub fn with_visibility(mut self, value: Visibility) -> Self {
+        self.visibility = value;
+        self
+    }
+
+    pub fn with_static(mut self, value: bool) -> Self {
+        self.is_static = value;
+        self
+    }
+
+    pub fn with_abstract(mut self, value: bool) -> Self {
+        self.is_abstract = value;
+        self
+    }
+}
+
+impl Display for Member {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if self.is_static {
+            f.write_str("{static} ")?;
+        }
+
+        if self.is_abstract {
+            f.write_str("{abstract} ")?;
+        }
+
+        if let Some(symbol) = self.visibility.symbol() {
+            f.write_fmt(format_args!("{symbol}"))?;
+        }
+
+        f.write_str(&self.name)?;
+
+        if let Some(parameters) = &self.parameters {
+            let parameters = parameters
+                .iter()
+                .map(|p| p.to_string())
+                .collect::<Vec<_>>()
+                .join(", ");
+            f.write_fmt(format_args!("({parameters})"))?;
+        }
+
+        if let Some(ty) = &self.ty {
+            f.write_fmt(format_args!(": {ty}"))?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Splits `text` on `separator`, ignoring separators nested inside brackets.
+pub(crate) fn split_top_level(text: &str, separator: char) -> Vec<String> {
+    let mut parts = vec![];
+    let mut depth = 0;
+    let mut current = String::new();
+
+    for c in text.chars() {
+        match c {
+            '<' | '(' | '[' | '{' => depth += 1,
+            '>' | ')' | ']' | '}' => depth -= 1,
+            c if c == separator && depth == 0 => {
+                parts.push(current.trim().to_string());
+                current.clear();
+                continue;
+            }
+            _ => (),
+        }
+
+        current.push(c);
+    }
+
+    parts.push(current.trim().to_string());
+    parts
+}
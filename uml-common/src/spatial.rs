@@ -0,0 +1,214 @@
+use std::{
+    collections::{HashMap, HashSet},
+    hash::Hash,
+};
+
+use crate::geometry::Rect;
+
+/// The side of a grid cell, in document units.
+const CELL_SIZE: f64 = 256.0;
+
+/// The most cells a key is kept in. Keys covering more, such as a huge or
+/// malformed element, are checked by every query instead.
+const MAX_CELLS: i64 = 1024;
+
+/// A uniform grid over the bounds of a set of keys, so that finding what is
+/// at a point or within an area only looks at the keys in the cells it
+/// covers instead of at every key.
+#[derive(Debug, Clone, PartialEq)]
+pub struct SpatialIndex<K>
+where
+    K: Copy + Eq + Hash,
+{
+    cell_size: f64,
+    cells: HashMap<(i64, i64), Vec<K>>,
+    oversized: Vec<K>,
+    bounds: HashMap<K, Rect>,
+}
+
+impl<K> SpatialIndex<K>
+where
+    K: Copy + Eq + Hash,
+{
+    pub fn new(cell_size: f64) -> Self {
+        Self {
+            cell_size: cell_size.max(1.0),
+            cells: HashMap::new(),
+            oversized: Vec::new(),
+            bounds: HashMap::new(),
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.bounds.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.bounds.is_empty()
+    }
+
+    pub fn contains(&self, key: K) -> bool {
+        self.bounds.contains_key(&key)
+    }
+
+    /// The bounds `key` was last inserted with.
+    pub fn bounds(&self, key: K) -> Option<Rect> {
+        self.bounds.get(&key).copied()
+    }
+
+    /// Inserts `key` with `bounds`, or moves it there if it is already in
+    /// the index. Keys whose bounds did not change are left as they are.
+    pub fn insert(&mut self, key: K, bounds: Rect) {
+        match self.bounds.get(&key) {
+            Some(old) if *old == bounds => return,
+            Some(_) => self.remove(key),
+            None => (),
+        }
+
+        if self.is_oversized(bounds) {
+            self.oversized.push(key);
+        } else {
+            for cell in self.covered_cells(bounds) {
+                self.cells.entry(cell).or_default().push(key);
+            }
+        }
+        self.bounds.insert(key, bounds);
+    }
+
+    pub fn remove(&mut self, key: K) {
+        let Some(bounds) = self.bounds.remove(&key) else {
+            return;
+        };
+
+        if self.is_oversized(bounds) {
+            self.oversized.retain(|k| *k != key);
+            return;
+        }
+
+        for cell in self.covered_cells(bounds) {
+            if let Some(keys) = self.cells.get_mut(&cell) {
+                keys.retain(|k| *k != key);
+
+                if keys.is_empty() {
+                    self.cells.remove(&cell);
+                }
+            }
+        }
+    }
+
+    /// Removes every key `keep` returns false for.
+    pub fn retain(&mut self, mut keep: impl FnMut(K) -> bool) {
+        let removed = self
+            .bounds
+            .keys()
+            .copied()
+            .filter(|key| !keep(*key))
+            .collect::<Vec<_>>();
+
+        for key in removed {
+            self.remove(key);
+        }
+    }
+
+    /// Renames every key to `rename(key)`, leaving their bounds as they
+    /// are. No two keys may be renamed to the same one.
+    pub fn rename(&mut self, rename: impl Fn(K) -> K) {
+        for key in self.cells.values_mut().flatten().chain(&mut self.oversized)
+        {
+            *key = rename(*key);
+        }
+
+        self.bounds = self
+            .bounds
+            .drain()
+            .map(|(key, bounds)| (rename(key), bounds))
+            .collect();
+    }
+
+    pub fn clear(&mut self) {
+        self.cells.clear();
+        self.oversized.clear();
+        self.bounds.clear();
+    }
+
+    /// The keys whose bounds contain the point (`x`, `y`).
+    pub fn at(&self, x: f64, y: f64) -> Vec<K> {
+        self.query(Rect::from_edges(x, y, x, y))
+    }
+
+    /// The keys whose bounds intersect `area`, each once, in no particular
+    /// order.
+    pub fn query(&self, area: Rect) -> Vec<K> {
+        let (l, t, r, b) = self.cell_range(area);
+        let mut seen = HashSet::new();
+        let mut keys = vec![];
+
+        // Areas covering more cells than there are in use are answered from
+        // the cells in use instead.
+        let candidates: Box<dyn Iterator<Item = &K>> =
+            if self.cell_count(area) > self.cells.len() as i64 {
+                Box::new(
+                    self.cells
+                        .iter()
+                        .filter(|((x, y), _)| {
+                            (l..=r).contains(x) && (t..=b).contains(y)
+                        })
+                        .flat_map(|(_, keys)| keys),
+                )
+            } else {
+                Box::new(
+                    self.covered_cells(area)
+                        .filter_map(|cell| self.cells.get(&cell))
+                        .flatten(),
+                )
+            };
+
+        for key in candidates.chain(&self.oversized) {
+            if seen.insert(*key) && self.bounds[key].intersects(area) {
+                keys.push(*key);
+            }
+        }
+
+        keys
+    }
+
+    /// The columns and rows of the cells `bounds` overlap, as the edges of
+    /// a rectangle.
+    fn cell_range(&self, bounds: Rect) -> (i64, i64, i64, i64) {
+        // Casting saturates, so edges far out are clamped to the outermost
+        // cells.
+        let cell = |v: f64| (v / self.cell_size).floor() as i64;
+        let (l, t, r, b) = bounds.edges();
+        (cell(l), cell(t), cell(r), cell(b))
+    }
+
+    /// How many cells `bounds` overlap, saturating rather than overflowing.
+    fn cell_count(&self, bounds: Rect) -> i64 {
+        let (l, t, r, b) = self.cell_range(bounds);
+        let columns = r.saturating_sub(l).saturating_add(1);
+        let rows = b.saturating_sub(t).saturating_add(1);
+        columns.saturating_mul(rows)
+    }
+
+    fn is_oversized(&self, bounds: Rect) -> bool {
+        self.cell_count(bounds) > MAX_CELLS
+    }
+
+    /// The cells `bounds` overlap.
+    fn covered_cells(
+        &self,
+        bounds: Rect,
+    ) -> impl Iterator<Item = (i64, i64)> + use<K> {
+        let (l, t, r, b) = self.cell_range(bounds);
+        (l..=r).flat_map(move |x| (t..=b).map(move |y| (x, y)))
+    }
+}
+
+impl<K> Default for SpatialIndex<K>
+where
+    K: Copy + Eq + Hash,
+{
+    fn default() -> Self {
+        Self::new(CELL_SIZE)
+    }
+}
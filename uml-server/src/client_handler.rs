@@ -2,13 +2,16 @@ use std::time::Duration;
 
 use actix_ws::AggregatedMessage::{self, *};
 use tokio::sync::mpsc::Receiver;
-use uml_common::{document::Document, id::Id};
+use uml_common::{
+    document::Document,
+    id::{Id, Origin},
+};
 
 pub enum WsMessage {
     Document {
         recipient: Id,
         json: String,
-        document: Document,
+        document: Box<Document>,
     },
     Closed {
         recipient: Id,
@@ -22,6 +25,7 @@ pub enum WsMessage {
 
 pub struct ClientHandler {
     id: Id,
+    origin: Origin,
     session: actix_ws::Session,
     stream: Receiver<AggregatedMessage>,
 }
@@ -30,11 +34,13 @@ impl ClientHandler {
     pub fn new(
         session: actix_ws::Session,
         stream: Receiver<AggregatedMessage>,
+        origin: Origin,
     ) -> Self {
         Self {
             session,
             stream,
             id: Id::default(),
+            origin,
         }
     }
 
@@ -42,6 +48,11 @@ impl ClientHandler {
         self.id
     }
 
+    /// The origin the client mints the ids of its elements under.
+    pub fn origin(&self) -> Origin {
+        self.origin
+    }
+
     pub async fn read(&mut self) -> WsMessage {
         let msg = self.stream.recv().await;
         log::trace!(
@@ -62,7 +73,7 @@ impl ClientHandler {
             Ok(document) => WsMessage::Document {
                 recipient: self.id(),
                 json,
-                document,
+                document: Box::new(document),
             },
             Err(e) => WsMessage::DeserializeError {
                 recipient: self.id(),
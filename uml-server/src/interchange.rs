@@ -0,0 +1,158 @@
+use actix_web::{
+    HttpResponse, Responder,
+    web::{Data, Json, Query},
+};
+use serde::Deserialize;
+use tokio::sync::Mutex;
+use uml_common::{
+    document::Document,
+    format::{ParseError, dot, drawio, mermaid, openapi, rust, sql, xmi},
+};
+
+use crate::state::State;
+
+/// The largest file that can be uploaded for import.
+pub const MAX_UPLOAD_SIZE: usize = 16 * 1024 * 1024;
+
+/// Replaces the shared document with the one parsed from an upload. `what`
+/// names the kind of file in the log when it cannot be parsed.
+async fn import<T>(
+    upload: T,
+    parse: impl FnOnce(T) -> Result<Document, ParseError>,
+    what: &str,
+    state: Data<Mutex<State>>,
+) -> HttpResponse {
+    let document = match parse(upload) {
+        Ok(document) => document,
+        Err(e) => {
+            log::debug!("Uploaded {what} could not be parsed: {e}");
+            return HttpResponse::BadRequest().body(e.to_string());
+        }
+    };
+
+    state
+        .get_ref()
+        .lock()
+        .await
+        .replace_document(document)
+        .await;
+    HttpResponse::NoContent().finish()
+}
+
+pub async fn export_mermaid(state: Data<Mutex<State>>) -> impl Responder {
+    let document = state.get_ref().lock().await.document().await;
+
+    HttpResponse::Ok()
+        .content_type("text/plain; charset=utf-8")
+        .body(mermaid::export(&document))
+}
+
+pub async fn import_mermaid(
+    body: String,
+    state: Data<Mutex<State>>,
+) -> impl Responder {
+    import(body.as_str(), mermaid::import, "Mermaid diagram", state).await
+}
+
+#[derive(Deserialize)]
+pub struct SourceFile {
+    /// Where the file is within the crate, to point at it in errors.
+    path: String,
+    source: String,
+}
+
+/// Reverse engineers the uploaded source files of a Rust crate as one, so
+/// types can refer to each other across files. The files are uploaded as a
+/// JSON array of `{"path": ..., "source": ...}` objects.
+pub async fn import_rust(
+    files: Json<Vec<SourceFile>>,
+    state: Data<Mutex<State>>,
+) -> impl Responder {
+    let parse = |files: Vec<SourceFile>| {
+        rust::import_sources(files.iter().map(|f| f.source.as_str())).map_err(
+            |e| {
+                // Only syntax errors fail an import, so the file at fault
+                // is the one that does not parse on its own.
+                match files.iter().find(|f| rust::import(&f.source).is_err()) {
+                    Some(file) => ParseError::new(
+                        e.line(),
+                        format!("{}: {}", file.path, e.message()),
+                    ),
+                    None => e,
+                }
+            },
+        )
+    };
+
+    import(files.into_inner(), parse, "Rust source", state).await
+}
+
+pub async fn export_xmi(state: Data<Mutex<State>>) -> impl Responder {
+    let document = state.get_ref().lock().await.document().await;
+
+    HttpResponse::Ok()
+        .content_type("application/xml; charset=utf-8")
+        .body(xmi::export(&document))
+}
+
+pub async fn import_xmi(
+    body: String,
+    state: Data<Mutex<State>>,
+) -> impl Responder {
+    import(body.as_str(), xmi::import, "XMI model", state).await
+}
+
+pub async fn export_drawio(state: Data<Mutex<State>>) -> impl Responder {
+    let document = state.get_ref().lock().await.document().await;
+
+    HttpResponse::Ok()
+        .content_type("application/xml; charset=utf-8")
+        .insert_header((
+            "Content-Disposition",
+            "attachment; filename=\"diagram.drawio\"",
+        ))
+        .body(drawio::export(&document))
+}
+
+pub async fn import_drawio(
+    body: String,
+    state: Data<Mutex<State>>,
+) -> impl Responder {
+    import(body.as_str(), drawio::import, "draw.io file", state).await
+}
+
+#[derive(Deserialize)]
+pub struct DotOptions {
+    /// Whether to pin nodes to their current positions.
+    #[serde(default)]
+    positions: bool,
+}
+
+pub async fn export_dot(
+    options: Query<DotOptions>,
+    state: Data<Mutex<State>>,
+) -> impl Responder {
+    let document = state.get_ref().lock().await.document().await;
+
+    HttpResponse::Ok()
+        .content_type("text/vnd.graphviz; charset=utf-8")
+        .body(dot::export(&document, options.positions))
+}
+
+/// Imports the tables of uploaded SQL DDL. Several migrations can be uploaded
+/// at once by concatenating them.
+pub async fn import_sql(
+    body: String,
+    state: Data<Mutex<State>>,
+) -> impl Responder {
+    import(body.as_str(), sql::import, "SQL", state).await
+}
+
+/// Imports the schemas of an uploaded OpenAPI document or JSON Schema file,
+/// written as JSON or YAML.
+pub async fn import_openapi(
+    body: String,
+    state: Data<Mutex<State>>,
+) -> impl Responder {
+    import(body.as_str(), openapi::import, "API schema", state).await
+}
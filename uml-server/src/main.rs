@@ -10,6 +10,7 @@ use state::State;
 use tokio::sync::Mutex;
 
 mod client_handler;
+mod interchange;
 mod serve;
 mod state;
 
@@ -59,8 +60,52 @@ async fn main() -> std::io::Result<()> {
     HttpServer::new(move || {
         App::new()
             .app_data(data.clone())
+            .app_data(web::PayloadConfig::new(interchange::MAX_UPLOAD_SIZE))
+            .app_data(
+                web::JsonConfig::default().limit(interchange::MAX_UPLOAD_SIZE),
+            )
             .service(web::resource("/websocket").to(websocket))
             .service(web::resource("/").to(serve::index))
+            .service(
+                web::resource("/export/mermaid")
+                    .route(web::get().to(interchange::export_mermaid)),
+            )
+            .service(
+                web::resource("/import/mermaid")
+                    .route(web::post().to(interchange::import_mermaid)),
+            )
+            .service(
+                web::resource("/import/rust")
+                    .route(web::post().to(interchange::import_rust)),
+            )
+            .service(
+                web::resource("/import/sql")
+                    .route(web::post().to(interchange::import_sql)),
+            )
+            .service(
+                web::resource("/import/openapi")
+                    .route(web::post().to(interchange::import_openapi)),
+            )
+            .service(
+                web::resource("/export/xmi")
+                    .route(web::get().to(interchange::export_xmi)),
+            )
+            .service(
+                web::resource("/import/xmi")
+                    .route(web::post().to(interchange::import_xmi)),
+            )
+            .service(
+                web::resource("/export/dot")
+                    .route(web::get().to(interchange::export_dot)),
+            )
+            .service(
+                web::resource("/export/drawio")
+                    .route(web::get().to(interchange::export_drawio)),
+            )
+            .service(
+                web::resource("/import/drawio")
+                    .route(web::post().to(interchange::import_drawio)),
+            )
             .service(
                 web::resource("/static/{filename:.*}").to(serve::serve_static),
             )
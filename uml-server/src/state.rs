@@ -3,16 +3,25 @@ use actix_ws::{AggregatedMessage, Session};
 use futures::future::select_all;
 use futures_util::{StreamExt, stream::FuturesUnordered};
 use tokio::{
-    sync::mpsc::{Receiver, Sender},
+    sync::{
+        mpsc::{Receiver, Sender},
+        oneshot,
+    },
     task::JoinHandle,
 };
-use uml_common::document::Document;
+use uml_common::{document::Document, id::Origin};
 
 use crate::client_handler::{ClientHandler, WsMessage};
 
+enum DocumentRequest {
+    Get(oneshot::Sender<Document>),
+    Replace(Box<Document>),
+}
+
 enum Event {
     ClientConnected(ClientHandler),
     ClientReceived(WsMessage),
+    Document(DocumentRequest),
     StopSignal,
 }
 
@@ -29,6 +38,7 @@ async fn read_message(handlers: &mut [ClientHandler]) -> Event {
 async fn wait_for_event(
     handlers: &mut [ClientHandler],
     new_clients_rx: &mut Receiver<ClientHandler>,
+    document_rx: &mut Receiver<DocumentRequest>,
     stop_signal_rx: &mut Receiver<()>,
 ) -> Event {
     tokio::select! {
@@ -42,6 +52,13 @@ async fn wait_for_event(
 
             Event::ClientConnected(handler)
         },
+        request = document_rx.recv() => {
+            let Some(request) = request else {
+                return Event::StopSignal;
+            };
+
+            Event::Document(request)
+        },
         _ = stop_signal_rx.recv() => {
             Event::StopSignal
         }
@@ -55,6 +72,15 @@ async fn handle_event(
 ) {
     match event {
         Event::ClientConnected(mut client_handler) => {
+            let Ok(origin) = serde_json::to_string(&client_handler.origin())
+            else {
+                log::warn!(
+                    "Client with ID {} was not sent its origin, as it could not be serialized.",
+                    client_handler.id(),
+                );
+                return;
+            };
+
             let Ok(json) = serde_json::to_string(&latest_document) else {
                 log::warn!(
                     "Client with ID {} was not sent document, as it could not be deserialized.",
@@ -63,6 +89,14 @@ async fn handle_event(
                 return;
             };
 
+            let Ok(()) = client_handler.send(origin).await else {
+                log::warn!(
+                    "Attempted to add client with ID {}, but connection is closed.",
+                    client_handler.id(),
+                );
+                return;
+            };
+
             let Ok(()) = client_handler.send(json).await else {
                 log::warn!(
                     "Attempted to add client with ID {}, but connection is closed.",
@@ -80,7 +114,7 @@ async fn handle_event(
             document,
         }) => {
             log::trace!("Client with ID {} received a message.", recipient);
-            *latest_document = document;
+            *latest_document = *document;
 
             for handler in handlers {
                 if handler.id() != recipient {
@@ -116,6 +150,22 @@ async fn handle_event(
                 log::debug!("Client with ID {} has been removed.", recipient);
             }
         }
+        Event::Document(DocumentRequest::Get(tx)) => {
+            let _ = tx.send(latest_document.clone());
+        }
+        Event::Document(DocumentRequest::Replace(document)) => {
+            let Ok(json) = serde_json::to_string(&document) else {
+                log::warn!("Replacement document could not be serialized.");
+                return;
+            };
+
+            log::debug!("Document replaced, sending it to all clients.");
+            *latest_document = *document;
+
+            for handler in handlers {
+                let _ = handler.send(json.clone()).await;
+            }
+        }
         Event::StopSignal => {
             log::debug!("Stop signal received, closing connection to clients.");
 
@@ -132,7 +182,11 @@ async fn handle_event(
 }
 
 pub struct State {
+    /// The origin given to the last client that connected. The server mints
+    /// its own ids under origin 0.
+    origin: u32,
     new_clients_tx: Sender<ClientHandler>,
+    document_tx: Sender<DocumentRequest>,
     stop_signal_tx: Sender<()>,
     task: Option<JoinHandle<()>>,
 }
@@ -143,13 +197,39 @@ impl State {
         session: Session,
         rx: Receiver<AggregatedMessage>,
     ) {
-        let client = ClientHandler::new(session, rx);
+        // After four billion connections, the first origins are handed out
+        // again.
+        self.origin = self.origin.checked_add(1).unwrap_or(1);
+        let origin = Origin {
+            origin: self.origin,
+        };
+
+        let client = ClientHandler::new(session, rx, origin);
         self.new_clients_tx
             .send(client)
             .await
             .expect("receive half should always be open");
     }
 
+    /// Returns a copy of the document that is currently shared by the clients.
+    pub async fn document(&mut self) -> Document {
+        let (tx, rx) = oneshot::channel();
+        self.document_tx
+            .send(DocumentRequest::Get(tx))
+            .await
+            .expect("receive half should always be open");
+        rx.await
+            .expect("document request should always be answered")
+    }
+
+    /// Replaces the shared document and sends it to every client.
+    pub async fn replace_document(&mut self, document: Document) {
+        self.document_tx
+            .send(DocumentRequest::Replace(Box::new(document)))
+            .await
+            .expect("receive half should always be open");
+    }
+
     pub async fn stop(&mut self) {
         let Some(task) = self.task.take() else {
             return;
@@ -168,6 +248,9 @@ impl Default for State {
         let (new_clients_tx, mut new_clients_rx) =
             tokio::sync::mpsc::channel::<ClientHandler>(100);
 
+        let (document_tx, mut document_rx) =
+            tokio::sync::mpsc::channel::<DocumentRequest>(100);
+
         let task = rt::spawn(async move {
             let mut handlers: Vec<ClientHandler> = vec![];
             let mut latest_document = Document::default();
@@ -176,6 +259,7 @@ impl Default for State {
                 let event = wait_for_event(
                     &mut handlers,
                     &mut new_clients_rx,
+                    &mut document_rx,
                     &mut stop_signal_rx,
                 )
                 .await;
@@ -190,7 +274,9 @@ impl Default for State {
         });
 
         Self {
+            origin: 0,
             new_clients_tx,
+            document_tx,
             stop_signal_tx,
             task: Some(task),
         }
@@ -0,0 +1,103 @@
+use uml_common::{
+    document::Document,
+    drawable::Drawable,
+    elements::{
+        Action, ControlNode, ControlNodeKind, ObjectNode, Relationship,
+        RelationshipKind, Swimlane,
+    },
+};
+
+#[test]
+fn swimlanes_enclose_and_move_their_nodes() {
+    let mut document = Document::default();
+    let start = document.add_element(ControlNode::new(
+        88.0,
+        80.0,
+        ControlNodeKind::Initial,
+    ));
+    let order = document.add_element(Action::new(50.0, 150.0, "Order".into()));
+    let mut customer = Swimlane::new(0.0, 0.0, 200.0, 200.0, "Customer".into());
+    customer.add_node(start);
+    customer.add_node(order);
+    let customer = document.add_element(customer);
+    document.attach_containers();
+
+    // The lane grows downwards to fit the action, keeping its own width.
+    assert_eq!(
+        document.get(order).unwrap().bounds().edges(),
+        (50.0, 150.0, 150.0, 190.0)
+    );
+    assert_eq!(
+        document.get(customer).unwrap().bounds().edges(),
+        (0.0, 0.0, 200.0, 210.0)
+    );
+
+    document.move_element(customer, 300.0, 10.0);
+    document.attach_containers();
+
+    assert_eq!(
+        document.get(start).unwrap().bounds().edges(),
+        (388.0, 90.0, 412.0, 114.0)
+    );
+    assert_eq!(
+        document.get(order).unwrap().bounds().edges(),
+        (350.0, 160.0, 450.0, 200.0)
+    );
+    assert_eq!(
+        document.get(customer).unwrap().bounds().edges(),
+        (300.0, 10.0, 500.0, 220.0)
+    );
+
+    // The nodes inside the lane stay selectable.
+    let lane = document.elements().iter().find(|el| el.id() == customer);
+    let lane = lane.unwrap();
+    assert!(lane.cursor_intersects(400.0, 15.0));
+    assert!(lane.cursor_intersects(300.0, 150.0));
+    assert!(!lane.cursor_intersects(400.0, 150.0));
+}
+
+#[test]
+fn guarded_flows_leave_decisions_at_their_corners() {
+    let mut document = Document::default();
+    let decision = document.add_element(ControlNode::new(
+        0.0,
+        0.0,
+        ControlNodeKind::Decision,
+    ));
+    let ship = document.add_element(Action::new(-30.0, 100.0, "Ship".into()));
+    let mut invoice = ObjectNode::new(150.0, 0.0, "Invoice".into());
+    invoice.set_in_state(Some("paid".into()));
+    assert_eq!(invoice.in_state(), Some("paid"));
+    let invoice = document.add_element(invoice);
+    let fork = document.add_element(ControlNode::new(
+        -30.0,
+        200.0,
+        ControlNodeKind::Fork,
+    ));
+
+    let mut flows = [(decision, ship), (decision, invoice), (ship, fork)].map(
+        |(source, target)| {
+            Relationship::new(source, target, RelationshipKind::ControlFlow)
+        },
+    );
+    flows[0].set_guard(Some("in stock"));
+    assert_eq!(flows[0].label(), Some("[in stock]"));
+    assert_eq!(flows[0].guard(), Some("in stock"));
+    flows[1].set_guard(None);
+    assert_eq!(flows[1].guard(), None);
+
+    for flow in flows {
+        document.add_element(flow);
+    }
+    document.attach_relationships();
+
+    let flows = document
+        .elements()
+        .iter()
+        .filter_map(|el| el.as_relationship())
+        .collect::<Vec<_>>();
+
+    assert_eq!(flows[0].points(), [(20.0, 40.0), (20.0, 100.0)]);
+    assert_eq!(flows[1].points(), [(40.0, 20.0), (150.0, 20.0)]);
+    assert_eq!(flows[2].points(), [(20.0, 140.0), (20.0, 200.0)]);
+}
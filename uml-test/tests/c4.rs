@@ -0,0 +1,92 @@
+use uml_common::{
+    document::Document,
+    drawable::Drawable,
+    elements::{C4Boundary, C4Element, C4Kind, Relationship, RelationshipKind},
+    id::Id,
+};
+
+#[test]
+fn elements_show_their_kind_and_technology() {
+    let mut api = C4Element::new(0.0, 0.0, C4Kind::Container, "API".into());
+    assert_eq!(api.kind_text(), "[Container]");
+
+    api.set_technology(Some("Rust, axum".into()));
+    assert_eq!(api.kind_text(), "[Container: Rust, axum]");
+
+    api.set_kind(C4Kind::Component);
+    assert_eq!(api.kind_text(), "[Component: Rust, axum]");
+
+    api.set_description("Serves orders\n\n  over JSON ");
+    assert_eq!(api.description(), "Serves orders\nover JSON");
+
+    let json = serde_json::to_string(&api).unwrap();
+    let restored = serde_json::from_str::<C4Element>(&json).unwrap();
+    assert_eq!(restored.kind_text(), "[Component: Rust, axum]");
+    assert_eq!(restored.description(), api.description());
+}
+
+#[test]
+fn people_have_a_head_on_top() {
+    let customer = C4Element::new(0.0, 0.0, C4Kind::Person, "Customer".into());
+    let shop = C4Element::new(0.0, 0.0, C4Kind::SoftwareSystem, "Shop".into());
+
+    assert_eq!(shop.bounds().edges(), (0.0, 0.0, 160.0, 100.0));
+    assert_eq!(customer.bounds().edges(), (0.0, 0.0, 160.0, 136.0));
+}
+
+#[test]
+fn boundaries_enclose_their_elements() {
+    let mut document = Document::default();
+    let shop = document.add_element(C4Boundary::new(
+        0.0,
+        0.0,
+        "Shop".into(),
+        Some(C4Kind::SoftwareSystem),
+    ));
+    let api = document.add_element(C4Element::new(
+        100.0,
+        100.0,
+        C4Kind::Container,
+        "API".into(),
+    ));
+    let customer = document.add_element(C4Element::new(
+        400.0,
+        0.0,
+        C4Kind::Person,
+        "Customer".into(),
+    ));
+
+    assert_eq!(
+        document.get(shop).unwrap().bounds().edges(),
+        (0.0, 0.0, 240.0, 184.0)
+    );
+
+    assert!(document.set_parent(api, Some(shop)));
+    document.attach_containers();
+    assert_eq!(
+        document.get(shop).unwrap().bounds().edges(),
+        (0.0, 0.0, 280.0, 220.0)
+    );
+
+    // Elements dropped in a boundary join it.
+    document.move_element(customer, -300.0, 0.0);
+    assert_eq!(document.drop_element(customer), Some(shop));
+
+    document.move_element(shop, 10.0, 10.0);
+    assert_eq!(
+        document.get(api).unwrap().bounds().edges(),
+        (110.0, 110.0, 270.0, 210.0)
+    );
+}
+
+#[test]
+fn relationships_are_labelled_with_their_technology() {
+    let mut uses =
+        Relationship::new(Id::default(), Id::default(), RelationshipKind::Uses);
+    uses.set_label(Some("Places orders using".into()));
+    uses.set_technology(Some("HTTPS".into()));
+
+    assert!(RelationshipKind::Uses.is_dashed());
+    assert_eq!(uses.caption().as_deref(), Some("Places orders using"));
+    assert_eq!(uses.technology(), Some("HTTPS"));
+}
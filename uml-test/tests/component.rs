@@ -0,0 +1,122 @@
+use uml_common::{
+    document::Document,
+    drawable::Drawable,
+    elements::{
+        Artifact, Component, DeploymentNode, Interface, InterfaceKind, Port,
+        Relationship, RelationshipKind,
+    },
+};
+
+#[test]
+fn ports_and_interfaces_follow_their_owner() {
+    let mut document = Document::default();
+    let shop = document.add_element(Component::new(0.0, 0.0, "Shop".into()));
+    let http =
+        document.add_element(Port::new(shop, 130.0, 20.0, "http".into()));
+    let api = document.add_element(Interface::new(
+        http,
+        200.0,
+        17.0,
+        InterfaceKind::Provided,
+        "API".into(),
+    ));
+    let payments = document.add_element(Interface::new(
+        shop,
+        -60.0,
+        16.0,
+        InterfaceKind::Required,
+        "Payments".into(),
+    ));
+    document.attach_ports();
+
+    let stem = |document: &Document, id| {
+        let el = document.elements().iter().find(|el| el.id() == id);
+        el.unwrap().as_interface().unwrap().stem()
+    };
+
+    // The port snaps onto the nearest side of the component.
+    assert_eq!(
+        document.get(shop).unwrap().bounds().edges(),
+        (0.0, 0.0, 140.0, 60.0)
+    );
+    assert_eq!(
+        document.get(http).unwrap().bounds().edges(),
+        (133.0, 20.0, 147.0, 34.0)
+    );
+    assert_eq!(stem(&document, api), Some((147.0, 27.0)));
+    assert_eq!(stem(&document, payments), Some((0.0, 30.0)));
+
+    document.move_element(shop, 10.0, 5.0);
+    document.attach_ports();
+
+    assert_eq!(
+        document.get(http).unwrap().bounds().edges(),
+        (143.0, 25.0, 157.0, 39.0)
+    );
+    assert_eq!(
+        document.get(api).unwrap().bounds().edges(),
+        (210.0, 22.0, 230.0, 42.0)
+    );
+    assert_eq!(stem(&document, api), Some((157.0, 32.0)));
+    assert_eq!(stem(&document, payments), Some((10.0, 35.0)));
+
+    // Dragged ports slide along the border.
+    document.move_element(http, -60.0, 100.0);
+    document.attach_ports();
+    assert_eq!(
+        document.get(http).unwrap().bounds().edges(),
+        (83.0, 58.0, 97.0, 72.0)
+    );
+}
+
+#[test]
+fn communication_paths_connect_deployment_nodes() {
+    let mut document = Document::default();
+    let server = document.add_element(DeploymentNode::new(
+        0.0,
+        0.0,
+        200.0,
+        100.0,
+        "Server".into(),
+    ));
+    let database = document.add_element(DeploymentNode::new(
+        400.0,
+        0.0,
+        200.0,
+        100.0,
+        "Database".into(),
+    ));
+    let jar =
+        document.add_element(Artifact::new(20.0, 40.0, "shop.jar".into()));
+    let mut path = Relationship::new(
+        server,
+        database,
+        RelationshipKind::CommunicationPath,
+    );
+    path.set_label(Some("JDBC".into()));
+    document.add_element(path);
+    document.attach_relationships();
+
+    // The box includes its top and right side.
+    assert_eq!(
+        document.get(server).unwrap().bounds().edges(),
+        (0.0, 0.0, 214.0, 114.0)
+    );
+    assert_eq!(
+        document.get(jar).unwrap().bounds().edges(),
+        (20.0, 40.0, 140.0, 90.0)
+    );
+
+    let path = document
+        .elements()
+        .iter()
+        .find_map(|el| el.as_relationship());
+    assert_eq!(path.unwrap().points(), [(214.0, 57.0), (400.0, 57.0)]);
+
+    // Only the title and the border of a node can be grabbed.
+    let server = document.elements().iter().find(|el| el.id() == server);
+    let server = server.unwrap();
+    assert!(server.cursor_intersects(100.0, 20.0));
+    assert!(server.cursor_intersects(2.0, 80.0));
+    assert!(!server.cursor_intersects(100.0, 80.0));
+}
@@ -0,0 +1,111 @@
+use serde_json::Value;
+use uml_common::{
+    document::Document,
+    elements::{Class, Package, Relationship, RelationshipKind},
+    id::{Id, Origin},
+    interaction::Interactive,
+};
+
+fn class(x: f64, y: f64, name: &str) -> Class {
+    Class::new(x, y, name.into(), None, None, None)
+}
+
+#[test]
+fn elements_are_looked_up_by_id() {
+    let mut document = Document::default();
+    let order = document.add_element(class(0.0, 0.0, "Order"));
+    let item = document.add_element(class(200.0, 0.0, "Item"));
+
+    assert!(document.contains(order));
+    assert_eq!(document.get(item).unwrap().x(), 200.0);
+
+    document.get_mut(order).unwrap().adjust_position(50.0, 0.0);
+    assert_eq!(document.get(order).unwrap().x(), 50.0);
+}
+
+#[test]
+fn removed_elements_leave_their_container() {
+    let mut document = Document::default();
+    let shop = document.add_element(Package::new(0.0, 0.0, "shop".into()));
+    let order = document.add_element(class(20.0, 40.0, "Order"));
+    let item = document.add_element(class(200.0, 40.0, "Item"));
+    assert!(document.set_parent(order, Some(shop)));
+
+    let removed = document.remove(order).unwrap();
+    assert_eq!(removed.id(), order);
+    assert!(!document.contains(order));
+    assert!(document.remove(order).is_none());
+    assert_eq!(document.get(shop).unwrap().contents(), &[]);
+
+    // Elements after the removed one are still found.
+    assert_eq!(document.get(item).unwrap().x(), 200.0);
+    assert_eq!(document.elements().len(), 2);
+}
+
+/// Writes every whole number in `value` without a fraction, as documents
+/// saved with integer coordinates were.
+fn as_integers(value: &mut Value) {
+    match value {
+        Value::Number(n) => {
+            if let Some(f) = n.as_f64().filter(|f| f.fract() == 0.0) {
+                *value = Value::from(f as i64);
+            }
+        }
+        Value::Array(values) => values.iter_mut().for_each(as_integers),
+        Value::Object(map) => map.values_mut().for_each(as_integers),
+        _ => (),
+    }
+}
+
+#[test]
+fn documents_with_integer_coordinates_still_load() {
+    let mut document = Document::default();
+    let order = document.add_element(class(200.0, 40.0, "Order"));
+    let item = document.add_element(class(0.0, 300.0, "Item"));
+    document.add_element(Relationship::new(
+        order,
+        item,
+        RelationshipKind::Association,
+    ));
+
+    let mut json = serde_json::to_value(&document).unwrap();
+    as_integers(&mut json);
+    assert!(json.to_string().contains(r#""x":200,"y":40"#));
+
+    let mut restored = serde_json::from_value::<Document>(json).unwrap();
+    assert_eq!(restored.elements(), document.elements());
+
+    // Positions are kept to fractions of a unit from then on.
+    restored.move_element(order, 0.25, -0.5);
+    let moved = restored.get(order).unwrap();
+    assert_eq!((moved.x(), moved.y()), (200.25, 39.5));
+}
+
+#[test]
+fn documents_with_duplicate_ids_are_rejected() {
+    let mut document = Document::default();
+    document.add_element(class(0.0, 0.0, "Order"));
+    document.add_element(class(200.0, 0.0, "Item"));
+
+    let mut json = serde_json::to_value(&document).unwrap();
+    json["elements"][1]["id"] = json["elements"][0]["id"].clone();
+
+    let error = serde_json::from_value::<Document>(json).unwrap_err();
+    assert!(error.to_string().contains("more than one element"));
+}
+
+#[test]
+fn ids_are_minted_under_the_origin_of_the_client() {
+    Id::set_origin(Origin { origin: 7 });
+
+    // Ids received from elsewhere do not affect the ones minted here.
+    let received = serde_json::from_str::<Id>(&u64::MAX.to_string()).unwrap();
+    let minted = [Id::default(), Id::default()];
+
+    for id in minted {
+        let value = serde_json::to_value(id).unwrap().as_u64().unwrap();
+        assert_eq!(value >> 32, 7);
+        assert_ne!(id, received);
+    }
+    assert_ne!(minted[0], minted[1]);
+}
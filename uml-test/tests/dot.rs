@@ -0,0 +1,52 @@
+use uml_common::format::{dot, mermaid};
+
+#[test]
+fn export_records_and_edges() {
+    let document = mermaid::import(
+        r#"classDiagram
+    class Shape {
+        <<interface>>
+        +area() double*
+    }
+    class Polygon {
+        -List~Point~ points
+    }
+    Shape <|.. Polygon
+    Polygon "1" *-- "3..*" Point : vertices
+"#,
+    )
+    .expect("diagram should parse");
+    let ids = document
+        .elements()
+        .iter()
+        .map(|el| el.id())
+        .collect::<Vec<_>>();
+
+    let output = dot::export(&document, false);
+
+    assert!(output.starts_with("digraph {\n"));
+    assert!(output.contains(&format!(
+        r#"n{} [shape=record, label="{{«interface»\nShape||\{{abstract\}} +area(): double\l}}"];"#,
+        ids[0]
+    )));
+    assert!(output.contains(r"-points: List\<Point\>\l"));
+    assert!(output.contains(&format!(
+        "n{} -> n{} [arrowhead=empty, style=dashed];",
+        ids[1], ids[0]
+    )));
+    assert!(output.contains(&format!(
+        r#"n{} -> n{} [arrowhead=diamond, label="vertices", taillabel="3..*", headlabel="1"];"#,
+        ids[2], ids[1]
+    )));
+    assert!(!output.contains("pos="));
+}
+
+#[test]
+fn keep_positions() {
+    let document =
+        mermaid::import("classDiagram\n    class A\n").expect("should parse");
+    let output = dot::export(&document, true);
+
+    assert!(output.contains("pos=\""));
+    assert!(output.contains("!\""));
+}
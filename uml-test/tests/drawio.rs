@@ -0,0 +1,129 @@
+use uml_common::{
+    elements::RelationshipKind,
+    format::{drawio, mermaid},
+};
+
+const FILE: &str = r#"<mxfile host="app.diagrams.net">
+  <diagram id="page" name="Page-1">
+    <mxGraphModel dx="800" dy="600" grid="1" gridSize="10">
+      <root>
+        <mxCell id="0"/>
+        <mxCell id="1" parent="0"/>
+        <mxCell id="animal" value="&lt;&lt;abstract&gt;&gt;&lt;br&gt;Animal" style="swimlane;fontStyle=1;childLayout=stackLayout;html=1;" vertex="1" parent="1">
+          <mxGeometry x="200" y="40" width="160" height="86" as="geometry"/>
+        </mxCell>
+        <mxCell id="name" value="+ name: String" style="text;strokeColor=none;fillColor=none;" vertex="1" parent="animal">
+          <mxGeometry y="40" width="160" height="26" as="geometry"/>
+        </mxCell>
+        <mxCell id="line" style="line;strokeWidth=1;" vertex="1" parent="animal">
+          <mxGeometry y="66" width="160" height="8" as="geometry"/>
+        </mxCell>
+        <mxCell id="speak" value="+ speak(): String" style="text;strokeColor=none;fillColor=none;" vertex="1" parent="animal">
+          <mxGeometry y="74" width="160" height="26" as="geometry"/>
+        </mxCell>
+        <mxCell id="leg" value="&lt;p&gt;&lt;b&gt;Leg&lt;/b&gt;&lt;/p&gt;&lt;hr&gt;&lt;p&gt;- length: int&lt;/p&gt;" style="verticalAlign=top;align=left;overflow=fill;html=1;" vertex="1" parent="1">
+          <mxGeometry x="20" y="240" width="120" height="60" as="geometry"/>
+        </mxCell>
+        <mxCell id="group" value="" style="group" vertex="1" parent="1">
+          <mxGeometry x="400" y="300" width="100" height="100" as="geometry"/>
+        </mxCell>
+        <mxCell id="box" value="Zoo" style="rounded=1;whiteSpace=wrap;fillColor=#dae8fc;strokeColor=#6c8ebf;" vertex="1" parent="group">
+          <mxGeometry x="10" y="20" width="80" height="40" as="geometry"/>
+        </mxCell>
+        <mxCell id="has" value="has" style="endArrow=open;startArrow=diamondThin;startFill=1;" edge="1" parent="1" source="animal" target="leg">
+          <mxGeometry relative="1" as="geometry"/>
+        </mxCell>
+        <mxCell id="has_source" value="1" style="edgeLabel;" vertex="1" connectable="0" parent="has">
+          <mxGeometry x="-1" relative="1" as="geometry"/>
+        </mxCell>
+        <mxCell id="has_target" value="4" style="edgeLabel;" vertex="1" connectable="0" parent="has">
+          <mxGeometry x="1" relative="1" as="geometry"/>
+        </mxCell>
+        <mxCell id="dangling" style="endArrow=classic;" edge="1" parent="1" source="box">
+          <mxGeometry relative="1" as="geometry"/>
+        </mxCell>
+      </root>
+    </mxGraphModel>
+  </diagram>
+</mxfile>
+"#;
+
+#[test]
+fn import_shapes_and_edges() {
+    let document = drawio::import(FILE).expect("file should parse");
+    let elements = document.elements();
+
+    let animal = elements[0].as_class().expect("swimlane should be a class");
+    assert_eq!(animal.name(), "Animal");
+    assert_eq!(animal.stereotype(), Some("abstract"));
+    assert_eq!(animal.attributes().collect::<Vec<_>>(), ["+ name: String"]);
+    assert_eq!(
+        animal.operations().collect::<Vec<_>>(),
+        ["+ speak(): String"]
+    );
+
+    let leg = elements[1]
+        .as_class()
+        .expect("HTML class should be a class");
+    assert_eq!(leg.name(), "Leg");
+    assert_eq!(leg.attributes().collect::<Vec<_>>(), ["- length: int"]);
+
+    let zoo = elements[2]
+        .as_rectangle()
+        .expect("shape should be a rectangle");
+    assert_eq!((zoo.x(), zoo.y()), (410.0, 320.0));
+    assert_eq!(elements[3].as_label().map(|l| l.text()), Some("Zoo"));
+
+    // The diamond is drawn at the source in draw.io, but at the target here.
+    let has = elements[4].as_relationship().expect("edge should be kept");
+    assert_eq!(has.kind(), RelationshipKind::Composition);
+    assert_eq!(has.source(), elements[1].id());
+    assert_eq!(has.target(), elements[0].id());
+    assert_eq!(has.source_multiplicity(), Some("4"));
+    assert_eq!(has.target_multiplicity(), Some("1"));
+    assert_eq!(has.label(), Some("has"));
+
+    assert_eq!(elements.len(), 5);
+}
+
+#[test]
+fn import_compressed_page() {
+    let file = r#"<mxfile><diagram id="page" name="Page-1">jZDdDoIwDIWfpvdjS3wAQfHGh1hCw0g2R0ZVeHsbWiRekHixpP3OTv/A1Wluix/DPXcYwV3A1SVnkijNNcYI1gwduAasNfzAXg/UalXN6As+6B+DF8PLxycKufGHLHSiJSolnLneOVDiEZuKQzViYemw+Yq0c4s5IZWFv2wGmc0skuqo5j10FASdFAUc+qA1nTI/Sd5/6+57cqCrbul+0lX7ufgH</diagram></mxfile>"#;
+    let document = drawio::import(file).expect("page should decompress");
+    let label = document.elements()[0].as_label().expect("text is a label");
+
+    assert_eq!(label.text(), "Hello");
+    assert_eq!(label.x(), 10.0);
+}
+
+#[test]
+fn export_round_trips() {
+    let document = mermaid::import(
+        r#"classDiagram
+    class Shape {
+        <<interface>>
+        +area() double*
+    }
+    class Circle {
+        -double radius
+    }
+    Shape <|.. Circle
+    Circle "1" *-- "*" Point : center
+"#,
+    )
+    .expect("diagram should parse");
+
+    let exported = drawio::export(&document);
+    let reimported = drawio::import(&exported).expect("export should parse");
+
+    assert_eq!(mermaid::export(&reimported), mermaid::export(&document));
+
+    for (original, reimported) in
+        document.elements().iter().zip(reimported.elements())
+    {
+        assert_eq!(
+            (original.x(), original.y()),
+            (reimported.x(), reimported.y())
+        );
+    }
+}
@@ -0,0 +1,114 @@
+use uml_common::{
+    document::Document,
+    elements::{
+        Cardinality, Class, Entity, EntityAttribute, Key, Relationship,
+        RelationshipKind,
+    },
+    format::drawio,
+};
+
+#[test]
+fn attributes_are_parsed_with_their_keys() {
+    let attribute = EntityAttribute::parse("PK, FK order_id: int");
+    assert_eq!(attribute.keys(), [Key::Primary, Key::Foreign]);
+    assert_eq!(attribute.name(), "order_id");
+    assert_eq!(attribute.ty(), Some("int"));
+    assert_eq!(attribute.text(), "PK, FK order_id: int");
+
+    let attribute = EntityAttribute::parse("uk email");
+    assert_eq!(attribute.keys(), [Key::Unique]);
+    assert_eq!(attribute.name(), "email");
+    assert_eq!(attribute.ty(), None);
+
+    // Names that merely start like a key are not keys.
+    let attribute = EntityAttribute::parse("pkg: text");
+    assert!(attribute.keys().is_empty());
+    assert_eq!(attribute.text(), "pkg: text");
+}
+
+#[test]
+fn entities_know_their_primary_key() {
+    let mut line = Entity::new(0.0, 0.0, "order_line".into());
+    line.set_attributes([
+        EntityAttribute::new("order_id", Some("int"), &[Key::Primary]),
+        EntityAttribute::new("position", Some("int"), &[Key::Primary]),
+        EntityAttribute::new("product_id", Some("int"), &[Key::Foreign]),
+    ]);
+    line.add_attribute(EntityAttribute::parse("amount: int"));
+
+    assert_eq!(line.primary_key(), ["order_id", "position"]);
+    assert_eq!(line.attributes().len(), 4);
+
+    let json = serde_json::to_string(&line).unwrap();
+    assert_eq!(serde_json::from_str::<Entity>(&json).unwrap(), line);
+}
+
+#[test]
+fn cardinalities_are_stored_as_multiplicities() {
+    let mut document = Document::default();
+    let customer =
+        document.add_element(Entity::new(0.0, 0.0, "customer".into()));
+    let order = document.add_element(Entity::new(300.0, 0.0, "order".into()));
+
+    let mut places = Relationship::new(
+        customer,
+        order,
+        RelationshipKind::EntityRelationship,
+    );
+    places.set_cardinalities(Some(Cardinality::One), Some(Cardinality::Many));
+    assert_eq!(places.source_multiplicity(), Some("1"));
+    assert_eq!(places.target_multiplicity(), Some("*"));
+
+    places.set_multiplicities(Some("0..1".into()), Some("1..*".into()));
+    assert_eq!(places.source_cardinality(), Some(Cardinality::ZeroOrOne));
+    assert_eq!(places.target_cardinality(), Some(Cardinality::OneOrMany));
+
+    places.set_multiplicities(Some("2..4".into()), None);
+    assert_eq!(places.source_cardinality(), None);
+}
+
+#[test]
+fn crows_feet_round_trip_through_drawio() {
+    let mut document = Document::default();
+    let customer = document.add_element(Class::new(
+        0.0,
+        0.0,
+        "Customer".into(),
+        None,
+        None,
+        None,
+    ));
+    let order = document.add_element(Class::new(
+        300.0,
+        0.0,
+        "Order".into(),
+        None,
+        None,
+        None,
+    ));
+
+    let mut places = Relationship::new(
+        customer,
+        order,
+        RelationshipKind::EntityRelationship,
+    );
+    places.set_cardinalities(
+        Some(Cardinality::One),
+        Some(Cardinality::OneOrMany),
+    );
+    document.add_element(places);
+
+    let file = drawio::export(&document);
+    assert!(file.contains("startArrow=ERmandOne;endArrow=ERoneToMany;"));
+
+    let imported = drawio::import(&file).unwrap();
+    let places = imported
+        .elements()
+        .iter()
+        .find_map(|el| el.as_relationship())
+        .unwrap();
+
+    assert_eq!(places.kind(), RelationshipKind::EntityRelationship);
+    assert_eq!(places.source_cardinality(), Some(Cardinality::One));
+    assert_eq!(places.target_cardinality(), Some(Cardinality::OneOrMany));
+}
@@ -0,0 +1,70 @@
+use uml_common::geometry::{Point, Rect, Transform, Vector};
+
+#[test]
+fn rects_combine_and_contain() {
+    let a = Rect::new(0.0, 0.0, 100.0, 50.0);
+    let b = Rect::from_edges(150.0, 80.0, 80.0, 20.0);
+
+    assert_eq!(b.edges(), (80.0, 20.0, 150.0, 80.0));
+    assert_eq!(a.union(b).edges(), (0.0, 0.0, 150.0, 80.0));
+    assert_eq!(
+        a.intersection(b).map(|r| r.edges()),
+        Some((80.0, 20.0, 100.0, 50.0))
+    );
+    assert_eq!(a.intersection(Rect::new(200.0, 0.0, 10.0, 10.0)), None);
+
+    assert!(a.contains(Point::new(100.0, 50.0)));
+    assert!(!a.contains(Point::new(101.0, 25.0)));
+    assert!(a.union(b).contains_rect(b));
+    assert!(!a.contains_rect(b));
+
+    assert_eq!(
+        Rect::from_points([(10.0, 40.0), (-5.0, 0.0), (30.0, 20.0)])
+            .map(|r| r.edges()),
+        Some((-5.0, 0.0, 30.0, 40.0))
+    );
+    assert_eq!(Rect::from_points(Vec::<Point>::new()), None);
+}
+
+#[test]
+fn inflating_never_turns_a_rect_inside_out() {
+    let rect = Rect::new(0.0, 0.0, 100.0, 20.0);
+
+    assert_eq!(rect.inflate(6.0).edges(), (-6.0, -6.0, 106.0, 26.0));
+    assert_eq!(rect.inflate(-5.0).edges(), (5.0, 5.0, 95.0, 15.0));
+    assert_eq!(rect.inflate(-50.0).edges(), (50.0, 10.0, 50.0, 10.0));
+}
+
+#[test]
+fn distances_to_segments_and_borders() {
+    let a = Point::new(0.0, 0.0);
+    let b = Point::new(100.0, 0.0);
+
+    assert_eq!(Point::new(50.0, 30.0).distance_to_segment(a, b), 30.0);
+    assert_eq!(Point::new(130.0, 40.0).distance_to_segment(a, b), 50.0);
+    assert_eq!(Point::new(3.0, 4.0).distance_to_segment(a, a), 5.0);
+
+    let rect = Rect::new(0.0, 0.0, 100.0, 100.0);
+    assert_eq!(rect.distance_to_border(Point::new(50.0, 10.0)), 10.0);
+    assert_eq!(rect.distance_to_border(Point::new(-20.0, 50.0)), 20.0);
+
+    let unit = Vector::new(3.0, 4.0).normalized();
+    assert_eq!((unit.x(), unit.y()), (0.6, 0.8));
+    assert_eq!(a.offset(unit * 10.0), Point::new(6.0, 8.0));
+}
+
+#[test]
+fn transforms_invert_and_map_rects() {
+    let transform =
+        Transform::translation(-10.0, 5.0).then(Transform::scale(2.0, 2.0));
+
+    assert_eq!(transform.apply(10.0, -5.0), (0.0, 0.0));
+    assert_eq!(transform.apply(11.0, -5.0), (2.0, 0.0));
+
+    let inverse = transform.inverse().unwrap();
+    assert_eq!(inverse.apply(0.0, 0.0), (10.0, -5.0));
+    assert_eq!(Transform::scale(0.0, 1.0).inverse(), None);
+
+    let rect = inverse.apply_rect(Rect::new(0.0, 0.0, 3.0, 3.0));
+    assert_eq!(rect.edges(), (10.0, -5.0, 11.5, -3.5));
+}
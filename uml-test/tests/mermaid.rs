@@ -0,0 +1,95 @@
+use uml_common::{
+    elements::RelationshipKind,
+    format::mermaid::{export, import},
+};
+
+const DIAGRAM: &str = r#"classDiagram
+    %% A comment
+    class Animal {
+        <<abstract>>
+        +String name
+        -int age$
+        +makeSound(int volume) String*
+    }
+    class Duck["Rubber duck"]
+    Animal <|-- Duck
+    Animal "1" *-- "0..*" Leg : has
+    Zoo o-- Animal
+"#;
+
+#[test]
+fn import_classes_members_and_relationships() {
+    let document = import(DIAGRAM).expect("diagram should parse");
+    let classes = document
+        .elements()
+        .iter()
+        .filter_map(|el| el.as_class())
+        .collect::<Vec<_>>();
+
+    let names = classes.iter().map(|c| c.name()).collect::<Vec<_>>();
+    assert_eq!(names, ["Animal", "Rubber duck", "Leg", "Zoo"]);
+
+    let animal = classes[0];
+    assert_eq!(animal.stereotype(), Some("abstract"));
+    assert_eq!(
+        animal.attributes().collect::<Vec<_>>(),
+        ["+name: String", "{static} -age: int"]
+    );
+    assert_eq!(
+        animal.operations().collect::<Vec<_>>(),
+        ["{abstract} +makeSound(volume: int): String"]
+    );
+
+    let relationships = document
+        .elements()
+        .iter()
+        .filter_map(|el| el.as_relationship())
+        .collect::<Vec<_>>();
+
+    assert_eq!(relationships.len(), 3);
+    assert_eq!(relationships[0].kind(), RelationshipKind::Generalization);
+    assert_eq!(relationships[1].kind(), RelationshipKind::Composition);
+    assert_eq!(relationships[1].source_multiplicity(), Some("0..*"));
+    assert_eq!(relationships[1].target_multiplicity(), Some("1"));
+    assert_eq!(relationships[1].label(), Some("has"));
+}
+
+#[test]
+fn export_round_trips() {
+    let document = import(DIAGRAM).expect("diagram should parse");
+    let exported = export(&document);
+    let reimported = import(&exported).expect("export should parse");
+
+    assert_eq!(export(&reimported), exported);
+    assert!(exported.contains("class Rubber_duck[\"Rubber duck\"]"));
+    assert!(exported.contains("Leg \"0..*\" --* \"1\" Animal : has"));
+}
+
+#[test]
+fn package_members_round_trip() {
+    let diagram = r#"classDiagram
+    class Counter {
+        ~int count
+        ~List~String~ names
+        ~reset() void
+    }
+"#;
+    let document = import(diagram).expect("diagram should parse");
+    let counter = document.elements()[0].as_class().unwrap();
+
+    assert_eq!(
+        counter.attributes().collect::<Vec<_>>(),
+        ["~count: int", "~names: List<String>"]
+    );
+    assert_eq!(counter.operations().collect::<Vec<_>>(), ["~reset(): void"]);
+
+    let exported = export(&document);
+    assert!(exported.contains("~int count"));
+    assert_eq!(export(&import(&exported).unwrap()), exported);
+}
+
+#[test]
+fn reject_other_diagram_types() {
+    let error = import("sequenceDiagram\n    A->>B: hi").unwrap_err();
+    assert_eq!(error.line(), Some(1));
+}
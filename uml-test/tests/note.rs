@@ -0,0 +1,68 @@
+use uml_common::{
+    document::Document,
+    elements::{Note, Package, UseCase},
+    interaction::Interactive,
+    prompt::PromptResponse,
+};
+
+#[test]
+fn anchor_lines_follow_both_ends() {
+    let mut document = Document::default();
+    let mut note = Note::new(0.0, 0.0, "Orders are\nkept for a year");
+    let package =
+        document.add_element(Package::new(200.0, 0.0, "orders".into()));
+    let use_case =
+        document.add_element(UseCase::new(0.0, 200.0, "Archive".into()));
+    note.add_anchor(package);
+    note.add_anchor(use_case);
+    let note = document.add_element(note);
+    document.attach_notes();
+
+    // Lines are clipped to the outlines exactly; compare them to the pixel.
+    let round = |(x, y): (f64, f64)| (x.round(), y.round());
+    let lines = |document: &Document| {
+        document
+            .elements()
+            .iter()
+            .find_map(|el| el.as_note())
+            .unwrap()
+            .anchor_lines()
+            .iter()
+            .map(|(from, to)| (round(*from), round(*to)))
+            .collect::<Vec<_>>()
+    };
+
+    assert_eq!(
+        lines(&document),
+        [((80.0, 26.0), (200.0, 44.0)), ((42.0, 40.0), (58.0, 200.0))]
+    );
+
+    document.move_element(package, 0.0, 100.0);
+    document.attach_notes();
+    assert_eq!(lines(&document)[0], ((75.0, 40.0), (200.0, 111.0)));
+
+    document.move_element(note, 0.0, 100.0);
+    document.attach_notes();
+    assert_eq!(lines(&document)[1], ((44.0, 140.0), (55.0, 200.0)));
+}
+
+#[test]
+fn notes_are_edited_line_by_line() {
+    let mut note = Note::new(0.0, 0.0, "first\nsecond");
+    assert_eq!(note.text(), "first\nsecond");
+
+    let answer = |note: &mut Note, y, response: &str| {
+        let prompt = note.click(5.0, y).expect("notes are editable");
+        note.prompt(PromptResponse::Text {
+            response: response.into(),
+            metadata: prompt.metadata(),
+        });
+    };
+
+    // Clicking below the last line adds one.
+    answer(&mut note, 100.0, "third");
+    assert_eq!(note.text(), "first\nsecond\nthird");
+
+    answer(&mut note, 0.0, "");
+    assert_eq!(note.text(), "second\nthird");
+}
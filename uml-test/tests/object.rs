@@ -0,0 +1,123 @@
+use uml_common::{
+    document::Document,
+    elements::{Class, InstanceSpecification, Relationship, RelationshipKind},
+};
+
+fn class(name: &str, attributes: &[&str]) -> Class {
+    let mut class = Class::new(0.0, 0.0, name.into(), None, None, None);
+    class.set_attributes(attributes.iter().copied());
+    class
+}
+
+fn instance<'a>(
+    document: &'a Document,
+    name: &str,
+) -> &'a InstanceSpecification {
+    document
+        .elements()
+        .iter()
+        .filter_map(|el| el.as_instance_specification())
+        .find(|i| i.name() == name)
+        .unwrap()
+}
+
+#[test]
+fn titles_name_the_class() {
+    let mut named = InstanceSpecification::new(0.0, 0.0, "order".into(), None);
+    assert_eq!(named.title(), "order");
+
+    named.set_classifier(Some("Order".into()));
+    assert_eq!(named.title(), "order : Order");
+
+    let anonymous =
+        InstanceSpecification::new(0.0, 0.0, "".into(), Some("Order".into()));
+    assert_eq!(anonymous.title(), ": Order");
+
+    // A linked class takes over the written classifier.
+    let mut document = Document::default();
+    let class = document.add_element(class("Invoice", &[]));
+    named.set_class(Some(class));
+    document.add_element(named);
+    document.attach_instances();
+
+    let order = instance(&document, "order");
+    assert_eq!(order.classifier(), Some("Invoice"));
+    assert_eq!(order.title(), "order : Invoice");
+}
+
+#[test]
+fn slots_are_checked_against_the_class() {
+    let mut document = Document::default();
+    let class =
+        document.add_element(class("Order", &["+ id: u32", "- total: f64"]));
+
+    let mut order = InstanceSpecification::new(0.0, 0.0, "order".into(), None);
+    order.set_slot("id", "42");
+    order.set_slot("total", "9.5");
+    order.set_slot("customer", "ann");
+    order.set_slot("id", "43");
+    assert_eq!(
+        order.slots().collect::<Vec<_>>(),
+        [("id", "43"), ("total", "9.5"), ("customer", "ann")]
+    );
+
+    // Without a class nothing is checked.
+    assert!(order.unknown_slots().is_empty());
+
+    order.set_class(Some(class));
+    document.add_element(order);
+    document.attach_instances();
+    assert_eq!(instance(&document, "order").unknown_slots(), ["customer"]);
+}
+
+#[test]
+fn links_are_typed_by_associations() {
+    let mut document = Document::default();
+    let order = document.add_element(class("Order", &[]));
+    let customer = document.add_element(class("Customer", &[]));
+
+    let mut placed_by =
+        Relationship::new(order, customer, RelationshipKind::Association);
+    placed_by.set_label(Some("placed by".into()));
+    let association = document.add_element(placed_by);
+
+    let mut add_instance = |name: &str, class| {
+        let mut instance =
+            InstanceSpecification::new(0.0, 0.0, name.into(), None);
+        instance.set_class(Some(class));
+        document.add_element(instance)
+    };
+    let first = add_instance("first", order);
+    let second = add_instance("second", order);
+    let ann = add_instance("ann", customer);
+
+    let mut add_link = |source, target| {
+        let mut link =
+            Relationship::new(source, target, RelationshipKind::Link);
+        link.set_association(Some(association));
+        document.add_element(link)
+    };
+    let typed = add_link(ann, first);
+    let mistyped = add_link(first, second);
+    document.attach_instances();
+
+    let link = |document: &Document, id| {
+        document
+            .elements()
+            .iter()
+            .find(|el| el.id() == id)
+            .and_then(|el| el.as_relationship())
+            .unwrap()
+            .clone()
+    };
+
+    // Links can go either way along their association.
+    let typed_link = link(&document, typed);
+    assert!(!typed_link.is_mistyped());
+    assert_eq!(typed_link.caption().as_deref(), Some("placed by"));
+    assert!(link(&document, mistyped).is_mistyped());
+
+    document.remove(association);
+    document.attach_instances();
+    assert!(link(&document, typed).is_mistyped());
+}
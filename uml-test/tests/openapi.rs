@@ -0,0 +1,136 @@
+use uml_common::{elements::RelationshipKind, format::openapi::import};
+
+const SPEC: &str = r#"
+openapi: 3.0.3
+info:
+  title: Pet store
+  version: 1.0.0
+paths: {}
+components:
+  schemas:
+    Pet:
+      type: object
+      required: [id, name]
+      properties:
+        id:
+          type: integer
+          format: int64
+        name:
+          type: string
+        status:
+          $ref: '#/components/schemas/Status'
+        tags:
+          type: array
+          items:
+            $ref: '#/components/schemas/Tag'
+    Dog:
+      allOf:
+        - $ref: '#/components/schemas/Pet'
+        - type: object
+          required: [breed]
+          properties:
+            breed:
+              type: string
+    Tag:
+      type: object
+      properties:
+        label:
+          type: string
+    Status:
+      type: string
+      enum: [available, sold]
+"#;
+
+#[test]
+fn import_openapi_components() {
+    let document = import(SPEC).expect("spec should parse");
+    let classes = document
+        .elements()
+        .iter()
+        .filter_map(|el| el.as_class())
+        .collect::<Vec<_>>();
+
+    let names = classes.iter().map(|c| c.name()).collect::<Vec<_>>();
+    assert_eq!(names, ["Pet", "Dog", "Tag", "Status"]);
+
+    assert_eq!(
+        classes[0].attributes().collect::<Vec<_>>(),
+        [
+            "id: int64",
+            "name: string",
+            "status: Status [0..1]",
+            "tags: Tag [*]"
+        ]
+    );
+    assert_eq!(
+        classes[1].attributes().collect::<Vec<_>>(),
+        ["breed: string"]
+    );
+    assert_eq!(classes[3].stereotype(), Some("enumeration"));
+    assert_eq!(
+        classes[3].attributes().collect::<Vec<_>>(),
+        ["available", "sold"]
+    );
+
+    let ids = document
+        .elements()
+        .iter()
+        .map(|el| el.id())
+        .collect::<Vec<_>>();
+    let relationships = document
+        .elements()
+        .iter()
+        .filter_map(|el| el.as_relationship())
+        .collect::<Vec<_>>();
+
+    assert_eq!(relationships.len(), 3);
+    assert_eq!(relationships[0].kind(), RelationshipKind::Generalization);
+    assert_eq!(
+        (relationships[0].source(), relationships[0].target()),
+        (ids[1], ids[0])
+    );
+    assert_eq!(relationships[1].kind(), RelationshipKind::Association);
+    assert_eq!(relationships[1].target(), ids[3]);
+    assert_eq!(relationships[1].label(), Some("status"));
+    assert_eq!(relationships[1].target_multiplicity(), Some("0..1"));
+    assert_eq!(relationships[2].target(), ids[2]);
+    assert_eq!(relationships[2].target_multiplicity(), Some("*"));
+}
+
+#[test]
+fn import_json_schema() {
+    let schema = r##"{
+        "$schema": "https://json-schema.org/draft/2020-12/schema",
+        "title": "Order",
+        "type": "object",
+        "required": ["lines"],
+        "properties": {
+            "note": { "type": ["string", "null"] },
+            "lines": { "type": "array", "items": { "$ref": "#/$defs/Line" } }
+        },
+        "$defs": {
+            "Line": {
+                "type": "object",
+                "properties": { "quantity": { "type": "integer" } }
+            }
+        }
+    }"##;
+
+    let document = import(schema).expect("schema should parse");
+    let order = document.elements()[0].as_class().expect("root is a class");
+
+    assert_eq!(order.name(), "Order");
+    assert_eq!(
+        order.attributes().collect::<Vec<_>>(),
+        ["note: string [0..1]", "lines: Line [*]"]
+    );
+    assert_eq!(document.elements().len(), 3);
+}
+
+#[test]
+fn reject_files_without_schemas() {
+    let error = import("{\n  \"openapi\": \"3.0.0\",\n  oops\n}").unwrap_err();
+    assert_eq!(error.line(), Some(3));
+
+    assert!(import("openapi: 3.0.0\npaths: {}\n").is_err());
+}
@@ -0,0 +1,91 @@
+use uml_common::{
+    document::Document,
+    drawable::Drawable,
+    elements::{Class, Package, Relationship, RelationshipKind},
+};
+
+#[test]
+fn packages_nest_and_move_their_members() {
+    let mut document = Document::default();
+    let outer = document.add_element(Package::new(0.0, 0.0, "shop".into()));
+    let inner = document.add_element(Package::new(40.0, 40.0, "orders".into()));
+    let order = document.add_element(Class::new(
+        100.0,
+        100.0,
+        "Order".into(),
+        None,
+        None,
+        None,
+    ));
+
+    assert!(document.set_parent(inner, Some(outer)));
+    assert!(document.set_parent(order, Some(inner)));
+    document.attach_containers();
+
+    assert_eq!(document.parent(order), Some(inner));
+    assert_eq!(document.parent(inner), Some(outer));
+    assert_eq!(document.parent(outer), None);
+    assert_eq!(
+        document.get(inner).unwrap().bounds().edges(),
+        (40.0, 40.0, 200.0, 152.0)
+    );
+    assert_eq!(
+        document.get(outer).unwrap().bounds().edges(),
+        (0.0, 0.0, 220.0, 172.0)
+    );
+
+    // Packages cannot be nested in themselves, and classes hold no members.
+    assert!(!document.set_parent(outer, Some(inner)));
+    assert!(!document.set_parent(outer, Some(outer)));
+    assert!(!document.set_parent(inner, Some(order)));
+    assert_eq!(document.parent(outer), None);
+
+    document.move_element(outer, 10.0, 20.0);
+
+    assert_eq!(
+        document.get(order).unwrap().bounds().edges(),
+        (110.0, 120.0, 110.0, 120.0)
+    );
+    assert_eq!(document.get(inner).unwrap().bounds().edges().0, 50.0);
+}
+
+#[test]
+fn dropping_an_element_re_parents_it() {
+    let mut document = Document::default();
+    let outer = document.add_element(Package::new(0.0, 0.0, "shop".into()));
+    let inner = document.add_element(Package::new(40.0, 40.0, "orders".into()));
+    let order = document.add_element(Class::new(
+        100.0,
+        100.0,
+        "Order".into(),
+        None,
+        None,
+        None,
+    ));
+    document.attach_containers();
+
+    // The class lies in both packages, and goes into the innermost one.
+    assert_eq!(document.drop_element(inner), Some(outer));
+    assert_eq!(document.drop_element(order), Some(inner));
+    assert_eq!(
+        document.get(outer).unwrap().bounds().edges(),
+        (0.0, 0.0, 220.0, 172.0)
+    );
+
+    // Dragged out, the class leaves its package, which shrinks back.
+    document.move_element(order, 400.0, 0.0);
+    document.attach_containers();
+    assert_eq!(
+        document.get(inner).unwrap().bounds().edges(),
+        (40.0, 40.0, 520.0, 152.0)
+    );
+    assert_eq!(document.drop_element(order), None);
+    assert_eq!(document.parent(order), None);
+    assert_eq!(
+        document.get(inner).unwrap().bounds().edges(),
+        (40.0, 40.0, 200.0, 152.0)
+    );
+
+    let import = Relationship::new(inner, outer, RelationshipKind::Import);
+    assert_eq!(import.caption().as_deref(), Some("«import»"));
+}
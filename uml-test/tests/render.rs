@@ -0,0 +1,254 @@
+use std::{cell::RefCell, rc::Rc};
+
+use uml_common::{
+    camera::Camera,
+    canvas::{Canvas, TextCache},
+    document::Document,
+    drawable::Drawable,
+    elements::{
+        Class, Label, Note, Package, Path, Rectangle, Relationship,
+        RelationshipKind, TextProperties,
+    },
+    interaction::Interactive,
+    prompt::PromptResponse,
+    size::Size,
+};
+
+/// A canvas of 800 by 600 that records what is drawn on it.
+#[derive(Default)]
+struct Recorder {
+    rectangles: RefCell<Vec<Rectangle>>,
+    texts: RefCell<Vec<String>>,
+    measured: RefCell<Vec<String>>,
+}
+
+impl Canvas for Recorder {
+    fn draw_rectangle(&self, rect: Rectangle, _: &Camera) {
+        self.rectangles.borrow_mut().push(rect);
+    }
+
+    fn draw_text(&self, label: &Label, _: &Camera) {
+        self.texts.borrow_mut().push(label.text().to_string());
+    }
+
+    fn draw_path(&self, _: &Path, _: &Camera) {}
+
+    fn measure_text(
+        &self,
+        text: &str,
+        _: &TextProperties,
+    ) -> Option<Size<f32>> {
+        self.measured.borrow_mut().push(text.to_string());
+        Some(Size::new(text.len() as f32 * 10.0, 16.0))
+    }
+
+    fn size(&self) -> Size<f64> {
+        Size::new(800.0, 600.0)
+    }
+}
+
+fn class(x: f64, y: f64, name: &str) -> Class {
+    Class::new(x, y, name.into(), None, None, None)
+}
+
+#[test]
+fn only_visible_grid_dots_are_drawn() {
+    let canvas = Recorder::default();
+    let mut camera = Camera::default();
+    camera.translate(-10.0, 20.0);
+    Document::default().draw(&canvas, &camera);
+
+    let rectangles = canvas.rectangles.borrow();
+    let background = &rectangles[0];
+    assert_eq!(
+        (background.x(), background.y(), background.width()),
+        (-10.0, 20.0, 800.0)
+    );
+
+    // Dots sit on multiples of the spacing within (-10, 20) to (790, 620).
+    let dots = rectangles[1..]
+        .iter()
+        .map(|r| (r.x(), r.y()))
+        .collect::<Vec<_>>();
+    assert_eq!(dots.len(), 11 * 8);
+    assert_eq!(dots[0], (0.0, 75.0));
+    assert_eq!(dots[dots.len() - 1], (750.0, 600.0));
+}
+
+#[test]
+fn elements_outside_the_viewport_are_skipped() {
+    let canvas = Recorder::default();
+    let mut document = Document::default();
+    document.add_element(class(100.0, 100.0, "Visible"));
+
+    for i in 0..1000 {
+        document.add_element(class(2000.0 + i as f64 * 300.0, 100.0, "Hidden"));
+    }
+
+    document.draw(&canvas, &Camera::default());
+    assert!(canvas.texts.borrow().contains(&"Visible".to_string()));
+    assert!(!canvas.texts.borrow().contains(&"Hidden".to_string()));
+    assert!(!canvas.measured.borrow().contains(&"Hidden".to_string()));
+
+    // Moving the camera brings the other elements into view, along with
+    // those just outside of it.
+    let canvas = Recorder::default();
+    let mut camera = Camera::default();
+    camera.translate(2000.0, 0.0);
+    document.draw(&canvas, &camera);
+    assert!(!canvas.texts.borrow().contains(&"Visible".to_string()));
+    assert_eq!(
+        canvas
+            .texts
+            .borrow()
+            .iter()
+            .filter(|t| *t == "Hidden")
+            .count(),
+        4
+    );
+}
+
+#[test]
+fn notes_are_drawn_while_their_anchor_lines_are_visible() {
+    let canvas = Recorder::default();
+    let mut document = Document::default();
+    let package =
+        document.add_element(Package::new(100.0, 100.0, "shop".into()));
+    let mut note = Note::new(1400.0, 100.0, "Sells things");
+    note.add_anchor(package);
+    document.add_element(note);
+
+    document.draw(&canvas, &Camera::default());
+    assert!(canvas.texts.borrow().contains(&"Sells things".to_string()));
+}
+
+#[test]
+fn unchanged_elements_are_not_laid_out_again() {
+    let mut document = Document::default();
+    let moved = document.add_element(class(100.0, 100.0, "Moved"));
+    document.add_element(class(400.0, 100.0, "Still"));
+
+    let canvas = Recorder::default();
+    document.draw(&canvas, &Camera::default());
+    assert!(canvas.measured.borrow().contains(&"Still".to_string()));
+
+    let canvas = Recorder::default();
+    document.draw(&canvas, &Camera::default());
+    assert!(canvas.measured.borrow().is_empty());
+    assert!(canvas.texts.borrow().contains(&"Still".to_string()));
+
+    document.move_element(moved, 10.0, 10.0);
+    let canvas = Recorder::default();
+    document.draw(&canvas, &Camera::default());
+    assert!(canvas.measured.borrow().contains(&"Moved".to_string()));
+    assert!(!canvas.measured.borrow().contains(&"Still".to_string()));
+}
+
+#[test]
+fn hovered_and_clicked_elements_are_not_laid_out_again() {
+    let mut document = Document::default();
+    let id = document.add_element(class(100.0, 100.0, "Order"));
+    document.draw(&Recorder::default(), &Camera::default());
+
+    document.hover(id, true);
+    assert!(document.click(id, 110.0, 110.0).is_some());
+    assert!(document.get(id).unwrap().is_hovered());
+    assert!(!document.get(id).unwrap().needs_layout());
+
+    let canvas = Recorder::default();
+    document.draw(&canvas, &Camera::default());
+    assert!(canvas.measured.borrow().is_empty());
+
+    document.hover(id, false);
+    assert!(!document.get(id).unwrap().is_hovered());
+}
+
+#[test]
+fn borrowed_elements_keep_their_layout_until_invalidated() {
+    let mut document = Document::default();
+    let id = document.add_element(class(100.0, 100.0, "Order"));
+    document.draw(&Recorder::default(), &Camera::default());
+
+    let el = document.get_mut(id).unwrap();
+    el.as_interactive_mut()
+        .get_interaction_mut()
+        .set_selected(true);
+    el.as_class_mut().unwrap().set_name("Invoice".into());
+    assert!(!el.needs_layout());
+
+    el.invalidate();
+    let canvas = Recorder::default();
+    document.draw(&canvas, &Camera::default());
+    assert!(canvas.measured.borrow().contains(&"Invoice".to_string()));
+}
+
+#[test]
+fn moved_and_renamed_elements_are_laid_out_again() {
+    let mut document = Document::default();
+    let moved = document.add_element(class(100.0, 100.0, "Moved"));
+    let renamed = document.add_element(class(400.0, 100.0, "Renamed"));
+    document.draw(&Recorder::default(), &Camera::default());
+
+    document.get_mut(moved).unwrap().set_position(150.0, 120.0);
+    document
+        .get_mut(renamed)
+        .unwrap()
+        .prompt(PromptResponse::Text {
+            response: "Customer".into(),
+            metadata: Rc::new(()),
+        });
+    assert!(document.get(moved).unwrap().needs_layout());
+    assert!(document.get(renamed).unwrap().needs_layout());
+
+    let canvas = Recorder::default();
+    document.draw(&canvas, &Camera::default());
+    assert!(canvas.measured.borrow().contains(&"Moved".to_string()));
+    assert!(canvas.measured.borrow().contains(&"Customer".to_string()));
+    assert_eq!(document.get(moved).unwrap().x(), 150.0);
+}
+
+#[test]
+fn elements_changed_in_place_are_attached_again() {
+    let mut document = Document::default();
+    let order = document.add_element(class(100.0, 100.0, "Order"));
+    let item = document.add_element(class(400.0, 100.0, "Item"));
+    let relationship = document.add_element(Relationship::new(
+        order,
+        item,
+        RelationshipKind::Association,
+    ));
+
+    document.draw(&Recorder::default(), &Camera::default());
+    let before = document.get(relationship).unwrap().bounds();
+    document.draw(&Recorder::default(), &Camera::default());
+    assert_eq!(document.get(relationship).unwrap().bounds(), before);
+
+    document.get_mut(item).unwrap().adjust_position(0.0, 300.0);
+    document.draw(&Recorder::default(), &Camera::default());
+    assert!(document.get(relationship).unwrap().bounds().bottom() >= 400.0);
+}
+
+#[test]
+fn text_is_measured_once_per_font() {
+    let cache = TextCache::new();
+    let regular = TextProperties::default();
+    let measured = RefCell::new(0);
+    let measure = || {
+        *measured.borrow_mut() += 1;
+        Some(Size::new(40.0, 16.0))
+    };
+
+    cache.get_or_measure("Order", &regular, measure);
+    let size = cache.get_or_measure("Order", &regular, measure).unwrap();
+    assert_eq!((size.width(), size.height()), (40.0, 16.0));
+    assert_eq!(*measured.borrow(), 1);
+
+    cache.get_or_measure("Customer", &regular, measure);
+    cache.get_or_measure("Order", &regular.clone().weight(700), measure);
+    assert_eq!(*measured.borrow(), 3);
+    assert_eq!(cache.len(), 3);
+
+    // Failed measurements are tried again.
+    assert!(cache.get_or_measure("Item", &regular, || None).is_none());
+    assert_eq!(cache.len(), 3);
+}
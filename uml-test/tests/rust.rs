@@ -0,0 +1,212 @@
+use uml_common::{
+    elements::RelationshipKind,
+    format::{
+        mermaid,
+        rust::{export, import},
+    },
+};
+
+const SOURCE: &str = r#"
+pub trait Shape {
+    fn area(&self) -> f64;
+}
+
+pub struct Point {
+    pub x: f64,
+    pub y: f64,
+}
+
+pub struct Polygon {
+    points: Vec<Point>,
+    pub(crate) label: Option<String>,
+}
+
+impl Polygon {
+    pub fn new(points: Vec<Point>) -> Self {
+        Self { points, label: None }
+    }
+}
+
+impl Shape for Polygon {
+    fn area(&self) -> f64 {
+        0.0
+    }
+}
+
+enum Kind {
+    Open,
+    Closed(Polygon),
+}
+"#;
+
+#[test]
+fn reverse_engineer_types() {
+    let document = import(SOURCE).expect("source should parse");
+    let classes = document
+        .elements()
+        .iter()
+        .filter_map(|el| el.as_class())
+        .collect::<Vec<_>>();
+
+    let names = classes.iter().map(|c| c.name()).collect::<Vec<_>>();
+    assert_eq!(names, ["Shape", "Point", "Polygon", "Kind"]);
+
+    assert_eq!(classes[0].stereotype(), Some("interface"));
+    assert_eq!(
+        classes[0].operations().collect::<Vec<_>>(),
+        ["{abstract} +area(): f64"]
+    );
+    assert_eq!(
+        classes[2].attributes().collect::<Vec<_>>(),
+        ["-points: Vec<Point>", "~label: Option<String>"]
+    );
+    assert_eq!(
+        classes[2].operations().collect::<Vec<_>>(),
+        ["{static} +new(points: Vec<Point>): Self"]
+    );
+    assert_eq!(classes[3].stereotype(), Some("enumeration"));
+    assert_eq!(
+        classes[3].attributes().collect::<Vec<_>>(),
+        ["Open", "Closed: (Polygon)"]
+    );
+}
+
+#[test]
+fn reverse_engineer_relationships() {
+    let document = import(SOURCE).expect("source should parse");
+    let relationships = document
+        .elements()
+        .iter()
+        .filter_map(|el| el.as_relationship())
+        .map(|r| (r.kind(), r.label(), r.target_multiplicity()))
+        .collect::<Vec<_>>();
+
+    assert_eq!(
+        relationships,
+        [
+            (RelationshipKind::Realization, None, None),
+            (RelationshipKind::Association, Some("points"), Some("*")),
+            (RelationshipKind::Association, Some("Closed"), None),
+        ]
+    );
+}
+
+#[test]
+fn generate_skeleton_code() {
+    let document = mermaid::import(
+        r#"classDiagram
+    class Shape {
+        <<interface>>
+        +area() double*
+    }
+    class Shopping_cart["Shopping cart"] {
+        -List~Item~ items
+        +add(Item item)
+        +create()$ Shopping_cart
+    }
+    class Color {
+        <<enumeration>>
+        Red
+        Green
+    }
+    Shopping_cart ..|> Shape
+"#,
+    )
+    .expect("diagram should parse");
+
+    assert_eq!(
+        export(&document),
+        r#"pub trait Shape {
+    fn area(&self) -> f64;
+}
+
+pub struct ShoppingCart {
+    items: Vec<Item>,
+}
+
+impl ShoppingCart {
+    pub fn add(&self, item: Item) {
+        todo!()
+    }
+
+    pub fn create() -> ShoppingCart {
+        todo!()
+    }
+}
+
+impl Shape for ShoppingCart {
+    fn area(&self) -> f64 {
+        todo!()
+    }
+}
+
+pub enum Color {
+    Red,
+    Green,
+}
+"#
+    );
+}
+
+#[test]
+fn exported_code_keeps_references_and_keywords() {
+    let document = import(
+        r#"
+pub trait Named {
+    fn name<'a>(&'a self, prefix: &'a str) -> &'a str;
+}
+
+pub struct Token<'a> {
+    pub r#type: &'static str,
+    text: &'a str,
+    owned: String,
+}
+
+impl<'a> Token<'a> {
+    pub fn new(text: &'a str, r#in: &str) -> Self {
+        todo!()
+    }
+}
+
+impl Named for Token<'_> {
+    fn name<'a>(&'a self, prefix: &'a str) -> &'a str {
+        todo!()
+    }
+}
+"#,
+    )
+    .expect("source should parse");
+
+    assert_eq!(
+        export(&document),
+        r#"pub trait Named {
+    fn name<'a>(&self, prefix: &'a str) -> &'a str;
+}
+
+pub struct Token<'a> {
+    pub r#type: &'static str,
+    text: &'a str,
+    owned: String,
+}
+
+impl<'a> Token<'a> {
+    pub fn new(text: &'a str, r#in: &str) -> Self {
+        todo!()
+    }
+}
+
+impl Named for Token<'_> {
+    fn name<'a>(&self, prefix: &'a str) -> &'a str {
+        todo!()
+    }
+}
+"#
+    );
+
+    // A bare `str`, as written in other languages, is an owned string.
+    let document = mermaid::import(
+        "classDiagram\n    class Tag {\n        -str label\n    }",
+    )
+    .expect("diagram should parse");
+    assert!(export(&document).contains("label: String,"));
+}
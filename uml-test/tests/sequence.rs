@@ -0,0 +1,186 @@
+use uml_common::{
+    document::Document,
+    drawable::Drawable,
+    elements::{
+        Activation, Fragment, FragmentOperator, Lifeline, Message, MessageKind,
+    },
+    interaction::Interactive,
+    prompt::PromptResponse,
+};
+
+#[test]
+fn messages_keep_their_order_when_lifelines_move() {
+    let mut document = Document::default();
+    let client = document.add_element(Lifeline::new(0.0, 0.0, "client".into()));
+    let shop = document.add_element(Lifeline::new(200.0, 0.0, "shop".into()));
+    document.add_element(Message::new(
+        client,
+        shop,
+        MessageKind::Synchronous,
+        100.0,
+    ));
+    document.add_element(Message::new(
+        shop,
+        client,
+        MessageKind::Return,
+        150.0,
+    ));
+    document.attach_messages();
+
+    let points = |document: &Document| {
+        document
+            .elements()
+            .iter()
+            .filter_map(|el| el.as_message())
+            .map(|m| m.points().to_vec())
+            .collect::<Vec<_>>()
+    };
+
+    assert_eq!(
+        points(&document),
+        [
+            vec![(50.0, 100.0), (250.0, 100.0)],
+            vec![(250.0, 150.0), (50.0, 150.0)]
+        ]
+    );
+
+    // Drag the shop to the left of the client.
+    let shop = document.elements()[1].id();
+    document
+        .get_mut(shop)
+        .unwrap()
+        .adjust_position(-300.0, 40.0);
+    document.attach_messages();
+
+    assert_eq!(
+        points(&document),
+        [
+            vec![(50.0, 100.0), (-50.0, 100.0)],
+            vec![(-50.0, 150.0), (50.0, 150.0)]
+        ]
+    );
+}
+
+#[test]
+fn messages_meet_activations() {
+    let mut document = Document::default();
+    let a = document.add_element(Lifeline::new(0.0, 0.0, "a".into()));
+    let b = document.add_element(Lifeline::new(200.0, 0.0, "b".into()));
+    document.add_element(Activation::new(b, 100.0, 200.0));
+    document.add_element(Activation::new(b, 120.0, 160.0));
+    document.add_element(Message::new(a, b, MessageKind::Synchronous, 100.0));
+    document.add_element(Message::new(b, b, MessageKind::Synchronous, 120.0));
+    document.add_element(Message::new(b, a, MessageKind::Return, 200.0));
+    document.attach_messages();
+
+    let elements = document.elements();
+    let bars = [&elements[2], &elements[3]]
+        .map(|el| el.as_activation().expect("activation").bounds().edges());
+    assert_eq!(
+        bars,
+        [(244.0, 100.0, 256.0, 200.0), (250.0, 120.0, 262.0, 160.0)]
+    );
+
+    let messages = elements[4..]
+        .iter()
+        .map(|el| el.as_message().expect("message").points().to_vec())
+        .collect::<Vec<_>>();
+    assert_eq!(messages[0], [(50.0, 100.0), (244.0, 100.0)]);
+    assert_eq!(messages[1][0], (262.0, 120.0));
+    assert_eq!(messages[2], [(244.0, 200.0), (50.0, 200.0)]);
+}
+
+#[test]
+fn create_and_destroy_messages() {
+    let mut document = Document::default();
+    let a = document.add_element(Lifeline::new(0.0, 0.0, "a".into()));
+    let b = document.add_element(Lifeline::new(200.0, 0.0, "b".into()));
+    document.add_element(Message::new(a, b, MessageKind::Create, 100.0));
+    document.add_element(Message::new(a, b, MessageKind::Destroy, 250.0));
+    document.attach_messages();
+
+    let b = document.elements()[1].as_lifeline().expect("lifeline");
+    let (left, top, _, bottom) = b.header_bounds().edges();
+    assert_eq!((top + bottom) / 2.0, 100.0);
+    assert!(b.is_destroyed());
+    assert_eq!(b.bottom(), 250.0);
+
+    let create = document.elements()[2].as_message().expect("message");
+    assert_eq!(create.points(), [(50.0, 100.0), (left, 100.0)]);
+
+    // The lifeline that is not destroyed reaches past the last message.
+    let a = document.elements()[0].as_lifeline().expect("lifeline");
+    assert!(a.bottom() > 250.0);
+}
+
+#[test]
+fn fragments_follow_their_lifelines() {
+    let mut document = Document::default();
+    let a = document.add_element(Lifeline::new(0.0, 0.0, "a".into()));
+    let b = document.add_element(Lifeline::new(200.0, 0.0, "b".into()));
+    document.add_element(Lifeline::new(400.0, 0.0, "c".into()));
+
+    let mut alt = Fragment::new(FragmentOperator::Alt, vec![a, b], 80.0, 400.0);
+    alt.set_guard(0, Some("in stock".into()));
+    alt.add_operand(240.0, Some("else".into()));
+    document.add_element(alt);
+    document.add_element(Fragment::new(
+        FragmentOperator::Loop,
+        vec![b],
+        100.0,
+        200.0,
+    ));
+    document.attach_messages();
+
+    let bounds = |document: &Document| {
+        document.elements()[3..]
+            .iter()
+            .map(|el| el.bounds().edges())
+            .collect::<Vec<_>>()
+    };
+
+    // The loop is nested in the alternative, so it is inset a little.
+    assert_eq!(
+        bounds(&document),
+        [(-20.0, 80.0, 320.0, 400.0), (188.0, 100.0, 312.0, 200.0)]
+    );
+
+    // The lifelines below the fragment are stretched to cover it.
+    let c_bottom = document.elements()[2].bounds().bottom();
+    assert!(document.elements()[0].bounds().bottom() > 400.0);
+    assert!(c_bottom < 400.0);
+
+    // Moving a lifeline resizes the fragments that cover it.
+    let (a, b) = (document.elements()[0].id(), document.elements()[1].id());
+    document.get_mut(a).unwrap().adjust_position(-100.0, 0.0);
+    document.get_mut(b).unwrap().adjust_position(300.0, 0.0);
+    document.attach_messages();
+
+    assert_eq!(
+        bounds(&document),
+        [(-120.0, 80.0, 620.0, 400.0), (488.0, 100.0, 612.0, 200.0)]
+    );
+
+    let alt = document.elements()[3].as_fragment().expect("fragment");
+    let guards = alt
+        .operands()
+        .iter()
+        .map(|o| (o.y(), o.guard()))
+        .collect::<Vec<_>>();
+    assert_eq!(guards, [(80.0, Some("in stock")), (240.0, Some("else"))]);
+}
+
+#[test]
+fn clicking_an_operand_edits_its_guard() {
+    let mut fragment = Fragment::new(FragmentOperator::Alt, vec![], 0.0, 200.0);
+    fragment.add_operand(100.0, None);
+
+    let prompt = fragment.click(10.0, 150.0).expect("operands have guards");
+    fragment.prompt(PromptResponse::Text {
+        response: "x > 0".into(),
+        metadata: prompt.metadata(),
+    });
+
+    assert_eq!(fragment.operands()[0].guard(), None);
+    assert_eq!(fragment.operands()[1].guard(), Some("x > 0"));
+}
@@ -0,0 +1,239 @@
+use uml_common::{
+    camera::Camera,
+    canvas::Canvas,
+    color::BLACK,
+    document::Document,
+    drawable::Drawable,
+    elements::{
+        Class, Label, Package, Path, Rectangle, Relationship, RelationshipKind,
+        TextProperties,
+    },
+    geometry::Rect,
+    interaction::Interactive,
+    size::Size,
+    spatial::SpatialIndex,
+};
+
+/// A canvas of 800 by 600 that measures every character as 10 by 16.
+struct Blank;
+
+impl Canvas for Blank {
+    fn draw_rectangle(&self, _: Rectangle, _: &Camera) {}
+
+    fn draw_text(&self, _: &Label, _: &Camera) {}
+
+    fn draw_path(&self, _: &Path, _: &Camera) {}
+
+    fn measure_text(
+        &self,
+        text: &str,
+        _: &TextProperties,
+    ) -> Option<Size<f32>> {
+        Some(Size::new(text.len() as f32 * 10.0, 16.0))
+    }
+
+    fn size(&self) -> Size<f64> {
+        Size::new(800.0, 600.0)
+    }
+}
+
+fn class(x: f64, y: f64, name: &str) -> Class {
+    Class::new(x, y, name.into(), None, None, None)
+}
+
+#[test]
+fn index_finds_keys_by_point_and_area() {
+    let mut index = SpatialIndex::new(100.0);
+    index.insert(1, Rect::from_edges(0.0, 0.0, 50.0, 50.0));
+    index.insert(2, Rect::from_edges(40.0, 40.0, 450.0, 60.0));
+    index.insert(3, Rect::from_edges(-500.0, -500.0, -400.0, -400.0));
+
+    let mut at = index.at(45.0, 45.0);
+    at.sort();
+    assert_eq!(at, vec![1, 2]);
+    assert_eq!(index.at(300.0, 50.0), vec![2]);
+    assert!(index.at(300.0, 300.0).is_empty());
+
+    let mut all =
+        index.query(Rect::from_edges(-10_000.0, -10_000.0, 10_000.0, 10_000.0));
+    all.sort();
+    assert_eq!(all, vec![1, 2, 3]);
+
+    // Moving a key leaves nothing behind in the cells it was in.
+    index.insert(2, Rect::from_edges(1000.0, 1000.0, 1010.0, 1010.0));
+    assert_eq!(index.at(300.0, 50.0), Vec::<i32>::new());
+    assert_eq!(index.at(1005.0, 1005.0), vec![2]);
+
+    index.remove(2);
+    assert!(!index.contains(2));
+    assert_eq!(index.len(), 2);
+}
+
+#[test]
+fn huge_bounds_are_indexed_without_covering_every_cell() {
+    let mut index = SpatialIndex::new(100.0);
+    index.insert(1, Rect::from_edges(0.0, 0.0, 50.0, 50.0));
+    index.insert(2, Rect::from_edges(-1e300, -1e300, 1e300, 1e300));
+    index.insert(3, Rect::from_edges(0.0, 0.0, 1e6, 10.0));
+
+    let mut at = index.at(20.0, 5.0);
+    at.sort();
+    assert_eq!(at, vec![1, 2, 3]);
+    assert_eq!(index.at(5e5, 5.0).len(), 2);
+
+    let mut all =
+        index.query(Rect::from_edges(f64::MIN, f64::MIN, f64::MAX, f64::MAX));
+    all.sort();
+    assert_eq!(all, vec![1, 2, 3]);
+
+    index.remove(2);
+    assert_eq!(index.at(-5e5, -5e5), Vec::<i32>::new());
+}
+
+#[test]
+fn topmost_element_under_the_cursor_is_hit() {
+    let mut document = Document::default();
+    let below = document.add_element(class(100.0, 100.0, "Below"));
+    let above = document.add_element(class(120.0, 110.0, "Above"));
+    document.draw(&Blank, &Camera::default());
+
+    assert_eq!(document.element_at(125.0, 115.0), Some(above));
+    assert_eq!(document.element_at(105.0, 105.0), Some(below));
+    assert_eq!(document.element_at(700.0, 500.0), None);
+
+    // Moved elements are found where they are now.
+    document.move_element(below, 400.0, 300.0);
+    assert_eq!(document.element_at(105.0, 105.0), None);
+    assert_eq!(document.element_at(505.0, 405.0), Some(below));
+}
+
+#[test]
+fn elements_are_hit_in_the_order_they_are_drawn() {
+    let mut document = Document::default();
+    let class = document.add_element(class(100.0, 100.0, "Order"));
+    let package = document.add_element(Package::new(80.0, 60.0, "shop".into()));
+    document.set_parent(class, Some(package));
+    document.draw(&Blank, &Camera::default());
+
+    // The package comes later in the document, but is drawn below the
+    // classes in it.
+    assert_eq!(document.element_at(105.0, 105.0), Some(class));
+    assert_eq!(document.element_at(85.0, 65.0), Some(package));
+}
+
+#[test]
+fn hovered_elements_are_left_with_the_cursor() {
+    let mut document = Document::default();
+    let first = document.add_element(class(100.0, 100.0, "First"));
+    let second = document.add_element(class(400.0, 100.0, "Second"));
+    document.draw(&Blank, &Camera::default());
+    let hovered = |document: &Document, id| {
+        document.get(id).is_some_and(|el| el.is_hovered())
+    };
+
+    document.update_cursor(105.0, 105.0, true);
+    assert!(hovered(&document, first));
+
+    document.update_cursor(405.0, 105.0, true);
+    assert!(!hovered(&document, first));
+    assert!(hovered(&document, second));
+
+    document.update_cursor(405.0, 105.0, false);
+    assert!(!hovered(&document, second));
+}
+
+#[test]
+fn selecting_elements_deselects_the_others() {
+    let mut document = Document::default();
+    let first = document.add_element(class(100.0, 100.0, "First"));
+    let second = document.add_element(class(400.0, 100.0, "Second"));
+    let selected = |document: &Document, id| {
+        document.get(id).is_some_and(|el| el.is_selected())
+    };
+
+    document.select([first]);
+    assert!(selected(&document, first));
+
+    document.select([second]);
+    assert!(!selected(&document, first));
+    assert!(selected(&document, second));
+    assert_eq!(document.selection().len(), 1);
+
+    document.select([]);
+    assert!(document.selection().is_empty());
+    assert!(!selected(&document, second));
+}
+
+#[test]
+fn labels_are_hit_at_their_drawn_text() {
+    let mut document = Document::default();
+    let label = document.add_element(Label::new(
+        100.0,
+        100.0,
+        "Title",
+        TextProperties::default(),
+        BLACK,
+    ));
+    document.draw(&Blank, &Camera::default());
+
+    // The text runs from the top at y = 100 down to its baseline at 116.
+    assert_eq!(document.element_at(120.0, 108.0), Some(label));
+    assert_eq!(document.element_at(120.0, 90.0), None);
+}
+
+#[test]
+fn relationships_are_hit_near_their_line() {
+    let mut document = Document::default();
+    let source = document.add_element(class(0.0, 0.0, "A"));
+    let target = document.add_element(class(400.0, 0.0, "B"));
+    let link = document.add_element(Relationship::new(
+        source,
+        target,
+        RelationshipKind::Association,
+    ));
+    document.draw(&Blank, &Camera::default());
+
+    let (l, t, r, _) = document
+        .elements()
+        .iter()
+        .find(|el| el.id() == link)
+        .unwrap()
+        .bounds()
+        .edges();
+    let x = (l + r) / 2.0;
+
+    assert_eq!(document.element_at(x, t - 4.0), Some(link));
+    assert_eq!(document.element_at(x, t - 40.0), None);
+}
+
+#[test]
+fn marquee_picks_elements_entirely_within_it() {
+    let mut document = Document::default();
+    let inside = document.add_element(class(100.0, 100.0, "Inside"));
+    document.add_element(class(280.0, 100.0, "Across"));
+    document.add_element(class(2000.0, 2000.0, "Away"));
+    document.draw(&Blank, &Camera::default());
+
+    assert_eq!(
+        document.elements_within(Rect::from_edges(50.0, 50.0, 300.0, 300.0)),
+        vec![inside]
+    );
+}
+
+#[test]
+fn removing_an_element_keeps_the_others_indexed() {
+    let mut document = Document::default();
+    let first = document.add_element(class(100.0, 100.0, "First"));
+    let second = document.add_element(class(400.0, 100.0, "Second"));
+    let later = document.add_element(class(700.0, 100.0, "Later"));
+    document.draw(&Blank, &Camera::default());
+
+    document.remove(second);
+    assert_eq!(document.element_at(105.0, 105.0), Some(first));
+    assert_eq!(document.element_at(405.0, 105.0), None);
+    assert_eq!(document.element_at(705.0, 105.0), Some(later));
+
+    // Not laid out yet, a new element is no more than its corner.
+    let added = document.add_element(class(1000.0, 100.0, "Added"));
+    assert_eq!(document.element_at(1000.0, 100.0), Some(added));
+}
@@ -0,0 +1,106 @@
+use uml_common::{elements::RelationshipKind, format::sql::import};
+
+const MIGRATION: &str = r#"
+-- Authors and their books
+CREATE TABLE IF NOT EXISTS public.authors (
+    id serial PRIMARY KEY,
+    name varchar(255) NOT NULL,
+    "email" text UNIQUE
+);
+
+/* Books belong to an author and
+   optionally to a series */
+CREATE TABLE books (
+    id bigint GENERATED ALWAYS AS IDENTITY,
+    author_id integer NOT NULL REFERENCES authors (id) ON DELETE CASCADE,
+    series_id integer,
+    price numeric(10, 2) DEFAULT round(0.0, 2),
+    tags text[],
+    published timestamp with time zone,
+    CONSTRAINT books_pkey PRIMARY KEY (id)
+);
+
+CREATE TABLE series (id integer PRIMARY KEY, title text NOT NULL);
+
+ALTER TABLE ONLY books
+    ADD CONSTRAINT books_series_fkey FOREIGN KEY (series_id) REFERENCES series(id);
+
+CREATE FUNCTION touch() RETURNS trigger AS $$
+BEGIN
+    -- don't split on this; or that
+    RETURN NEW;
+END;
+$$ LANGUAGE plpgsql;
+"#;
+
+#[test]
+fn import_tables_and_foreign_keys() {
+    let document = import(MIGRATION).expect("migration should parse");
+    let classes = document
+        .elements()
+        .iter()
+        .filter_map(|el| el.as_class())
+        .collect::<Vec<_>>();
+
+    let names = classes.iter().map(|c| c.name()).collect::<Vec<_>>();
+    assert_eq!(names, ["authors", "books", "series"]);
+
+    assert_eq!(
+        classes[0].attributes().collect::<Vec<_>>(),
+        [
+            "«PK» id: serial",
+            "name: varchar(255)",
+            "email: text [0..1]"
+        ]
+    );
+    assert_eq!(
+        classes[1].attributes().collect::<Vec<_>>(),
+        [
+            "«PK» id: bigint",
+            "«FK» author_id: integer",
+            "«FK» series_id: integer [0..1]",
+            "price: numeric(10, 2) [0..1]",
+            "tags: text[] [0..1]",
+            "published: timestamp with time zone [0..1]"
+        ]
+    );
+
+    let relationships = document
+        .elements()
+        .iter()
+        .filter_map(|el| el.as_relationship())
+        .collect::<Vec<_>>();
+    let ids = document
+        .elements()
+        .iter()
+        .map(|el| el.id())
+        .collect::<Vec<_>>();
+
+    assert_eq!(relationships.len(), 2);
+    assert!(
+        relationships
+            .iter()
+            .all(|r| r.kind() == RelationshipKind::Association)
+    );
+    assert_eq!(
+        (relationships[0].source(), relationships[0].target()),
+        (ids[1], ids[0])
+    );
+    assert_eq!(relationships[0].label(), Some("author_id"));
+    assert_eq!(relationships[0].source_multiplicity(), Some("*"));
+    assert_eq!(relationships[0].target_multiplicity(), Some("1"));
+    assert_eq!(relationships[1].target(), ids[2]);
+    assert_eq!(relationships[1].target_multiplicity(), Some("0..1"));
+
+    // Referenced tables are laid out above the tables referencing them.
+    assert!(document.elements()[0].y() < document.elements()[1].y());
+}
+
+#[test]
+fn report_the_line_of_errors() {
+    let error =
+        import("CREATE TABLE a (\n    id integer,\n    b (\n").unwrap_err();
+    assert_eq!(error.line(), Some(3));
+
+    assert!(import("SELECT 1;").is_err());
+}
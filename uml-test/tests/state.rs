@@ -0,0 +1,109 @@
+use uml_common::{
+    document::Document,
+    drawable::Drawable,
+    elements::{
+        Pseudostate, PseudostateKind, Relationship, RelationshipKind, State,
+    },
+};
+
+#[test]
+fn composite_states_enclose_and_move_their_substates() {
+    let mut document = Document::default();
+    let mut outer = State::new(0.0, 0.0, "Active".into());
+    let mut middle = State::new(40.0, 80.0, "Running".into());
+    let leaf = document.add_element(State::new(100.0, 160.0, "Busy".into()));
+    middle.add_substate(leaf);
+    let middle = document.add_element(middle);
+    outer.add_substate(middle);
+    let outer = document.add_element(outer);
+    document.attach_containers();
+
+    assert_eq!(
+        document.get(leaf).unwrap().bounds().edges(),
+        (100.0, 160.0, 200.0, 210.0)
+    );
+    assert_eq!(
+        document.get(middle).unwrap().bounds().edges(),
+        (40.0, 80.0, 220.0, 230.0)
+    );
+    assert_eq!(
+        document.get(outer).unwrap().bounds().edges(),
+        (0.0, 0.0, 240.0, 250.0)
+    );
+
+    document.move_element(outer, 10.0, 20.0);
+    document.attach_containers();
+
+    assert_eq!(
+        document.get(leaf).unwrap().bounds().edges(),
+        (110.0, 180.0, 210.0, 230.0)
+    );
+    assert_eq!(
+        document.get(middle).unwrap().bounds().edges(),
+        (50.0, 100.0, 230.0, 250.0)
+    );
+    assert_eq!(
+        document.get(outer).unwrap().bounds().edges(),
+        (10.0, 20.0, 250.0, 270.0)
+    );
+
+    // Only the border and the top of a composite state can be grabbed.
+    let outer = document.elements().iter().find(|el| el.id() == outer);
+    let outer = outer.unwrap();
+    assert!(outer.cursor_intersects(30.0, 30.0));
+    assert!(outer.cursor_intersects(250.0, 150.0));
+    assert!(!outer.cursor_intersects(30.0, 150.0));
+}
+
+#[test]
+fn states_keep_their_behaviors() {
+    let mut state = State::new(0.0, 0.0, "Heating".into());
+    state.set_entry(Some("turn on".into()));
+    state.set_exit(Some("turn off".into()));
+
+    assert_eq!(state.entry(), Some("turn on"));
+    assert_eq!(state.do_activity(), None);
+    assert_eq!(state.exit(), Some("turn off"));
+    assert_eq!(state.bounds().edges(), (0.0, 0.0, 100.0, 52.0));
+
+    state.set_entry(None);
+    assert_eq!(state.entry(), None);
+}
+
+#[test]
+fn transitions_are_labelled_and_end_on_pseudostates() {
+    let mut document = Document::default();
+    let initial = document.add_element(Pseudostate::new(
+        40.0,
+        -100.0,
+        PseudostateKind::Initial,
+    ));
+    let idle = document.add_element(State::new(0.0, 0.0, "Idle".into()));
+    let choice = document.add_element(Pseudostate::new(
+        200.0,
+        10.0,
+        PseudostateKind::Choice,
+    ));
+    document.add_element(Relationship::new(
+        initial,
+        idle,
+        RelationshipKind::Transition,
+    ));
+    let mut transition =
+        Relationship::new(idle, choice, RelationshipKind::Transition);
+    transition.set_trigger(Some("coin"), Some("credit > 0"), Some("count()"));
+    assert_eq!(transition.label(), Some("coin [credit > 0] / count()"));
+    transition.set_trigger(None, Some("ready"), None);
+    assert_eq!(transition.label(), Some("[ready]"));
+    document.add_element(transition);
+    document.attach_relationships();
+
+    let transitions = document
+        .elements()
+        .iter()
+        .filter_map(|el| el.as_relationship())
+        .collect::<Vec<_>>();
+
+    assert_eq!(transitions[0].points(), [(50.0, -80.0), (50.0, 0.0)]);
+    assert_eq!(transitions[1].points(), [(100.0, 25.0), (200.0, 25.0)]);
+}
@@ -0,0 +1,77 @@
+use uml_common::{
+    document::Document,
+    drawable::Drawable,
+    elements::{
+        Actor, Relationship, RelationshipKind, SystemBoundary, UseCase,
+    },
+};
+
+#[test]
+fn use_cases_are_hit_within_their_ellipse() {
+    let mut document = Document::default();
+    document.add_element(UseCase::new(0.0, 0.0, "Check out".into()));
+    let use_case = &document.elements()[0];
+
+    assert_eq!(use_case.bounds().edges(), (0.0, 0.0, 120.0, 50.0));
+    assert!(use_case.cursor_intersects(60.0, 25.0));
+    assert!(use_case.cursor_intersects(2.0, 25.0));
+    assert!(use_case.cursor_intersects(60.0, 1.0));
+    assert!(!use_case.cursor_intersects(5.0, 5.0));
+    assert!(!use_case.cursor_intersects(115.0, 45.0));
+}
+
+#[test]
+fn include_and_extend_end_on_the_ellipse() {
+    let mut document = Document::default();
+    let customer =
+        document.add_element(Actor::new(-200.0, 0.0, "Customer".into()));
+    let check_out =
+        document.add_element(UseCase::new(0.0, 0.0, "Check out".into()));
+    let pay = document.add_element(UseCase::new(300.0, 0.0, "Pay".into()));
+    let coupon =
+        document.add_element(UseCase::new(0.0, 200.0, "Coupon".into()));
+    document.add_element(Relationship::new(
+        customer,
+        check_out,
+        RelationshipKind::Link,
+    ));
+    document.add_element(Relationship::new(
+        check_out,
+        pay,
+        RelationshipKind::Include,
+    ));
+    let mut extend =
+        Relationship::new(coupon, check_out, RelationshipKind::Extend);
+    extend.set_label(Some("[has coupon]".into()));
+    document.add_element(extend);
+    document.add_element(SystemBoundary::new(
+        -40.0,
+        -40.0,
+        500.0,
+        340.0,
+        "Shop".into(),
+    ));
+    document.attach_relationships();
+
+    let relationships = document
+        .elements()
+        .iter()
+        .filter_map(|el| el.as_relationship())
+        .collect::<Vec<_>>();
+
+    // Horizontal and vertical lines touch the ellipses at their extremes.
+    assert_eq!(relationships[1].points(), [(120.0, 25.0), (300.0, 25.0)]);
+    assert_eq!(relationships[2].points(), [(60.0, 200.0), (60.0, 50.0)]);
+
+    assert_eq!(relationships[1].caption().as_deref(), Some("«include»"));
+    assert_eq!(
+        relationships[2].caption().as_deref(),
+        Some("«extend» [has coupon]")
+    );
+    assert!(relationships[2].kind().is_dashed());
+
+    // Only the border of the system boundary can be grabbed.
+    let boundary = &document.elements()[7];
+    assert!(boundary.cursor_intersects(-40.0, 100.0));
+    assert!(!boundary.cursor_intersects(200.0, 150.0));
+}
@@ -0,0 +1,154 @@
+use uml_common::{
+    elements::RelationshipKind,
+    format::{mermaid, xmi},
+};
+
+const MODEL: &str = r#"<?xml version="1.0" encoding="UTF-8"?>
+<xmi:XMI xmi:version="2.1" xmlns:uml="http://schema.omg.org/spec/UML/2.1" xmlns:xmi="http://schema.omg.org/spec/XMI/2.1">
+  <uml:Model xmi:type="uml:Model" name="EA_Model">
+    <packagedElement xmi:type="uml:Package" xmi:id="pkg" name="Zoo">
+      <packagedElement xmi:type="uml:Class" xmi:id="animal" name="Animal" isAbstract="true">
+        <ownedAttribute xmi:id="a1" name="name" visibility="private">
+          <type xmi:idref="EAJava_String"/>
+        </ownedAttribute>
+        <ownedOperation xmi:id="o1" name="feed" visibility="public">
+          <ownedParameter xmi:id="p1" name="amount" direction="in" type="EAJava_int"/>
+          <ownedParameter xmi:id="p2" direction="return" type="EAJava_boolean"/>
+        </ownedOperation>
+      </packagedElement>
+      <packagedElement xmi:type="uml:Class" xmi:id="duck" name="Duck">
+        <generalization xmi:type="uml:Generalization" xmi:id="g1" general="animal"/>
+      </packagedElement>
+      <packagedElement xmi:type="uml:Class" xmi:id="leg" name="Leg"/>
+      <packagedElement xmi:type="uml:Association" xmi:id="as1" name="has" memberEnd="e1 e2">
+        <ownedEnd xmi:id="e1" type="leg" association="as1" aggregation="composite">
+          <lowerValue xmi:type="uml:LiteralInteger" xmi:id="l1" value="0"/>
+          <upperValue xmi:type="uml:LiteralUnlimitedNatural" xmi:id="u1" value="-1"/>
+        </ownedEnd>
+        <ownedEnd xmi:id="e2" type="animal" association="as1">
+          <lowerValue xmi:type="uml:LiteralInteger" xmi:id="l2" value="1"/>
+          <upperValue xmi:type="uml:LiteralUnlimitedNatural" xmi:id="u2" value="1"/>
+        </ownedEnd>
+      </packagedElement>
+    </packagedElement>
+  </uml:Model>
+  <xmi:Extension extender="Enterprise Architect">
+    <diagrams>
+      <diagram xmi:id="d1">
+        <elements>
+          <element subject="animal" geometry="Left=300;Top=40;Right=420;Bottom=120;"/>
+          <element subject="duck" geometry="Left=300;Top=240;Right=420;Bottom=300;"/>
+          <element subject="leg" geometry="Left=40;Top=40;Right=120;Bottom=100;"/>
+        </elements>
+      </diagram>
+    </diagrams>
+  </xmi:Extension>
+</xmi:XMI>
+"#;
+
+/// The value of an attribute on the first line of `xml` containing `needle`.
+fn attribute<'a>(xml: &'a str, needle: &str, name: &str) -> &'a str {
+    let line = xml.lines().find(|line| line.contains(needle)).unwrap();
+    let value = line.split(&format!(" {name}=\"")).nth(1).unwrap();
+    value.split('"').next().unwrap()
+}
+
+#[test]
+fn import_enterprise_architect_model() {
+    let document = xmi::import(MODEL).expect("model should parse");
+    let classes = document
+        .elements()
+        .iter()
+        .filter(|el| el.as_class().is_some())
+        .collect::<Vec<_>>();
+
+    let names = classes
+        .iter()
+        .filter_map(|el| el.as_class())
+        .map(|c| c.name())
+        .collect::<Vec<_>>();
+    assert_eq!(names, ["Animal", "Duck", "Leg"]);
+
+    let animal = classes[0].as_class().unwrap();
+    assert_eq!(animal.stereotype(), Some("abstract"));
+    assert_eq!(animal.attributes().collect::<Vec<_>>(), ["-name: String"]);
+    assert_eq!(
+        animal.operations().collect::<Vec<_>>(),
+        ["+feed(amount: int): boolean"]
+    );
+    assert_eq!((classes[0].x(), classes[0].y()), (300.0, 40.0));
+    assert_eq!((classes[2].x(), classes[2].y()), (40.0, 40.0));
+
+    let relationships = document
+        .elements()
+        .iter()
+        .filter_map(|el| el.as_relationship())
+        .collect::<Vec<_>>();
+
+    assert_eq!(relationships.len(), 2);
+    assert_eq!(relationships[0].kind(), RelationshipKind::Generalization);
+    assert_eq!(relationships[1].kind(), RelationshipKind::Composition);
+    assert_eq!(relationships[1].source(), classes[2].id());
+    assert_eq!(relationships[1].source_multiplicity(), Some("0..*"));
+    assert_eq!(relationships[1].target_multiplicity(), Some("1"));
+    assert_eq!(relationships[1].label(), Some("has"));
+}
+
+#[test]
+fn export_round_trips() {
+    let document = mermaid::import(
+        r#"classDiagram
+    class Shape {
+        <<interface>>
+        +area() double*
+    }
+    class Circle {
+        -double radius
+        +Circle(double radius)
+    }
+    class Color {
+        <<enumeration>>
+        RED
+        GREEN
+    }
+    Shape <|.. Circle
+    Circle --> "0..1" Color : fill
+"#,
+    )
+    .expect("diagram should parse");
+
+    let exported = xmi::export(&document);
+    let reimported = xmi::import(&exported).expect("export should parse");
+
+    let shape = attribute(&exported, r#"name="Shape""#, "xmi:id");
+    let circle = attribute(&exported, r#"name="Circle""#, "xmi:id");
+    assert_eq!(
+        attribute(&exported, "InterfaceRealization", "client"),
+        circle
+    );
+    assert_eq!(
+        attribute(&exported, "InterfaceRealization", "supplier"),
+        shape
+    );
+    assert_eq!(
+        attribute(&exported, "InterfaceRealization", "contract"),
+        shape
+    );
+
+    assert_eq!(mermaid::export(&reimported), mermaid::export(&document));
+
+    for (original, reimported) in
+        document.elements().iter().zip(reimported.elements())
+    {
+        assert_eq!(
+            (original.x(), original.y()),
+            (reimported.x(), reimported.y())
+        );
+    }
+}
+
+#[test]
+fn reject_malformed_xml() {
+    let error = xmi::import("<xmi:XMI>\n<unclosed>").unwrap_err();
+    assert!(error.line().is_some());
+}
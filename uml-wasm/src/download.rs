@@ -0,0 +1,59 @@
+use gloo::utils::document;
+use js_sys::Array;
+use uml_common::{document::Document, format::rust};
+use wasm_bindgen::{JsCast, JsValue};
+use web_sys::{Blob, BlobPropertyBag, HtmlAnchorElement, Url};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ExportFormat {
+    Rust,
+}
+
+impl ExportFormat {
+    pub fn filename(&self) -> &'static str {
+        match self {
+            ExportFormat::Rust => "diagram.rs",
+        }
+    }
+
+    pub fn render(&self, document: &Document) -> String {
+        match self {
+            ExportFormat::Rust => rust::export(document),
+        }
+    }
+}
+
+/// Lets the browser save `contents` as a file named `filename`.
+pub fn download(filename: &str, contents: &str) {
+    let parts = Array::of1(&JsValue::from_str(contents));
+    let options = BlobPropertyBag::new();
+    options.set_type("text/plain");
+
+    let Ok(blob) = Blob::new_with_str_sequence_and_options(&parts, &options)
+    else {
+        log::error!("Could not create blob for download.");
+        return;
+    };
+
+    let Ok(url) = Url::create_object_url_with_blob(&blob) else {
+        log::error!("Could not create object URL for download.");
+        return;
+    };
+
+    let Some(anchor) = document()
+        .create_element("a")
+        .ok()
+        .and_then(|el| el.dyn_into::<HtmlAnchorElement>().ok())
+    else {
+        log::error!("Could not create anchor element for download.");
+        return;
+    };
+
+    anchor.set_href(&url);
+    anchor.set_download(filename);
+    anchor.click();
+
+    if Url::revoke_object_url(&url).is_err() {
+        log::error!("Could not revoke object URL after download.");
+    }
+}
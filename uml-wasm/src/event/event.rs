@@ -6,11 +6,11 @@ use crate::{mouse_button::MouseButton, wsclient::WsEvent};
 
 #[derive(Debug, Clone)]
 pub enum MouseEvent {
-    Down { button: MouseButton, x: i32, y: i32 },
-    Up { button: MouseButton, x: i32, y: i32 },
-    Move { x: i32, y: i32 },
-    Out { x: i32, y: i32 },
-    Enter { x: i32, y: i32 },
+    Down { button: MouseButton, x: f64, y: f64 },
+    Up { button: MouseButton, x: f64, y: f64 },
+    Move { x: f64, y: f64 },
+    Out { x: f64, y: f64 },
+    Enter { x: f64, y: f64 },
 }
 
 #[derive(Debug, Clone)]
@@ -34,7 +34,7 @@ pub enum Event {
 }
 
 impl MouseEvent {
-    pub fn x(&self) -> i32 {
+    pub fn x(&self) -> f64 {
         match *self {
             MouseEvent::Down { x, .. } => x,
             MouseEvent::Up { x, .. } => x,
@@ -44,7 +44,7 @@ impl MouseEvent {
         }
     }
 
-    pub fn y(&self) -> i32 {
+    pub fn y(&self) -> f64 {
         match *self {
             MouseEvent::Down { y, .. } => y,
             MouseEvent::Up { y, .. } => y,
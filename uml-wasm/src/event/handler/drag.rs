@@ -1,4 +1,4 @@
-use uml_common::{camera::Camera, elements::Element, id::Id};
+use uml_common::{camera::Camera, document::Document, geometry::Rect, id::Id};
 
 use crate::{
     event::{
@@ -7,7 +7,7 @@ use crate::{
     mouse_button::MouseButton,
 };
 
-#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
 pub enum DragState {
     #[default]
     None,
@@ -18,9 +18,14 @@ pub enum DragState {
     DraggingElement {
         id: Id,
     },
+    /// Drawing a marquee from (`x`, `y`), in document coordinates.
+    Selecting {
+        x: f64,
+        y: f64,
+    },
 }
 
-#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
 pub struct DragHandler {
     state: DragState,
     cursor: CursorStyle,
@@ -28,15 +33,15 @@ pub struct DragHandler {
     translate_key: bool,
     left_button: bool,
 
-    x: i32,
-    y: i32,
+    x: f64,
+    y: f64,
 }
 
 impl DragHandler {
     pub fn handle(
         &mut self,
         event: &Event,
-        elements: &[Element],
+        document: &mut Document,
         camera: Camera,
     ) -> Vec<Outcome> {
         let old_state = *self;
@@ -47,7 +52,7 @@ impl DragHandler {
             _ => return vec![Outcome::None],
         }
 
-        let primary_outcome = self.update(old_state, elements, camera);
+        let primary_outcome = self.update(old_state, document, camera);
         let mut outcomes = vec![primary_outcome];
 
         let is_translating = matches!(self.state, DragState::Camera);
@@ -93,7 +98,7 @@ impl DragHandler {
     fn update(
         &mut self,
         old_state: DragHandler,
-        elements: &[Element],
+        document: &mut Document,
         camera: Camera,
     ) -> Outcome {
         let delta_x = self.x - old_state.x;
@@ -113,14 +118,13 @@ impl DragHandler {
                     return Outcome::None;
                 }
 
-                let x = self.x + camera.x() as i32;
-                let y = self.y + camera.y() as i32;
+                let x = self.x + camera.x();
+                let y = self.y + camera.y();
 
-                if let Some(el) =
-                    elements.iter().rev().find(|e| e.cursor_intersects(x, y))
-                {
-                    self.state = DragState::PressingElement { id: el.id() };
-                }
+                self.state = match document.element_at(x, y) {
+                    Some(id) => DragState::PressingElement { id },
+                    None => DragState::Selecting { x, y },
+                };
 
                 Outcome::None
             }
@@ -147,8 +151,8 @@ impl DragHandler {
                     self.state = DragState::None;
                     Outcome::ClickElement {
                         id,
-                        x: self.x + camera.x() as i32,
-                        y: self.y + camera.y() as i32,
+                        x: self.x + camera.x(),
+                        y: self.y + camera.y(),
                     }
                 } else {
                     Outcome::None
@@ -157,8 +161,8 @@ impl DragHandler {
             DragState::DraggingElement { id } => {
                 if !self.left_button {
                     self.state = DragState::None;
-                    Outcome::None
-                } else if delta_x != 0 || delta_y != 0 {
+                    Outcome::DropElement { id }
+                } else if delta_x != 0.0 || delta_y != 0.0 {
                     Outcome::MoveElement {
                         id,
                         x: delta_x,
@@ -168,6 +172,23 @@ impl DragHandler {
                     Outcome::None
                 }
             }
+            DragState::Selecting { x, y } => {
+                if self.left_button && delta_x == 0.0 && delta_y == 0.0 {
+                    return Outcome::None;
+                }
+
+                if !self.left_button {
+                    self.state = DragState::None;
+                }
+
+                let area = Rect::from_edges(
+                    x,
+                    y,
+                    self.x + camera.x(),
+                    self.y + camera.y(),
+                );
+                Outcome::SelectElements(document.elements_within(area))
+            }
         }
     }
 
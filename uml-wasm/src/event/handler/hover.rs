@@ -1,4 +1,4 @@
-use uml_common::{camera::Camera, elements::Element, id::Id};
+use uml_common::{camera::Camera, document::Document, id::Id};
 
 use crate::event::{Event, Outcome};
 
@@ -11,21 +11,17 @@ impl HoverHandler {
     pub fn handle(
         &mut self,
         event: &Event,
-        elements: &mut [Element],
+        document: &mut Document,
         camera: &Camera,
     ) -> Vec<Outcome> {
         let Event::Mouse(event) = event else {
             return vec![];
         };
 
-        let x = event.x() + camera.x() as i32;
-        let y = event.y() + camera.y() as i32;
+        let x = event.x() + camera.x();
+        let y = event.y() + camera.y();
 
-        let hovered = elements
-            .iter_mut()
-            .rev()
-            .find(|e| e.cursor_intersects(x, y))
-            .map(|e| e.id());
+        let hovered = document.element_at(x, y);
 
         self.get_outcomes(hovered)
     }
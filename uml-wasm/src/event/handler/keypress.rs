@@ -1,16 +1,29 @@
 use std::collections::HashSet;
 
-use uml_common::{camera::Camera, elements::Class};
+use uml_common::{
+    camera::Camera,
+    elements::{
+        Action, Actor, Artifact, C4Boundary, C4Element, C4Kind, Class,
+        Component, ControlNode, ControlNodeKind, DeploymentNode, Element,
+        Entity, InstanceSpecification, Lifeline, Note, ObjectNode, Package,
+        Pseudostate, PseudostateKind, State, Swimlane, SystemBoundary, UseCase,
+    },
+};
 
 use crate::{
     dialog::SHARED_DIALOG,
+    download::ExportFormat,
     event::{Event, KeyboardEvent, Outcome},
 };
 
-#[derive(Debug, Clone, Default, PartialEq, Eq)]
+/// Creates elements at the cursor with single keys. Elements that only exist
+/// between or on others, such as relationships, messages, activations,
+/// fragments, ports and interfaces, cannot be created this way; they are
+/// added by importing a diagram.
+#[derive(Debug, Clone, Default, PartialEq)]
 pub struct KeypressHandler {
-    x: i32,
-    y: i32,
+    x: f64,
+    y: f64,
     keys: HashSet<String>,
 }
 
@@ -50,15 +63,52 @@ impl KeypressHandler {
             return Outcome::None;
         }
 
-        match key {
-            "a" => {
-                let x = self.x + camera.x() as i32;
-                let y = self.y + camera.y() as i32;
-                let class =
-                    Class::new(x, y, "Test class".into(), None, None, Some(3));
-                Outcome::AddElement(class.into())
+        // Where the cursor is in the document, for the element to create.
+        let x = self.x + camera.x();
+        let y = self.y + camera.y();
+
+        let element: Element = match key {
+            "a" => Class::new(x, y, "Test class".into(), None, None, Some(3.0))
+                .into(),
+            "l" => Lifeline::new(x, y, "object: Class".into()).into(),
+            "u" => UseCase::new(x, y, "Use case".into()).into(),
+            "t" => Actor::new(x, y, "Actor".into()).into(),
+            "b" => {
+                SystemBoundary::new(x, y, 300.0, 400.0, "System".into()).into()
             }
-            _ => Outcome::None,
-        }
+            "s" => State::new(x, y, "State".into()).into(),
+            "q" => Pseudostate::new(x, y, PseudostateKind::Initial).into(),
+            "n" => Action::new(x, y, "Action".into()).into(),
+            "g" => ControlNode::new(x, y, ControlNodeKind::Initial).into(),
+            "j" => ObjectNode::new(x, y, "Object".into()).into(),
+            "w" => Swimlane::new(x, y, 200.0, 400.0, "Swimlane".into()).into(),
+            "c" => Component::new(x, y, "Component".into()).into(),
+            "d" => {
+                DeploymentNode::new(x, y, 300.0, 200.0, "Node".into()).into()
+            }
+            "f" => Artifact::new(x, y, "artifact.jar".into()).into(),
+            "p" => Package::new(x, y, "package".into()).into(),
+            "y" => C4Element::new(
+                x,
+                y,
+                C4Kind::SoftwareSystem,
+                "Software System".into(),
+            )
+            .into(),
+            "k" => C4Boundary::new(x, y, "Boundary".into(), None).into(),
+            "e" => Entity::new(x, y, "Entity".into()).into(),
+            "i" => InstanceSpecification::new(
+                x,
+                y,
+                "object".into(),
+                Some("Class".into()),
+            )
+            .into(),
+            "o" => Note::new(x, y, "Note").into(),
+            "r" => return Outcome::Download(ExportFormat::Rust),
+            _ => return Outcome::None,
+        };
+
+        Outcome::AddElement(element)
     }
 }
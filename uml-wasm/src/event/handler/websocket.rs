@@ -1,4 +1,5 @@
 use gloo::net::websocket::Message;
+use uml_common::id::{Id, Origin};
 
 use crate::{
     event::{Event, Outcome},
@@ -39,6 +40,13 @@ impl WebsocketHandler {
             return Outcome::None;
         };
 
+        // The server assigns the client its origin before sending the
+        // document.
+        if let Ok(origin) = serde_json::from_str::<Origin>(str) {
+            Id::set_origin(origin);
+            return Outcome::None;
+        }
+
         let Ok(document) = serde_json::from_str(str) else {
             log::error!("Could not deserialize text message from server.");
             return Outcome::None;
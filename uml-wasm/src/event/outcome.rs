@@ -1,5 +1,7 @@
 use uml_common::{document::Document, elements::Element, id::Id};
 
+use crate::download::ExportFormat;
+
 use super::cursor_style::CursorStyle;
 
 /// The result of a strategy. It describes what work needs to be done post-eventhandling (e.g.
@@ -12,11 +14,15 @@ pub enum Outcome {
     /// Update local document.
     UpdateDocument(Document),
     /// Translate the camera. The coordinates are relative to the previous cursor position.
-    Translate { x: i32, y: i32 },
+    Translate { x: f64, y: f64 },
     /// Move an element. The coordinates are relative to the previous cursor position.
-    MoveElement { id: Id, x: i32, y: i32 },
+    MoveElement { id: Id, x: f64, y: f64 },
+    /// Drop a dragged element, placing it in the container under it.
+    DropElement { id: Id },
     /// Click an element. The coordinates are relative to the document's origin (0, 0).
-    ClickElement { id: Id, x: i32, y: i32 },
+    ClickElement { id: Id, x: f64, y: f64 },
+    /// Select the elements within a marquee, deselecting all others.
+    SelectElements(Vec<Id>),
     /// Update hover flag of an element.
     HoverElement { id: Id, hovered: bool },
     /// Change the style of the cursor.
@@ -25,4 +31,6 @@ pub enum Outcome {
     UpdateInfo { visible: bool },
     /// Add an element to the document.
     AddElement(Element),
+    /// Download the document in another format.
+    Download(ExportFormat),
 }
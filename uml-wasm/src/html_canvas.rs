@@ -1,17 +1,27 @@
-use gloo::utils::window;
+use std::{cell::RefCell, rc::Rc};
+
+use gloo::utils::{document, window};
+use js_sys::Array;
 use uml_common::{
     camera::Camera,
-    canvas::Canvas,
-    elements::{Label, Rectangle, TextProperties},
+    canvas::{Canvas, TextCache},
+    elements::{Label, Path, Rectangle, TextProperties},
+    grid::Grid,
     size::Size,
 };
-use wasm_bindgen::JsCast;
-use web_sys::TextMetrics;
+use wasm_bindgen::{JsCast, JsValue};
+use web_sys::{CanvasPattern, TextMetrics};
 
 #[derive(Clone)]
 pub struct HtmlCanvas {
     element: web_sys::HtmlCanvasElement,
     context: web_sys::CanvasRenderingContext2d,
+    /// The pattern of a single grid cell, built the first time the grid is
+    /// drawn and whenever the grid changes.
+    grid_pattern: Rc<RefCell<Option<(Grid, CanvasPattern)>>>,
+    /// Measured text, so that laying out an element again does not measure
+    /// the same strings twice.
+    text_cache: Rc<TextCache>,
 }
 
 impl HtmlCanvas {
@@ -30,7 +40,39 @@ impl HtmlCanvas {
             .dyn_into::<web_sys::CanvasRenderingContext2d>()
             .unwrap();
 
-        Self { element, context }
+        Self {
+            element,
+            context,
+            grid_pattern: Rc::default(),
+            text_cache: Rc::default(),
+        }
+    }
+
+    /// Draws a grid cell with its dot in the top left corner on a canvas of
+    /// its own, to be repeated as a pattern.
+    fn create_grid_pattern(grid: &Grid) -> Option<CanvasPattern> {
+        let tile = document()
+            .create_element("canvas")
+            .ok()?
+            .dyn_into::<web_sys::HtmlCanvasElement>()
+            .ok()?;
+        // Canvases are whole pixels in size.
+        let spacing = grid.spacing().round() as u32;
+        tile.set_width(spacing);
+        tile.set_height(spacing);
+
+        let context = tile
+            .get_context("2d")
+            .ok()??
+            .dyn_into::<web_sys::CanvasRenderingContext2d>()
+            .ok()?;
+        let size = grid.dot_size();
+        context.set_fill_style_str(&grid.dot_color().to_string());
+        context.fill_rect(0.0, 0.0, size, size);
+
+        context
+            .create_pattern_with_html_canvas_element(&tile, "repeat")
+            .ok()?
     }
 
     pub fn update_size(&self) {
@@ -57,30 +99,21 @@ impl Canvas for HtmlCanvas {
             self.context.set_line_width(stroke.width() as _);
         }
 
+        let (x, y) = camera.transform().apply(rect.x(), rect.y());
+
         if let Some(radius) = rect.radius() {
             self.context.begin_path();
 
             if self
                 .context
-                .round_rect_with_f64(
-                    rect.x() as f64 - camera.x(),
-                    rect.y() as f64 - camera.y(),
-                    rect.width() as f64,
-                    rect.height() as f64,
-                    radius as f64,
-                )
+                .round_rect_with_f64(x, y, rect.width(), rect.height(), radius)
                 .is_err()
             {
                 log::error!("Could not draw rounded rect.");
                 return;
             };
         } else {
-            self.context.rect(
-                rect.x() as f64 - camera.x(),
-                rect.y() as f64 - camera.y(),
-                rect.width() as f64,
-                rect.height() as f64,
-            );
+            self.context.rect(x, y, rect.width(), rect.height());
         }
 
         self.context.fill();
@@ -94,37 +127,134 @@ impl Canvas for HtmlCanvas {
         self.context.set_fill_style_str(&label.color().to_string());
         self.context.set_font(&label.props().get_font_string());
 
-        let x = label.x() as f64 - camera.x();
-        let y = label.y() as f64 - camera.y();
+        let (x, y) = camera.transform().apply(label.x(), label.y());
 
         if self.context.fill_text(label.text(), x, y).is_err() {
             log::debug!("Call to fill_text() failed.")
         }
     }
 
+    fn draw_path(&self, path: &Path, camera: &Camera) {
+        let Some(((first_x, first_y), rest)) = path.points().split_first()
+        else {
+            return;
+        };
+
+        let transform = camera.transform();
+        let (x, y) = transform.apply(*first_x, *first_y);
+        self.context.begin_path();
+        self.context.move_to(x, y);
+
+        for (x, y) in rest {
+            let (x, y) = transform.apply(*x, *y);
+            self.context.line_to(x, y);
+        }
+
+        if path.is_closed() {
+            self.context.close_path();
+        }
+
+        if let Some(fill) = path.fill() {
+            self.context.set_fill_style_str(&fill.to_string());
+            self.context.fill();
+        }
+
+        let Some(stroke) = path.stroke() else {
+            return;
+        };
+
+        let dash = match path.is_dashed() {
+            true => Array::of2(&JsValue::from(8), &JsValue::from(6)),
+            false => Array::new(),
+        };
+
+        if self.context.set_line_dash(&dash).is_err() {
+            log::error!("Could not set line dash.");
+        }
+
+        self.context
+            .set_stroke_style_str(&stroke.color().to_string());
+        self.context.set_line_width(stroke.width() as _);
+        self.context.stroke();
+
+        if self.context.set_line_dash(&Array::new()).is_err() {
+            log::error!("Could not reset line dash.");
+        }
+    }
+
+    fn size(&self) -> Size<f64> {
+        Size::new(
+            self.element.client_width() as f64,
+            self.element.client_height() as f64,
+        )
+    }
+
+    fn draw_grid(&self, grid: &Grid, camera: &Camera) {
+        let size = self.size();
+
+        self.context
+            .set_fill_style_str(&grid.background().to_string());
+        self.context
+            .fill_rect(0.0, 0.0, size.width(), size.height());
+
+        let mut cache = self.grid_pattern.borrow_mut();
+
+        if cache.as_ref().is_none_or(|(cached, _)| cached != grid) {
+            *cache = Self::create_grid_pattern(grid).map(|p| (*grid, p));
+        }
+
+        let Some((_, pattern)) = cache.as_ref() else {
+            log::error!("Could not create the grid pattern.");
+            return;
+        };
+
+        // The pattern repeats from the origin of the canvas, so it is
+        // shifted for the dots to stay on multiples of the spacing.
+        let spacing = grid.spacing();
+        let dx = camera.x().rem_euclid(spacing);
+        let dy = camera.y().rem_euclid(spacing);
+
+        self.context.save();
+
+        if self.context.translate(-dx, -dy).is_err() {
+            log::error!("Could not translate the grid.");
+        }
+
+        self.context.set_fill_style_canvas_pattern(pattern);
+        self.context.fill_rect(
+            0.0,
+            0.0,
+            size.width() + spacing,
+            size.height() + spacing,
+        );
+        self.context.restore();
+    }
+
     fn measure_text(
         &self,
         text: &str,
         props: &TextProperties,
     ) -> Option<Size<f32>> {
-        self.context.set_font(&props.get_font_string());
+        self.text_cache.get_or_measure(text, props, || {
+            self.context.set_font(&props.get_font_string());
 
-        let Ok(ret) = self.context.measure_text(text) else {
-            log::error!("Could not measure text.");
-            return None;
-        };
+            let Ok(ret) = self.context.measure_text(text) else {
+                log::error!("Could not measure text.");
+                return None;
+            };
 
-        let Some(metrics) = ret.dyn_ref::<TextMetrics>() else {
-            log::error!("Could not convert return type into TextMetrics.");
-            return None;
-        };
+            let Some(metrics) = ret.dyn_ref::<TextMetrics>() else {
+                log::error!("Could not convert return type into TextMetrics.");
+                return None;
+            };
 
-        let height = metrics.actual_bounding_box_ascent()
-            + metrics.actual_bounding_box_descent();
-        let width = metrics.actual_bounding_box_left()
-            + metrics.actual_bounding_box_right();
-        let size = Size::new(width as _, height as _);
+            let height = metrics.actual_bounding_box_ascent()
+                + metrics.actual_bounding_box_descent();
+            let width = metrics.actual_bounding_box_left()
+                + metrics.actual_bounding_box_right();
+            let size = Size::new(width as _, height as _);
 
-        Some(size)
+            Some(size)
+        })
     }
 }
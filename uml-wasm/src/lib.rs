@@ -12,6 +12,7 @@ use log::Level;
 use wasm_bindgen::prelude::*;
 
 mod dialog;
+mod download;
 mod event;
 mod html_canvas;
 mod mouse_button;
@@ -34,8 +35,8 @@ fn on_resize(callback: impl Fn(Event) + 'static) {
 fn on_mouse_move(callback: impl Fn(Event) + 'static) {
     add_event_listener("mousemove", move |e| {
         let event = e.dyn_ref::<web_sys::MouseEvent>().unwrap_throw();
-        let x = event.client_x();
-        let y = event.client_y();
+        let x = event.client_x() as f64;
+        let y = event.client_y() as f64;
         callback(MouseEvent::Move { x, y }.into());
     })
 }
@@ -43,8 +44,8 @@ fn on_mouse_move(callback: impl Fn(Event) + 'static) {
 fn on_mouse_down(callback: impl Fn(Event) + 'static) {
     add_event_listener("mousedown", move |e| {
         let event = e.dyn_ref::<web_sys::MouseEvent>().unwrap_throw();
-        let x = event.client_x();
-        let y = event.client_y();
+        let x = event.client_x() as f64;
+        let y = event.client_y() as f64;
         let Ok(button) = MouseButton::try_from(event.button()) else {
             return;
         };
@@ -56,8 +57,8 @@ fn on_mouse_down(callback: impl Fn(Event) + 'static) {
 fn on_mouse_up(callback: impl Fn(Event) + 'static) {
     add_event_listener("mouseup", move |e| {
         let event = e.dyn_ref::<web_sys::MouseEvent>().unwrap_throw();
-        let x = event.client_x();
-        let y = event.client_y();
+        let x = event.client_x() as f64;
+        let y = event.client_y() as f64;
         let Ok(button) = MouseButton::try_from(event.button()) else {
             return;
         };
@@ -69,8 +70,8 @@ fn on_mouse_up(callback: impl Fn(Event) + 'static) {
 fn on_mouse_out(callback: impl Fn(Event) + 'static) {
     add_event_listener("mouseout", move |e| {
         let event = e.dyn_ref::<web_sys::MouseEvent>().unwrap_throw();
-        let x = event.client_x();
-        let y = event.client_y();
+        let x = event.client_x() as f64;
+        let y = event.client_y() as f64;
         callback(MouseEvent::Out { x, y }.into());
     })
 }
@@ -78,8 +79,8 @@ fn on_mouse_out(callback: impl Fn(Event) + 'static) {
 fn on_mouse_enter(callback: impl Fn(Event) + 'static) {
     add_event_listener("mouseenter", move |e| {
         let event = e.dyn_ref::<web_sys::MouseEvent>().unwrap_throw();
-        let x = event.client_x();
-        let y = event.client_y();
+        let x = event.client_x() as f64;
+        let y = event.client_y() as f64;
         callback(MouseEvent::Enter { x, y }.into());
     })
 }
@@ -1,5 +1,6 @@
 use crate::{
     dialog::SHARED_DIALOG,
+    download::download,
     event::{
         Event, Outcome,
         cursor_style::CursorStyle,
@@ -101,12 +102,7 @@ impl State {
             response,
         } = event
         {
-            if let Some(el) = self
-                .document
-                .elements_mut()
-                .iter_mut()
-                .find(|el| el.id() == element_id)
-            {
+            if let Some(el) = self.document.get_mut(element_id) {
                 el.prompt(response);
             }
 
@@ -119,14 +115,14 @@ impl State {
 
         outcomes.extend_from_slice(&self.drag_handler.handle(
             &event,
-            self.document.elements_mut(),
+            &mut self.document,
             self.camera,
         ));
         outcomes.push(self.websocket_handler.handle(&event));
         outcomes.push(self.keypress_handler.handle(&event, &self.camera));
         outcomes.extend_from_slice(&self.hover_handler.handle(
             &event,
-            self.document.elements_mut(),
+            &mut self.document,
             &self.camera,
         ));
 
@@ -136,7 +132,9 @@ impl State {
             self.handle_outcome(outcome.clone());
             sync |= matches!(
                 outcome,
-                Outcome::AddElement(_) | Outcome::MoveElement { .. }
+                Outcome::AddElement(_)
+                    | Outcome::MoveElement { .. }
+                    | Outcome::DropElement { .. }
             );
             self.redraw_scheduled |= *outcome != Outcome::None;
         }
@@ -150,58 +148,39 @@ impl State {
         match outcome {
             Outcome::None => (),
             Outcome::Translate { x, y } => {
-                self.camera.translate(x as _, y as _);
+                self.camera.translate(x, y);
             }
             Outcome::MoveElement { id, x, y } => {
-                if let Some(el) = self
-                    .document
-                    .elements_mut()
-                    .iter_mut()
-                    .find(|e| e.id() == id)
-                {
-                    el.adjust_position(x, y);
-                }
+                self.document.move_element(id, x, y);
+            }
+            Outcome::DropElement { id } => {
+                self.document.drop_element(id);
             }
             Outcome::ClickElement { id, x, y } => {
-                if let Some(el) = self
-                    .document
-                    .elements_mut()
-                    .iter_mut()
-                    .find(|e| e.id() == id)
-                {
-                    if let Some(prompt) = el.click(x - el.x(), y - el.y()) {
-                        SHARED_DIALOG.with_borrow_mut(|d| {
-                            d.activate(el.id(), prompt);
-                        });
-                    }
+                if let Some(prompt) = self.document.click(id, x, y) {
+                    SHARED_DIALOG.with_borrow_mut(|d| {
+                        d.activate(id, prompt);
+                    });
                 }
             }
             Outcome::CursorStyle(style) => self.set_cursor(style),
             Outcome::UpdateInfo { visible } => {
                 self.update_info_element(visible)
             }
-            Outcome::UpdateDocument(mut document) => {
-                for el in document.elements_mut() {
-                    el.initalize(&self.canvas);
-                }
-
+            Outcome::UpdateDocument(document) => {
+                // Elements are laid out as they come into view.
                 self.document = document;
             }
             Outcome::AddElement(mut element) => {
                 element.initalize(&self.canvas);
-                self.document.elements_mut().push(element);
+                self.document.add_element(element);
             }
+            Outcome::SelectElements(ids) => self.document.select(ids),
             Outcome::HoverElement { id, hovered } => {
-                let Some(el) = self
-                    .document
-                    .elements_mut()
-                    .iter_mut()
-                    .find(|el| el.id() == id)
-                else {
-                    return;
-                };
-
-                el.get_interaction_mut().set_hover(hovered);
+                self.document.hover(id, hovered)
+            }
+            Outcome::Download(format) => {
+                download(format.filename(), &format.render(&self.document));
             }
         }
     }